@@ -0,0 +1,126 @@
+//! Compares the rasterization pipelines.
+//!
+//! Scenes using layered paints or effects are vectorized into an SVG string,
+//! which is parsed back into a `usvg` tree before being rendered. This
+//! benchmark measures each stage of that round trip separately, and compares
+//! it with the direct path used for scenes made of solid fills and strokes,
+//! which draws the scene with `tiny-skia` without writing the string.
+//!
+//! Run with `cargo bench --bench rasterize`.
+
+use decal::prelude::*;
+use std::{
+    hint::black_box,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+const ITERATIONS: u32 = 200;
+
+fn scene<T>(background: T, opacity: f32) -> Scene
+where
+    T: Into<Paint>,
+{
+    let background = background.into();
+
+    decal! {
+        Column {
+            Row {
+                Circle(24.0).fill(rgb(0xffffff)).opacity(opacity)
+                Rectangle(120.0, 24.0).corner_radius(12).fill(rgba(0xffffff80))
+            }
+            .gap(16)
+            .align_items(AlignItems::Center)
+            Text("The quick brown fox jumps over the lazy dog")
+                .font_size(48.0)
+                .color(rgb(0xffffff))
+            Polyline([(0.0, 40.0), (60.0, 0.0), (120.0, 30.0), (200.0, 10.0)])
+                .stroke(rgb(0xffffff))
+                .stroke_width(4.0)
+        }
+        .size((1200, 630))
+        .padding(64)
+        .gap(32)
+        .background(background)
+    }
+}
+
+fn measure<F>(name: &str, mut f: F)
+where
+    F: FnMut(),
+{
+    // warm up caches
+    f();
+
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        f();
+    }
+
+    let elapsed: Duration = start.elapsed() / ITERATIONS;
+    println!("{name:<24} {elapsed:>12.3?}");
+}
+
+fn main() {
+    let mut engine = Engine::new(EngineOptions::default());
+    let stripes = LinearGradient::angle(45.0).stops([(0.0, rgb(0x6366f1)), (1.0, rgb(0xec4899))]);
+    let mut layered = scene(stripes, 0.8);
+    let (svg, size) = engine
+        .vectorize(&mut layered, &VectorizeOptions::default())
+        .unwrap();
+    let usvg_options = usvg::Options::default();
+    let tree = usvg::Tree::from_str(&svg, &usvg_options).unwrap();
+
+    println!("layered scene");
+
+    measure("vectorize", || {
+        black_box(
+            engine
+                .vectorize(&mut layered, &VectorizeOptions::default())
+                .unwrap(),
+        );
+    });
+
+    measure("parse", || {
+        black_box(usvg::Tree::from_str(black_box(&svg), &usvg_options).unwrap());
+    });
+
+    measure("render", || {
+        let mut pixmap = tiny_skia::Pixmap::new(size.width() as u32, size.height() as u32).unwrap();
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+        black_box(pixmap);
+    });
+
+    measure("rasterize", || {
+        black_box(
+            engine
+                .rasterize(&mut layered, &RasterizeOptions::default())
+                .unwrap(),
+        );
+    });
+
+    let mut solid = scene(rgb(0x6366f1), 1.0);
+
+    println!("solid scene");
+
+    measure("round trip", || {
+        let (svg, size) = engine
+            .vectorize(&mut solid, &VectorizeOptions::default())
+            .unwrap();
+        let tree = usvg::Tree::from_str(&svg, &usvg_options).unwrap();
+        let mut pixmap = tiny_skia::Pixmap::new(size.width() as u32, size.height() as u32).unwrap();
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+        black_box(pixmap);
+    });
+
+    measure("rasterize (direct)", || {
+        black_box(
+            engine
+                .rasterize(&mut solid, &RasterizeOptions::default())
+                .unwrap(),
+        );
+    });
+}
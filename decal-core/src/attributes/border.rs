@@ -1,10 +1,10 @@
 use crate::primitives::{
-    Length,
+    LayoutLength,
     Rect,
 };
 
 /// Type alias representing the width of a border edge.
-type BorderWidth = Length<false, true>;
+type BorderWidth = LayoutLength<false, true>;
 
 /// Type alias for a rectangular border composed of four independent border
 /// widths.
@@ -45,15 +45,15 @@ impl IntoBorder for Border {
 /// Expands a single value into a uniform border applied to all sides.
 impl<T> IntoBorder for T
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Uses the same width for the top, right, bottom, and left edges.
     #[inline]
     fn into_border(self) -> Option<Border> {
         Some(Rect {
-            top: self.into(),
-            right: self.into(),
-            bottom: self.into(),
+            top: self.clone().into(),
+            right: self.clone().into(),
+            bottom: self.clone().into(),
             left: self.into(),
         })
     }
@@ -62,7 +62,7 @@ where
 /// Converts a two-value tuple into a vertical and horizontal border.
 impl<T> IntoBorder for (T, T)
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Interprets the tuple as `(vertical, horizontal)`.
     ///
@@ -71,8 +71,8 @@ where
     #[inline]
     fn into_border(self) -> Option<Border> {
         Some(Rect {
-            top: self.0.into(),
-            right: self.1.into(),
+            top: self.0.clone().into(),
+            right: self.1.clone().into(),
             bottom: self.0.into(),
             left: self.1.into(),
         })
@@ -82,7 +82,7 @@ where
 /// Converts a three-value tuple into a border with an inferred left edge.
 impl<T> IntoBorder for (T, T, T)
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Interprets the tuple as `(top, horizontal, bottom)`.
     ///
@@ -91,7 +91,7 @@ where
     fn into_border(self) -> Option<Border> {
         Some(Rect {
             top: self.0.into(),
-            right: self.1.into(),
+            right: self.1.clone().into(),
             bottom: self.2.into(),
             left: self.1.into(),
         })
@@ -101,7 +101,7 @@ where
 /// Converts a four-value tuple into an explicit border for all edges.
 impl<T> IntoBorder for (T, T, T, T)
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Interprets the tuple as `(top, right, bottom, left)` in clockwise order.
     #[inline]
@@ -118,36 +118,39 @@ where
 /// Array-based shorthand for two-value border definitions.
 impl<T> IntoBorder for [T; 2]
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Delegates to the two-element tuple implementation.
     #[inline]
     fn into_border(self) -> Option<Border> {
-        IntoBorder::into_border((self[0], self[1]))
+        let [a, b] = self;
+        IntoBorder::into_border((a, b))
     }
 }
 
 /// Array-based shorthand for three-value border definitions.
 impl<T> IntoBorder for [T; 3]
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Delegates to the three-element tuple implementation.
     #[inline]
     fn into_border(self) -> Option<Border> {
-        IntoBorder::into_border((self[0], self[1], self[2]))
+        let [a, b, c] = self;
+        IntoBorder::into_border((a, b, c))
     }
 }
 
 /// Array-based shorthand for four-value border definitions.
 impl<T> IntoBorder for [T; 4]
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Delegates to the four-element tuple implementation.
     #[inline]
     fn into_border(self) -> Option<Border> {
-        IntoBorder::into_border((self[0], self[1], self[2], self[3]))
+        let [a, b, c, d] = self;
+        IntoBorder::into_border((a, b, c, d))
     }
 }
 
@@ -170,19 +173,19 @@ pub trait IntoBorderPair {
 /// Expands a single value into a symmetric border pair.
 impl<T> IntoBorderPair for T
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Uses the same width for both elements of the pair.
     #[inline]
     fn into_border_pair(self) -> Option<BorderPair> {
-        Some((self.into(), self.into()))
+        Some((self.clone().into(), self.into()))
     }
 }
 
 /// Converts a two-value tuple into a border width pair.
 impl<T> IntoBorderPair for (T, T)
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Interprets the tuple elements directly without reordering.
     #[inline]
@@ -194,11 +197,12 @@ where
 /// Array-based shorthand for border width pairs.
 impl<T> IntoBorderPair for [T; 2]
 where
-    T: Into<BorderWidth> + Copy,
+    T: Into<BorderWidth> + Clone,
 {
     /// Delegates to the tuple-based border pair conversion.
     #[inline]
     fn into_border_pair(self) -> Option<BorderPair> {
-        IntoBorderPair::into_border_pair((self[0], self[1]))
+        let [a, b] = self;
+        IntoBorderPair::into_border_pair((a, b))
     }
 }
@@ -1,10 +1,10 @@
 use crate::primitives::{
     Corner,
-    Length,
+    LayoutLength,
 };
 
 /// Type alias representing the radius of a single corner.
-type CornerRadii = Length<false, true>;
+type CornerRadii = LayoutLength<false, true>;
 
 /// Type alias for a structure holding four independent corner radii.
 pub type CornerRadius = Corner<CornerRadii>;
@@ -46,15 +46,15 @@ impl IntoCornerRadius for CornerRadius {
 /// Expands a single value into a uniform radius applied to all corners.
 impl<T> IntoCornerRadius for T
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Uses the same radius for all four corners.
     #[inline]
     fn into_corner_radius(self) -> Option<CornerRadius> {
         Some(Corner {
-            top_left: self.into(),
-            top_right: self.into(),
-            bottom_right: self.into(),
+            top_left: self.clone().into(),
+            top_right: self.clone().into(),
+            bottom_right: self.clone().into(),
             bottom_left: self.into(),
         })
     }
@@ -63,7 +63,7 @@ where
 /// Converts a two-value tuple into alternating corner radii.
 impl<T> IntoCornerRadius for (T, T)
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Interprets the tuple as `(first, second)` applied diagonally.
     ///
@@ -72,8 +72,8 @@ where
     #[inline]
     fn into_corner_radius(self) -> Option<CornerRadius> {
         Some(Corner {
-            top_left: self.0.into(),
-            top_right: self.1.into(),
+            top_left: self.0.clone().into(),
+            top_right: self.1.clone().into(),
             bottom_right: self.0.into(),
             bottom_left: self.1.into(),
         })
@@ -84,7 +84,7 @@ where
 /// bottom-left value.
 impl<T> IntoCornerRadius for (T, T, T)
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Interprets the tuple as `(top_left, top_right, bottom_right)`.
     ///
@@ -93,7 +93,7 @@ where
     fn into_corner_radius(self) -> Option<CornerRadius> {
         Some(Corner {
             top_left: self.0.into(),
-            top_right: self.1.into(),
+            top_right: self.1.clone().into(),
             bottom_right: self.2.into(),
             bottom_left: self.1.into(),
         })
@@ -103,7 +103,7 @@ where
 /// Converts a four-value tuple into an explicit radius for each corner.
 impl<T> IntoCornerRadius for (T, T, T, T)
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Interprets the tuple as `(top_left, top_right, bottom_right,
     /// bottom_left)` in clockwise order.
@@ -121,35 +121,38 @@ where
 /// Array-based shorthand for two-value corner radius definitions.
 impl<T> IntoCornerRadius for [T; 2]
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Delegates to the two-element tuple implementation.
     #[inline]
     fn into_corner_radius(self) -> Option<CornerRadius> {
-        IntoCornerRadius::into_corner_radius((self[0], self[1]))
+        let [a, b] = self;
+        IntoCornerRadius::into_corner_radius((a, b))
     }
 }
 
 /// Array-based shorthand for three-value corner radius definitions.
 impl<T> IntoCornerRadius for [T; 3]
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Delegates to the three-element tuple implementation.
     #[inline]
     fn into_corner_radius(self) -> Option<CornerRadius> {
-        IntoCornerRadius::into_corner_radius((self[0], self[1], self[2]))
+        let [a, b, c] = self;
+        IntoCornerRadius::into_corner_radius((a, b, c))
     }
 }
 
 /// Array-based shorthand for four-value corner radius definitions.
 impl<T> IntoCornerRadius for [T; 4]
 where
-    T: Into<CornerRadii> + Copy,
+    T: Into<CornerRadii> + Clone,
 {
     /// Delegates to the four-element tuple implementation.
     #[inline]
     fn into_corner_radius(self) -> Option<CornerRadius> {
-        IntoCornerRadius::into_corner_radius((self[0], self[1], self[2], self[3]))
+        let [a, b, c, d] = self;
+        IntoCornerRadius::into_corner_radius((a, b, c, d))
     }
 }
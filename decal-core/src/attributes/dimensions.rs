@@ -1,10 +1,10 @@
 use crate::primitives::{
-    Length,
+    LayoutLength,
     Size,
 };
 
 /// Type alias representing two-dimensional size values expressed using lengths.
-type Dimensions = Size<Length>;
+type Dimensions = Size<LayoutLength>;
 
 /// Conversion trait for values that can be interpreted as width and height
 /// dimensions.
@@ -43,13 +43,13 @@ impl IntoDimensions for Dimensions {
 /// Expands a single value into equal width and height dimensions.
 impl<T> IntoDimensions for T
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Uses the same length for both width and height.
     #[inline]
     fn into_dimensions(self) -> Option<Dimensions> {
         Some(Size {
-            width: self.into(),
+            width: self.clone().into(),
             height: self.into(),
         })
     }
@@ -58,7 +58,7 @@ where
 /// Converts a two-value tuple into explicit width and height dimensions.
 impl<T> IntoDimensions for (T, T)
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Interprets the tuple as `(width, height)` without reordering.
     #[inline]
@@ -73,11 +73,12 @@ where
 /// Array-based shorthand for width and height dimensions.
 impl<T> IntoDimensions for [T; 2]
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Delegates to the two-element tuple implementation.
     #[inline]
     fn into_dimensions(self) -> Option<Dimensions> {
-        IntoDimensions::into_dimensions((self[0], self[1]))
+        let [a, b] = self;
+        IntoDimensions::into_dimensions((a, b))
     }
 }
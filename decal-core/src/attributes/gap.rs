@@ -1,10 +1,10 @@
 use crate::primitives::{
-    Length,
+    LayoutLength,
     Size,
 };
 
 /// Type alias representing the size of a gap along a single axis.
-type GapSize = Length<false, true>;
+type GapSize = LayoutLength<false, true>;
 
 /// Type alias for a two-dimensional gap defined by horizontal and vertical
 /// sizes.
@@ -45,13 +45,13 @@ impl IntoGap for Gap {
 /// Expands a single value into equal horizontal and vertical gaps.
 impl<T> IntoGap for T
 where
-    T: Into<GapSize> + Copy,
+    T: Into<GapSize> + Clone,
 {
     /// Uses the same size for both width and height gaps.
     #[inline]
     fn into_gap(self) -> Option<Gap> {
         Some(Size {
-            width: self.into(),
+            width: self.clone().into(),
             height: self.into(),
         })
     }
@@ -60,7 +60,7 @@ where
 /// Converts a two-value tuple into explicit horizontal and vertical gaps.
 impl<T> IntoGap for (T, T)
 where
-    T: Into<GapSize> + Copy,
+    T: Into<GapSize> + Clone,
 {
     /// Interprets the tuple as `(horizontal, vertical)` without reordering.
     #[inline]
@@ -75,11 +75,12 @@ where
 /// Array-based shorthand for gap definitions.
 impl<T> IntoGap for [T; 2]
 where
-    T: Into<GapSize> + Copy,
+    T: Into<GapSize> + Clone,
 {
     /// Delegates to the two-element tuple implementation.
     #[inline]
     fn into_gap(self) -> Option<Gap> {
-        IntoGap::into_gap((self[0], self[1]))
+        let [a, b] = self;
+        IntoGap::into_gap((a, b))
     }
 }
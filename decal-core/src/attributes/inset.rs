@@ -0,0 +1,200 @@
+use crate::primitives::{
+    LayoutLength,
+    Rect,
+};
+
+/// Type alias representing the inset offsets of all four edges.
+type Inset = Rect<LayoutLength>;
+
+/// Conversion trait for values that can be interpreted as a rectangular inset.
+pub trait IntoInset {
+    /// Converts the value into an inset definition.
+    ///
+    ///
+    /// This abstraction allows a wide range of ergonomic inputs while keeping
+    /// call sites concise.
+    ///
+    /// # Returns
+    /// - `Some(Inset)` when the value can be expanded into four inset edges.
+    /// - `None` when the value semantically represents the absence of inset,
+    ///   which resets every edge to `auto`.
+    fn into_inset(self) -> Option<Inset>;
+}
+
+/// Identity conversion for an optional inset.
+impl IntoInset for Option<Inset> {
+    /// Returns the contained inset without modification.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        self
+    }
+}
+
+/// Wraps an existing inset into an optional value.
+impl IntoInset for Inset {
+    /// Converts a concrete inset into `Some(Inset)`.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        Some(self)
+    }
+}
+
+/// Expands a single value into a uniform inset applied to all edges.
+impl<T> IntoInset for T
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Uses the same inset value for top, right, bottom, and left.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        Some(Rect {
+            top: self.clone().into(),
+            right: self.clone().into(),
+            bottom: self.clone().into(),
+            left: self.into(),
+        })
+    }
+}
+
+/// Converts a two-value tuple into vertical and horizontal insets.
+impl<T> IntoInset for (T, T)
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Interprets the tuple as `(vertical, horizontal)`.
+    ///
+    /// The first value is applied to top and bottom, the second to left and
+    /// right.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        Some(Rect {
+            top: self.0.clone().into(),
+            right: self.1.clone().into(),
+            bottom: self.0.into(),
+            left: self.1.into(),
+        })
+    }
+}
+
+/// Converts a three-value tuple into an inset with an inferred left edge.
+impl<T> IntoInset for (T, T, T)
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Interprets the tuple as `(top, horizontal, bottom)`.
+    ///
+    /// The left edge reuses the horizontal value.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        Some(Rect {
+            top: self.0.into(),
+            right: self.1.clone().into(),
+            bottom: self.2.into(),
+            left: self.1.into(),
+        })
+    }
+}
+
+/// Converts a four-value tuple into an explicit inset for all edges.
+impl<T> IntoInset for (T, T, T, T)
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Interprets the tuple as `(top, right, bottom, left)` in clockwise order.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        Some(Rect {
+            top: self.0.into(),
+            right: self.1.into(),
+            bottom: self.2.into(),
+            left: self.3.into(),
+        })
+    }
+}
+
+/// Array-based shorthand for two-value inset definitions.
+impl<T> IntoInset for [T; 2]
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Delegates to the two-element tuple implementation.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        let [a, b] = self;
+        IntoInset::into_inset((a, b))
+    }
+}
+
+/// Array-based shorthand for three-value inset definitions.
+impl<T> IntoInset for [T; 3]
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Delegates to the three-element tuple implementation.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        let [a, b, c] = self;
+        IntoInset::into_inset((a, b, c))
+    }
+}
+
+/// Array-based shorthand for four-value inset definitions.
+impl<T> IntoInset for [T; 4]
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Delegates to the four-element tuple implementation.
+    #[inline]
+    fn into_inset(self) -> Option<Inset> {
+        let [a, b, c, d] = self;
+        IntoInset::into_inset((a, b, c, d))
+    }
+}
+
+/// Conversion trait for values that can be interpreted as an inset pair.
+pub trait IntoInsetPair {
+    /// Converts the value into a pair of inset lengths.
+    ///
+    /// # Returns
+    /// - `Some((LayoutLength, LayoutLength))` when the value can be expanded
+    ///   into a pair.
+    /// - `None` when the value semantically represents the absence of insets.
+    fn into_inset_pair(self) -> Option<(LayoutLength, LayoutLength)>;
+}
+
+/// Expands a single value into a symmetric inset pair.
+impl<T> IntoInsetPair for T
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Uses the same inset value for both elements of the pair.
+    #[inline]
+    fn into_inset_pair(self) -> Option<(LayoutLength, LayoutLength)> {
+        Some((self.clone().into(), self.into()))
+    }
+}
+
+/// Converts a two-value tuple into an inset pair.
+impl<T> IntoInsetPair for (T, T)
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Interprets the tuple elements directly without reordering.
+    #[inline]
+    fn into_inset_pair(self) -> Option<(LayoutLength, LayoutLength)> {
+        Some((self.0.into(), self.1.into()))
+    }
+}
+
+/// Array-based shorthand for inset pair definitions.
+impl<T> IntoInsetPair for [T; 2]
+where
+    T: Into<LayoutLength> + Clone,
+{
+    /// Delegates to the tuple-based inset pair conversion.
+    #[inline]
+    fn into_inset_pair(self) -> Option<(LayoutLength, LayoutLength)> {
+        let [a, b] = self;
+        IntoInsetPair::into_inset_pair((a, b))
+    }
+}
@@ -1,10 +1,10 @@
 use crate::primitives::{
-    Length,
+    LayoutLength,
     Rect,
 };
 
 /// Type alias representing margin values for all four edges.
-type Margin = Rect<Length>;
+type Margin = Rect<LayoutLength>;
 
 /// Conversion trait for values that can be interpreted as a rectangular margin.
 pub trait IntoMargin {
@@ -41,15 +41,15 @@ impl IntoMargin for Margin {
 /// Expands a single value into a uniform margin applied to all edges.
 impl<T> IntoMargin for T
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Uses the same margin value for top, right, bottom, and left.
     #[inline]
     fn into_margin(self) -> Option<Margin> {
         Some(Rect {
-            top: self.into(),
-            right: self.into(),
-            bottom: self.into(),
+            top: self.clone().into(),
+            right: self.clone().into(),
+            bottom: self.clone().into(),
             left: self.into(),
         })
     }
@@ -58,7 +58,7 @@ where
 /// Converts a two-value tuple into vertical and horizontal margins.
 impl<T> IntoMargin for (T, T)
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Interprets the tuple as `(vertical, horizontal)`.
     ///
@@ -67,8 +67,8 @@ where
     #[inline]
     fn into_margin(self) -> Option<Margin> {
         Some(Rect {
-            top: self.0.into(),
-            right: self.1.into(),
+            top: self.0.clone().into(),
+            right: self.1.clone().into(),
             bottom: self.0.into(),
             left: self.1.into(),
         })
@@ -78,7 +78,7 @@ where
 /// Converts a three-value tuple into a margin with an inferred left edge.
 impl<T> IntoMargin for (T, T, T)
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Interprets the tuple as `(top, horizontal, bottom)`.
     ///
@@ -87,7 +87,7 @@ where
     fn into_margin(self) -> Option<Margin> {
         Some(Rect {
             top: self.0.into(),
-            right: self.1.into(),
+            right: self.1.clone().into(),
             bottom: self.2.into(),
             left: self.1.into(),
         })
@@ -97,7 +97,7 @@ where
 /// Converts a four-value tuple into an explicit margin for all edges.
 impl<T> IntoMargin for (T, T, T, T)
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Interprets the tuple as `(top, right, bottom, left)` in clockwise order.
     #[inline]
@@ -114,36 +114,39 @@ where
 /// Array-based shorthand for two-value margin definitions.
 impl<T> IntoMargin for [T; 2]
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Delegates to the two-element tuple implementation.
     #[inline]
     fn into_margin(self) -> Option<Margin> {
-        IntoMargin::into_margin((self[0], self[1]))
+        let [a, b] = self;
+        IntoMargin::into_margin((a, b))
     }
 }
 
 /// Array-based shorthand for three-value margin definitions.
 impl<T> IntoMargin for [T; 3]
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Delegates to the three-element tuple implementation.
     #[inline]
     fn into_margin(self) -> Option<Margin> {
-        IntoMargin::into_margin((self[0], self[1], self[2]))
+        let [a, b, c] = self;
+        IntoMargin::into_margin((a, b, c))
     }
 }
 
 /// Array-based shorthand for four-value margin definitions.
 impl<T> IntoMargin for [T; 4]
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Delegates to the four-element tuple implementation.
     #[inline]
     fn into_margin(self) -> Option<Margin> {
-        IntoMargin::into_margin((self[0], self[1], self[2], self[3]))
+        let [a, b, c, d] = self;
+        IntoMargin::into_margin((a, b, c, d))
     }
 }
 
@@ -152,31 +155,32 @@ pub trait IntoMarginPair {
     /// Converts the value into a pair of margin lengths.
     ///
     /// # Returns
-    /// - `Some((Length, Length))` when the value can be expanded into a pair.
+    /// - `Some((LayoutLength, LayoutLength))` when the value can be expanded
+    ///   into a pair.
     /// - `None` when the value semantically represents the absence of margins.
-    fn into_margin_pair(self) -> Option<(Length, Length)>;
+    fn into_margin_pair(self) -> Option<(LayoutLength, LayoutLength)>;
 }
 
 /// Expands a single value into a symmetric margin pair.
 impl<T> IntoMarginPair for T
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Uses the same margin value for both elements of the pair.
     #[inline]
-    fn into_margin_pair(self) -> Option<(Length, Length)> {
-        Some((self.into(), self.into()))
+    fn into_margin_pair(self) -> Option<(LayoutLength, LayoutLength)> {
+        Some((self.clone().into(), self.into()))
     }
 }
 
 /// Converts a two-value tuple into a margin pair.
 impl<T> IntoMarginPair for (T, T)
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Interprets the tuple elements directly without reordering.
     #[inline]
-    fn into_margin_pair(self) -> Option<(Length, Length)> {
+    fn into_margin_pair(self) -> Option<(LayoutLength, LayoutLength)> {
         Some((self.0.into(), self.1.into()))
     }
 }
@@ -184,11 +188,12 @@ where
 /// Array-based shorthand for margin pair definitions.
 impl<T> IntoMarginPair for [T; 2]
 where
-    T: Into<Length> + Copy,
+    T: Into<LayoutLength> + Clone,
 {
     /// Delegates to the tuple-based margin pair conversion.
     #[inline]
-    fn into_margin_pair(self) -> Option<(Length, Length)> {
-        IntoMarginPair::into_margin_pair((self[0], self[1]))
+    fn into_margin_pair(self) -> Option<(LayoutLength, LayoutLength)> {
+        let [a, b] = self;
+        IntoMarginPair::into_margin_pair((a, b))
     }
 }
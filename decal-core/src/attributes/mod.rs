@@ -2,6 +2,7 @@ mod border;
 mod corner_radius;
 mod dimensions;
 mod gap;
+mod inset;
 mod margin;
 mod overflow;
 mod padding;
@@ -11,6 +12,7 @@ pub use border::*;
 pub use corner_radius::*;
 pub use dimensions::*;
 pub use gap::*;
+pub use inset::*;
 pub use margin::*;
 pub use overflow::*;
 pub use padding::*;
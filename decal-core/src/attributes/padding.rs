@@ -1,10 +1,10 @@
 use crate::primitives::{
-    Length,
+    LayoutLength,
     Rect,
 };
 
 /// Type alias representing the size of padding on a single edge.
-type PaddingValue = Length<false, true>;
+type PaddingValue = LayoutLength<false, true>;
 
 /// Type alias for a rectangular padding composed of four independent padding
 /// values.
@@ -45,15 +45,15 @@ impl IntoPadding for Padding {
 /// Expands a single value into uniform padding applied to all edges.
 impl<T> IntoPadding for T
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Uses the same padding value for top, right, bottom, and left.
     #[inline]
     fn into_padding(self) -> Option<Padding> {
         Some(Rect {
-            top: self.into(),
-            right: self.into(),
-            bottom: self.into(),
+            top: self.clone().into(),
+            right: self.clone().into(),
+            bottom: self.clone().into(),
             left: self.into(),
         })
     }
@@ -62,14 +62,14 @@ where
 /// Converts a two-value tuple into vertical and horizontal padding.
 impl<T> IntoPadding for (T, T)
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Interprets the tuple as `(vertical, horizontal)`.
     #[inline]
     fn into_padding(self) -> Option<Padding> {
         Some(Rect {
-            top: self.0.into(),
-            right: self.1.into(),
+            top: self.0.clone().into(),
+            right: self.1.clone().into(),
             bottom: self.0.into(),
             left: self.1.into(),
         })
@@ -79,14 +79,14 @@ where
 /// Converts a three-value tuple into padding with an inferred left edge.
 impl<T> IntoPadding for (T, T, T)
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Interprets the tuple as `(top, horizontal, bottom)`.
     #[inline]
     fn into_padding(self) -> Option<Padding> {
         Some(Rect {
             top: self.0.into(),
-            right: self.1.into(),
+            right: self.1.clone().into(),
             bottom: self.2.into(),
             left: self.1.into(),
         })
@@ -96,7 +96,7 @@ where
 /// Converts a four-value tuple into explicit padding for all edges.
 impl<T> IntoPadding for (T, T, T, T)
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Interprets the tuple as `(top, right, bottom, left)` in clockwise order.
     #[inline]
@@ -113,36 +113,39 @@ where
 /// Array-based shorthand for two-value padding definitions.
 impl<T> IntoPadding for [T; 2]
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Delegates to the two-element tuple implementation.
     #[inline]
     fn into_padding(self) -> Option<Padding> {
-        IntoPadding::into_padding((self[0], self[1]))
+        let [a, b] = self;
+        IntoPadding::into_padding((a, b))
     }
 }
 
 /// Array-based shorthand for three-value padding definitions.
 impl<T> IntoPadding for [T; 3]
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Delegates to the three-element tuple implementation.
     #[inline]
     fn into_padding(self) -> Option<Padding> {
-        IntoPadding::into_padding((self[0], self[1], self[2]))
+        let [a, b, c] = self;
+        IntoPadding::into_padding((a, b, c))
     }
 }
 
 /// Array-based shorthand for four-value padding definitions.
 impl<T> IntoPadding for [T; 4]
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Delegates to the four-element tuple implementation.
     #[inline]
     fn into_padding(self) -> Option<Padding> {
-        IntoPadding::into_padding((self[0], self[1], self[2], self[3]))
+        let [a, b, c, d] = self;
+        IntoPadding::into_padding((a, b, c, d))
     }
 }
 
@@ -163,19 +166,19 @@ pub trait IntoPaddingPair {
 /// Expands a single value into a symmetric padding pair.
 impl<T> IntoPaddingPair for T
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Uses the same padding value for both elements of the pair.
     #[inline]
     fn into_padding_pair(self) -> Option<PaddingPair> {
-        Some((self.into(), self.into()))
+        Some((self.clone().into(), self.into()))
     }
 }
 
 /// Converts a two-value tuple into a padding pair.
 impl<T> IntoPaddingPair for (T, T)
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Interprets the tuple elements directly without reordering.
     #[inline]
@@ -187,11 +190,12 @@ where
 /// Array-based shorthand for padding pair definitions.
 impl<T> IntoPaddingPair for [T; 2]
 where
-    T: Into<PaddingValue> + Copy,
+    T: Into<PaddingValue> + Clone,
 {
     /// Delegates to the tuple-based padding pair conversion.
     #[inline]
     fn into_padding_pair(self) -> Option<PaddingPair> {
-        IntoPaddingPair::into_padding_pair((self[0], self[1]))
+        let [a, b] = self;
+        IntoPaddingPair::into_padding_pair((a, b))
     }
 }
@@ -11,6 +11,7 @@ use crate::{
         Resource,
     },
     primitives::{
+        Calc,
         Display,
         FlexDirection,
     },
@@ -24,6 +25,7 @@ pub struct Block {
     visual: Appearance,
     typography: Typography,
     resources: Vec<Resource>,
+    expressions: Vec<Calc>,
     /// Last non-none display value preserved for visibility toggling.
     last_display: taffy::Display,
 }
@@ -36,7 +38,8 @@ impl_node_builder! {
             this.layout,
             this.visual,
             Some(this.typography),
-            this.resources
+            this.resources,
+            this.expressions
         )
     }
 }
@@ -0,0 +1,104 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::Calc,
+};
+use taffy::prelude::*;
+
+/// Circle shape node.
+#[derive(Debug)]
+pub struct Circle {
+    meta: ShapeMeta,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Circle,
+    build(this) {
+        Node::new(
+            NodeKind::Shape(this.meta),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Circle {
+    /// Creates a new circle node.
+    ///
+    /// The circle is inscribed in the layout box of the node, so its radius
+    /// follows the smaller side of the box when the node is resized.
+    ///
+    /// # Arguments
+    /// - `radius`: The intrinsic radius of the circle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Circle(8.0).fill(rgb(0x00ff00))
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new(radius: f32) -> Self {
+        let diameter = radius.max(0.0) * 2.0;
+        let meta = ShapeMeta::new(Shape::Circle, diameter, diameter);
+
+        Self {
+            layout: Style {
+                size: Size {
+                    width: Dimension::length(meta.width),
+                    height: Dimension::length(meta.height),
+                },
+                ..Default::default()
+            },
+            meta,
+            visual: Default::default(),
+            typography: Default::default(),
+            resources: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+}
+
+impl Hideable for Circle {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Circle {}
+impl Dimensions for Circle {}
+impl Margin for Circle {}
+impl Opacity for Circle {}
+impl Positioned for Circle {}
+impl Transformation for Circle {}
+impl SelfAlignment for Circle {}
+impl Visibility for Circle {}
+impl FilterEffects for Circle {}
+impl Blendable for Circle {}
+impl Fillable for Circle {}
+impl Strokable for Circle {}
@@ -0,0 +1,103 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::Calc,
+};
+use taffy::prelude::*;
+
+/// Ellipse shape node.
+#[derive(Debug)]
+pub struct Ellipse {
+    meta: ShapeMeta,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Ellipse,
+    build(this) {
+        Node::new(
+            NodeKind::Shape(this.meta),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Ellipse {
+    /// Creates a new ellipse node.
+    ///
+    /// The ellipse is inscribed in the layout box of the node.
+    ///
+    /// # Arguments
+    /// - `rx`: The intrinsic horizontal radius of the ellipse.
+    /// - `ry`: The intrinsic vertical radius of the ellipse.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Ellipse(16.0, 8.0).fill(rgb(0x0000ff))
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new(rx: f32, ry: f32) -> Self {
+        let meta = ShapeMeta::new(Shape::Ellipse, rx.max(0.0) * 2.0, ry.max(0.0) * 2.0);
+
+        Self {
+            layout: Style {
+                size: Size {
+                    width: Dimension::length(meta.width),
+                    height: Dimension::length(meta.height),
+                },
+                ..Default::default()
+            },
+            meta,
+            visual: Default::default(),
+            typography: Default::default(),
+            resources: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+}
+
+impl Hideable for Ellipse {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Ellipse {}
+impl Dimensions for Ellipse {}
+impl Margin for Ellipse {}
+impl Opacity for Ellipse {}
+impl Positioned for Ellipse {}
+impl Transformation for Ellipse {}
+impl SelfAlignment for Ellipse {}
+impl Visibility for Ellipse {}
+impl FilterEffects for Ellipse {}
+impl Blendable for Ellipse {}
+impl Fillable for Ellipse {}
+impl Strokable for Ellipse {}
@@ -10,7 +10,10 @@ use crate::{
         Appearance,
         Resource,
     },
-    primitives::FlexDirection,
+    primitives::{
+        Calc,
+        FlexDirection,
+    },
 };
 use taffy::{
     Display,
@@ -24,6 +27,7 @@ pub struct Flex {
     visual: Appearance,
     typography: Typography,
     resources: Vec<Resource>,
+    expressions: Vec<Calc>,
 }
 
 impl_node_builder! {
@@ -34,7 +38,8 @@ impl_node_builder! {
             this.layout,
             this.visual,
             Some(this.typography),
-            this.resources
+            this.resources,
+            this.expressions
         )
     }
 }
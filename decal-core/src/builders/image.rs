@@ -12,7 +12,13 @@ use crate::{
         Appearance,
         Resource,
     },
-    primitives::CrossOrigin,
+    primitives::{
+        Calc,
+        CrossOrigin,
+        ObjectFit,
+        ObjectPosition,
+        PreserveAspectRatio,
+    },
 };
 use taffy::prelude::*;
 
@@ -24,6 +30,7 @@ pub struct Image {
     visual: Appearance,
     typography: Typography, // unused, only for satisfying the [`Sealed`] trait
     resources: Vec<Resource>,
+    expressions: Vec<Calc>,
 }
 
 impl_node_builder! {
@@ -34,7 +41,8 @@ impl_node_builder! {
             this.layout,
             this.visual,
             None,
-            this.resources
+            this.resources,
+            this.expressions
         )
     }
 }
@@ -54,10 +62,12 @@ impl Image {
     ///
     /// let width: f32 = 64.0;
     /// let height: f32 = 64.0;
+    /// let icon =
+    ///     ImageSource::svg(r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24" />"#);
     /// let scene = decal! {
     ///     Column {
     ///         Image("<image-href>", width, height)
-    ///         Image(ImageSource::svg("<markup>"), width, height)
+    ///         Image(icon, width, height)
     ///     }
     /// };
     /// ```
@@ -81,6 +91,67 @@ impl Image {
         }
     }
 
+    /// Creates a new image node sized by the intrinsic size of the image.
+    ///
+    /// Images are resolved by the engine before layout, using the same
+    /// resolvers and cache as rasterization. Images that cannot be resolved
+    /// have a zero intrinsic size.
+    ///
+    /// # Arguments
+    /// - `source`: The [`ImageSource`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// // height follows the aspect ratio of the image
+    /// let avatar = Image::auto("https://example.com/avatar.png").width(48);
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn auto<S>(source: S) -> Self
+    where
+        S: Into<ImageSource>,
+    {
+        Self {
+            meta: ImageMeta {
+                auto_size: true,
+                ..ImageMeta::new(source, 0.0, 0.0)
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new image node from inline SVG markup, sized by the intrinsic
+    /// size of the SVG document.
+    ///
+    /// # Arguments
+    /// - `svg`: The SVG markup.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let icon = r#"<svg xmlns="http://www.w3.org/2000/svg" width="24" height="24">
+    ///     <circle cx="12" cy="12" r="10" />
+    /// </svg>"#;
+    ///
+    /// // scaled to 48x48 through the view box of the SVG
+    /// let image = Image::svg(icon).width(48);
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn svg<S>(svg: S) -> Self
+    where
+        S: AsRef<str>,
+    {
+        Self::auto(ImageSource::svg(svg))
+    }
+
     /// Sets the cross-origin policy used when loading the image.
     ///
     /// # Arguments
@@ -95,6 +166,58 @@ impl Image {
         self.meta.cross_origin = cross_origin.into();
         self
     }
+
+    /// Sets how the image is scaled and aligned within the layout box.
+    ///
+    /// # Arguments
+    /// - `value`: The [`PreserveAspectRatio`] value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn preserve_aspect_ratio(mut self, value: PreserveAspectRatio) -> Self {
+        self.meta.preserve_aspect_ratio = value;
+        self
+    }
+
+    /// Sets how the image is resized to fit the layout box. This takes
+    /// precedence over [`preserve_aspect_ratio`].
+    ///
+    /// # Arguments
+    /// - `value`: The [`ObjectFit`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// // crops a non-square avatar into a square
+    /// let avatar = Image::new("https://example.com/avatar.png", 640.0, 480.0)
+    ///     .size(48)
+    ///     .object_fit(ObjectFit::Cover)
+    ///     .object_position(ObjectPosition::Top);
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    ///
+    /// [`preserve_aspect_ratio`]: Self::preserve_aspect_ratio
+    pub fn object_fit(mut self, value: ObjectFit) -> Self {
+        self.meta.object_fit = Some(value);
+        self
+    }
+
+    /// Sets the alignment of the image within the layout box. Only applies
+    /// when an [`ObjectFit`] is set.
+    ///
+    /// # Arguments
+    /// - `value`: The [`ObjectPosition`] value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn object_position(mut self, value: ObjectPosition) -> Self {
+        self.meta.object_position = value;
+        self
+    }
 }
 
 impl Hideable for Image {
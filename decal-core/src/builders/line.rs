@@ -0,0 +1,115 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::{
+        Calc,
+        Point,
+    },
+};
+use taffy::prelude::*;
+
+/// Line shape node.
+#[derive(Debug)]
+pub struct Line {
+    meta: ShapeMeta,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Line,
+    build(this) {
+        Node::new(
+            NodeKind::Shape(this.meta),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Line {
+    /// Creates a new line node between two points.
+    ///
+    /// The intrinsic size of the node is the bounding box of the points,
+    /// anchored at the origin. The points are scaled along with the layout
+    /// box of the node.
+    ///
+    /// # Arguments
+    /// - `from`: The start point convertible into [`Point`].
+    /// - `to`: The end point convertible into [`Point`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Line((0.0, 0.0), (120.0, 0.0))
+    ///         .stroke(rgb(0x000000))
+    ///         .stroke_width(2.0)
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new<P, Q>(from: P, to: Q) -> Self
+    where
+        P: Into<Point<f32>>,
+        Q: Into<Point<f32>>,
+    {
+        let meta = ShapeMeta::from_points(vec![from.into(), to.into()], |points| {
+            Shape::Line(points[0], points[1])
+        });
+
+        Self {
+            layout: Style {
+                size: Size {
+                    width: Dimension::length(meta.width),
+                    height: Dimension::length(meta.height),
+                },
+                ..Default::default()
+            },
+            meta,
+            visual: Default::default(),
+            typography: Default::default(),
+            resources: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+}
+
+impl Hideable for Line {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Line {}
+impl Dimensions for Line {}
+impl Margin for Line {}
+impl Opacity for Line {}
+impl Positioned for Line {}
+impl Transformation for Line {}
+impl SelfAlignment for Line {}
+impl Visibility for Line {}
+impl FilterEffects for Line {}
+impl Blendable for Line {}
+impl Strokable for Line {}
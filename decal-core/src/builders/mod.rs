@@ -1,14 +1,28 @@
 mod block;
+mod circle;
 mod column;
+mod ellipse;
 mod flex;
 mod image;
+mod line;
+mod path;
+mod polygon;
+mod polyline;
+mod rectangle;
 mod row;
 mod text;
 
 pub use block::*;
+pub use circle::*;
 pub use column::*;
+pub use ellipse::*;
 pub use flex::*;
 pub use image::*;
+pub use line::*;
+pub use path::*;
+pub use polygon::*;
+pub use polyline::*;
+pub use rectangle::*;
 pub use row::*;
 pub use text::*;
 
@@ -0,0 +1,158 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::{
+        Calc,
+        PathData,
+        PathDataError,
+        PreserveAspectRatio,
+        ViewBox,
+    },
+};
+use taffy::prelude::*;
+
+/// Path shape node.
+#[derive(Debug, Default)]
+pub struct Path {
+    data: PathData,
+    view_box: Option<ViewBox>,
+    preserve_aspect_ratio: PreserveAspectRatio,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Path,
+    build(this) {
+        let view_box = this.view_box.unwrap_or_else(|| {
+            let (min_x, min_y, max_x, max_y) = this.data.bounds().unwrap_or_default();
+            ViewBox::new(min_x, min_y, max_x - min_x, max_y - min_y)
+        });
+
+        Node::new(
+            NodeKind::Shape(ShapeMeta::new(
+                Shape::Path {
+                    data: this.data,
+                    view_box,
+                    preserve_aspect_ratio: this.preserve_aspect_ratio,
+                },
+                view_box.width(),
+                view_box.height(),
+            )),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Path {
+    /// Creates a new path node.
+    ///
+    /// The intrinsic size of the node is the size of its view box, which
+    /// defaults to the bounding box of the path. The path is scaled into the
+    /// layout box of the node according to its [`PreserveAspectRatio`].
+    ///
+    /// # Arguments
+    /// - `data`: The [`PathData`] geometry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let chevron = PathData::new()
+    ///     .move_to((0.0, 0.0))
+    ///     .line_to((6.0, 6.0))
+    ///     .line_to((0.0, 12.0));
+    ///
+    /// let scene = decal! {
+    ///     Row {
+    ///         Path(chevron)
+    ///             .stroke(rgb(0x000000))
+    ///             .stroke_width(2.0)
+    ///     }
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new(data: PathData) -> Self {
+        Self {
+            data,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new path node from the value of an SVG `d` attribute.
+    ///
+    /// # Arguments
+    /// - `d`: The SVG path data.
+    ///
+    /// # Returns
+    /// - [`Self`] on success.
+    /// - [`PathDataError`] if the path data is malformed.
+    pub fn parse(d: &str) -> Result<Self, PathDataError> {
+        PathData::parse(d).map(Self::new)
+    }
+
+    /// Sets the view box mapped onto the layout box of the node.
+    ///
+    /// # Arguments
+    /// - `view_box`: The [`ViewBox`] value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn view_box(mut self, view_box: ViewBox) -> Self {
+        self.view_box = Some(view_box);
+        self
+    }
+
+    /// Sets how the view box is scaled and aligned within the layout box.
+    ///
+    /// # Arguments
+    /// - `value`: The [`PreserveAspectRatio`] value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn preserve_aspect_ratio(mut self, value: PreserveAspectRatio) -> Self {
+        self.preserve_aspect_ratio = value;
+        self
+    }
+}
+
+impl Hideable for Path {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Path {}
+impl Dimensions for Path {}
+impl Margin for Path {}
+impl Opacity for Path {}
+impl Positioned for Path {}
+impl Transformation for Path {}
+impl SelfAlignment for Path {}
+impl Visibility for Path {}
+impl FilterEffects for Path {}
+impl Blendable for Path {}
+impl Fillable for Path {}
+impl Strokable for Path {}
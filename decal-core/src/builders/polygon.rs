@@ -0,0 +1,113 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::{
+        Calc,
+        Point,
+    },
+};
+use taffy::prelude::*;
+
+/// Polygon shape node.
+#[derive(Debug)]
+pub struct Polygon {
+    meta: ShapeMeta,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Polygon,
+    build(this) {
+        Node::new(
+            NodeKind::Shape(this.meta),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Polygon {
+    /// Creates a new closed polygon node through the points.
+    ///
+    /// The intrinsic size of the node is the bounding box of the points,
+    /// anchored at the origin. The points are scaled along with the layout
+    /// box of the node.
+    ///
+    /// # Arguments
+    /// - `points`: The vertices convertible into [`Point`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Polygon([(8.0, 0.0), (16.0, 16.0), (0.0, 16.0)])
+    ///         .fill(rgb(0xffcc00))
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new<I, P>(points: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Point<f32>>,
+    {
+        let meta =
+            ShapeMeta::from_points(points.into_iter().map(Into::into).collect(), Shape::Polygon);
+
+        Self {
+            layout: Style {
+                size: Size {
+                    width: Dimension::length(meta.width),
+                    height: Dimension::length(meta.height),
+                },
+                ..Default::default()
+            },
+            meta,
+            visual: Default::default(),
+            typography: Default::default(),
+            resources: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+}
+
+impl Hideable for Polygon {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Polygon {}
+impl Dimensions for Polygon {}
+impl Margin for Polygon {}
+impl Opacity for Polygon {}
+impl Positioned for Polygon {}
+impl Transformation for Polygon {}
+impl SelfAlignment for Polygon {}
+impl Visibility for Polygon {}
+impl FilterEffects for Polygon {}
+impl Blendable for Polygon {}
+impl Fillable for Polygon {}
+impl Strokable for Polygon {}
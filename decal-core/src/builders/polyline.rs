@@ -0,0 +1,115 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::{
+        Calc,
+        Point,
+    },
+};
+use taffy::prelude::*;
+
+/// Polyline shape node.
+#[derive(Debug)]
+pub struct Polyline {
+    meta: ShapeMeta,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Polyline,
+    build(this) {
+        Node::new(
+            NodeKind::Shape(this.meta),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Polyline {
+    /// Creates a new open polyline node through the points.
+    ///
+    /// The intrinsic size of the node is the bounding box of the points,
+    /// anchored at the origin. The points are scaled along with the layout
+    /// box of the node.
+    ///
+    /// # Arguments
+    /// - `points`: The vertices convertible into [`Point`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Polyline([(0.0, 8.0), (8.0, 0.0), (16.0, 8.0)])
+    ///         .stroke(rgb(0x000000))
+    ///         .stroke_linecap(StrokeLineCap::Round)
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new<I, P>(points: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Point<f32>>,
+    {
+        let meta = ShapeMeta::from_points(
+            points.into_iter().map(Into::into).collect(),
+            Shape::Polyline,
+        );
+
+        Self {
+            layout: Style {
+                size: Size {
+                    width: Dimension::length(meta.width),
+                    height: Dimension::length(meta.height),
+                },
+                ..Default::default()
+            },
+            meta,
+            visual: Default::default(),
+            typography: Default::default(),
+            resources: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+}
+
+impl Hideable for Polyline {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Polyline {}
+impl Dimensions for Polyline {}
+impl Margin for Polyline {}
+impl Opacity for Polyline {}
+impl Positioned for Polyline {}
+impl Transformation for Polyline {}
+impl SelfAlignment for Polyline {}
+impl Visibility for Polyline {}
+impl FilterEffects for Polyline {}
+impl Blendable for Polyline {}
+impl Strokable for Polyline {}
@@ -0,0 +1,104 @@
+use crate::{
+    capabilities::*,
+    layout::{
+        Node,
+        NodeKind,
+        Shape,
+        ShapeMeta,
+        Typography,
+    },
+    macros::impl_node_builder,
+    paint::{
+        Appearance,
+        Resource,
+    },
+    primitives::Calc,
+};
+use taffy::prelude::*;
+
+/// Rectangle shape node.
+#[derive(Debug)]
+pub struct Rectangle {
+    meta: ShapeMeta,
+    layout: Style,
+    visual: Appearance,
+    typography: Typography, // unused, only for satisfying the [`Sealed`] trait
+    resources: Vec<Resource>,
+    expressions: Vec<Calc>,
+}
+
+impl_node_builder! {
+    Rectangle,
+    build(this) {
+        Node::new(
+            NodeKind::Shape(this.meta),
+            this.layout,
+            this.visual,
+            None,
+            this.resources,
+            this.expressions
+        )
+    }
+}
+
+impl Rectangle {
+    /// Creates a new rectangle node with a fixed intrinsic size.
+    ///
+    /// # Arguments
+    /// - `width`: The intrinsic width of the rectangle.
+    /// - `height`: The intrinsic height of the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Rectangle(64.0, 32.0)
+    ///         .corner_radius(8.0)
+    ///         .fill(rgb(0xff0000))
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new(width: f32, height: f32) -> Self {
+        let meta = ShapeMeta::new(Shape::Rectangle, width, height);
+
+        Self {
+            layout: Style {
+                size: Size {
+                    width: Dimension::length(meta.width),
+                    height: Dimension::length(meta.height),
+                },
+                ..Default::default()
+            },
+            meta,
+            visual: Default::default(),
+            typography: Default::default(),
+            resources: Vec::new(),
+            expressions: Vec::new(),
+        }
+    }
+}
+
+impl Hideable for Rectangle {
+    fn hidden(mut self, value: bool) -> Self {
+        self.layout.display = if value { Display::None } else { Display::Block };
+        self
+    }
+}
+
+impl AspectRatio for Rectangle {}
+impl RoundedCorners for Rectangle {}
+impl Dimensions for Rectangle {}
+impl Margin for Rectangle {}
+impl Opacity for Rectangle {}
+impl Positioned for Rectangle {}
+impl Transformation for Rectangle {}
+impl SelfAlignment for Rectangle {}
+impl Visibility for Rectangle {}
+impl FilterEffects for Rectangle {}
+impl Blendable for Rectangle {}
+impl Fillable for Rectangle {}
+impl Strokable for Rectangle {}
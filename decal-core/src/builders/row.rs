@@ -10,6 +10,7 @@ use crate::{
         Appearance,
         Resource,
     },
+    primitives::Calc,
 };
 use taffy::prelude::*;
 
@@ -20,6 +21,7 @@ pub struct Row {
     visual: Appearance,
     typography: Typography,
     resources: Vec<Resource>,
+    expressions: Vec<Calc>,
 }
 
 impl_node_builder!(
@@ -30,7 +32,8 @@ impl_node_builder!(
             this.layout,
             this.visual,
             Some(this.typography),
-            this.resources
+            this.resources,
+            this.expressions
         )
     }
 );
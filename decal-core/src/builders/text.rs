@@ -16,10 +16,16 @@ use crate::{
         Resource,
     },
     primitives::{
+        Calc,
         Ellipsize,
+        FontFeature,
         FontStyle,
+        FontVariation,
         FontWeight,
+        IntoOptionalLayoutLength,
+        IntoOptionalTextSpacing,
         Paint,
+        TextDecoration,
     },
 };
 use taffy::prelude::*;
@@ -32,6 +38,7 @@ pub struct Text {
     visual: Appearance,
     typography: Typography,
     resources: Vec<Resource>,
+    expressions: Vec<Calc>,
 }
 
 impl_node_builder!(
@@ -45,7 +52,8 @@ impl_node_builder!(
             this.layout,
             this.visual,
             Some(this.typography),
-            this.resources
+            this.resources,
+            this.expressions
         )
     }
 );
@@ -300,9 +308,9 @@ impl TextSpan {
     /// - [`Self`]
     pub fn size<T>(mut self, size: T) -> Self
     where
-        T: Into<Option<f32>>,
+        T: IntoOptionalLayoutLength<false, false>,
     {
-        self.typography.size = size.into();
+        self.typography.size = size.into_optional_layout_length();
         self
     }
 
@@ -315,9 +323,9 @@ impl TextSpan {
     /// - [`Self`]
     pub fn line_height<T>(mut self, line_height: T) -> Self
     where
-        T: Into<Option<f32>>,
+        T: IntoOptionalLayoutLength<false, false>,
     {
-        self.typography.line_height = line_height.into();
+        self.typography.line_height = line_height.into_optional_layout_length();
         self
     }
 
@@ -375,15 +383,63 @@ impl TextSpan {
     /// Sets the letter spacing for the text span.
     ///
     /// # Arguments
-    /// - `letter_spacing`: The letter spacing.
+    /// - `letter_spacing`: The letter spacing, in ems for bare numbers, see
+    ///   [`TextSpacing`].
     ///
     /// # Returns
     /// - [`Self`]
+    ///
+    /// [`TextSpacing`]: crate::primitives::TextSpacing
     pub fn letter_spacing<T>(mut self, letter_spacing: T) -> Self
     where
-        T: Into<Option<f32>>,
+        T: IntoOptionalTextSpacing,
+    {
+        self.typography.letter_spacing = letter_spacing.into_optional_text_spacing();
+        self
+    }
+
+    /// Sets the word spacing for the text span.
+    ///
+    /// # Arguments
+    /// - `word_spacing`: The spacing added to each word separator.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn word_spacing<T>(mut self, word_spacing: T) -> Self
+    where
+        T: IntoOptionalTextSpacing,
     {
-        self.typography.letter_spacing = letter_spacing.into();
+        self.typography.word_spacing = word_spacing.into_optional_text_spacing();
+        self
+    }
+
+    /// Sets the OpenType features for the text span.
+    ///
+    /// # Arguments
+    /// - `features`: The [`FontFeature`] settings to apply.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn features<I>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = FontFeature>,
+    {
+        self.typography.features = Some(features.into_iter().collect());
+        self
+    }
+
+    /// Sets the variable font axis values for the text span.
+    ///
+    /// # Arguments
+    /// - `variations`: The [`FontVariation`] settings to apply.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn variations<I>(mut self, variations: I) -> Self
+    where
+        I: IntoIterator<Item = FontVariation>,
+    {
+        self.typography.variations = Some(variations.into_iter().collect());
         self
     }
 
@@ -402,6 +458,27 @@ impl TextSpan {
         self
     }
 
+    /// Sets the decoration line for the text span.
+    ///
+    /// # Arguments
+    /// - `decoration`: The [`TextDecoration`] to apply.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn decoration<T>(mut self, decoration: T) -> Self
+    where
+        T: Into<Option<TextDecoration>>,
+    {
+        let decoration = decoration.into();
+
+        if let Some(resources) = decoration.clone().map(|d| d.into_resources()) {
+            self.resources.extend(resources);
+        }
+
+        self.typography.decoration = decoration;
+        self
+    }
+
     /// Marks the text span as hidden or visible.
     ///
     /// # Arguments
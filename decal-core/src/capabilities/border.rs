@@ -5,7 +5,7 @@ use crate::{
         IntoBorderPair,
         IntoPaintStack,
     },
-    primitives::IntoOptionalLength,
+    primitives::IntoOptionalLayoutLength,
 };
 
 macro_rules! impl_side {
@@ -13,16 +13,17 @@ macro_rules! impl_side {
         #[doc = concat!("Sets the border width for the `", stringify!($field), "` side.")]
         #[doc = ""]
         #[doc = "# Arguments"]
-        #[doc = "- `value`: The border width convertible using [`IntoOptionalLength`]."]
+        #[doc = "- `value`: The border width convertible using [`IntoOptionalLayoutLength`]."]
         #[doc = ""]
         #[doc = "# Returns"]
         #[doc = "- [`Self`]"]
         fn $method<T>(mut self, value: T) -> Self
         where
-            T: IntoOptionalLength<false, true>,
+            T: IntoOptionalLayoutLength<false, true>,
         {
-            self.layout_mut().border.$field =
-                value.into_optional_length().unwrap_or_default().into();
+            let value = value.into_optional_layout_length().unwrap_or_default();
+            self.retain_expressions(&value);
+            self.layout_mut().border.$field = value.into();
             self
         }
     };
@@ -41,7 +42,9 @@ pub trait Border: Drawable {
     where
         T: IntoBorder,
     {
-        self.layout_mut().border = value.into_border().unwrap_or_default().into();
+        let value = value.into_border().unwrap_or_default();
+        self.retain_expressions(&value);
+        self.layout_mut().border = value.into();
         self
     }
 
@@ -58,6 +61,8 @@ pub trait Border: Drawable {
         T: IntoBorderPair,
     {
         let (left, right) = value.into_border_pair().unwrap_or_default();
+        self.retain_expressions(&left);
+        self.retain_expressions(&right);
         self.layout_mut().border.left = left.into();
         self.layout_mut().border.right = right.into();
         self
@@ -76,6 +81,8 @@ pub trait Border: Drawable {
         T: IntoBorderPair,
     {
         let (top, bottom) = value.into_border_pair().unwrap_or_default();
+        self.retain_expressions(&top);
+        self.retain_expressions(&bottom);
         self.layout_mut().border.top = top.into();
         self.layout_mut().border.bottom = bottom.into();
         self
@@ -1,7 +1,7 @@
 use super::Drawable;
 use crate::{
     attributes::IntoCornerRadius,
-    primitives::IntoOptionalLength,
+    primitives::IntoOptionalLayoutLength,
 };
 
 macro_rules! impl_corner {
@@ -9,16 +9,16 @@ macro_rules! impl_corner {
         #[doc = concat!("Sets the corner radius for the `", stringify!($field), "` corner.")]
         #[doc = ""]
         #[doc = "# Arguments"]
-        #[doc = "- `value`: The corner radius value convertible using [`IntoOptionalLength`]."]
+        #[doc = "- `value`: The corner radius value convertible using [`IntoOptionalLayoutLength`]."]
         #[doc = ""]
         #[doc = "# Returns"]
         #[doc = "- [`Self`]"]
         fn $method<T>(mut self, value: T) -> Self
         where
-            T: IntoOptionalLength<false, true>,
+            T: IntoOptionalLayoutLength<false, true>,
         {
             self.visual_mut().corner_radius.$field =
-                value.into_optional_length().unwrap_or_default();
+                value.into_optional_layout_length().unwrap_or_default();
             self
         }
     };
@@ -1,7 +1,7 @@
 use super::Drawable;
 use crate::{
     attributes::IntoDimensions,
-    primitives::IntoOptionalLength,
+    primitives::IntoOptionalLayoutLength,
 };
 
 macro_rules! impl_dimension {
@@ -9,17 +9,18 @@ macro_rules! impl_dimension {
         #[doc = concat!("Sets the ", stringify!($field), " dimension of the node.")]
         #[doc = ""]
         #[doc = "# Arguments"]
-        #[doc = "- `value`: The dimension value convertible using [`IntoOptionalLength`]."]
+        #[doc = "- `value`: The dimension value convertible using [`IntoOptionalLayoutLength`]."]
         #[doc = ""]
         #[doc = "# Returns"]
         #[doc = "- [`Self`]"]
         fn $method<T>(mut self, value: T) -> Self
         where
-            T: IntoOptionalLength,
+            T: IntoOptionalLayoutLength,
         {
-            self.layout_mut().$taffy_method.$taffy_field = value
-                .into_optional_length()
-                .map_or(taffy::Dimension::auto(), |inner| inner.into());
+            let value = value.into_optional_layout_length();
+            self.retain_expressions(&value);
+            self.layout_mut().$taffy_method.$taffy_field =
+                value.map_or(taffy::Dimension::auto(), |inner| inner.into());
             self
         }
     };
@@ -38,9 +39,9 @@ pub trait Dimensions: Drawable {
     where
         T: IntoDimensions,
     {
-        self.layout_mut().size = value
-            .into_dimensions()
-            .map_or(taffy::Size::auto(), |inner| inner.into());
+        let value = value.into_dimensions();
+        self.retain_expressions(&value);
+        self.layout_mut().size = value.map_or(taffy::Size::auto(), |inner| inner.into());
         self
     }
 
@@ -56,9 +57,9 @@ pub trait Dimensions: Drawable {
     where
         T: IntoDimensions,
     {
-        self.layout_mut().min_size = value
-            .into_dimensions()
-            .map_or(taffy::Size::auto(), |inner| inner.into());
+        let value = value.into_dimensions();
+        self.retain_expressions(&value);
+        self.layout_mut().min_size = value.map_or(taffy::Size::auto(), |inner| inner.into());
         self
     }
 
@@ -74,9 +75,9 @@ pub trait Dimensions: Drawable {
     where
         T: IntoDimensions,
     {
-        self.layout_mut().max_size = value
-            .into_dimensions()
-            .map_or(taffy::Size::auto(), |inner| inner.into());
+        let value = value.into_dimensions();
+        self.retain_expressions(&value);
+        self.layout_mut().max_size = value.map_or(taffy::Size::auto(), |inner| inner.into());
         self
     }
 
@@ -0,0 +1,38 @@
+use super::Drawable;
+use crate::{
+    attributes::IntoPaintStack,
+    primitives::FillRule,
+};
+
+/// Capability for configuring the fill of a shape node.
+pub trait Fillable: Drawable {
+    /// Sets the paint used to fill the interior of the shape.
+    ///
+    /// # Arguments
+    /// - `value`: The fill paint configuration convertible into a paint stack
+    ///   using [`IntoPaintStack`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn fill<T>(mut self, value: T) -> Self
+    where
+        T: IntoPaintStack,
+    {
+        let fill = value.into_paint_stack();
+        self.visual_mut().fill = fill.clone();
+        self.add_resources(fill);
+        self
+    }
+
+    /// Sets the rule used to determine the interior of the shape.
+    ///
+    /// # Arguments
+    /// - `value`: The [`FillRule`] applied when filling.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn fill_rule(mut self, value: FillRule) -> Self {
+        self.visual_mut().fill_rule = value;
+        self
+    }
+}
@@ -14,7 +14,9 @@ pub trait Gap: Drawable {
     where
         T: IntoGap,
     {
-        self.layout_mut().gap = value.into_gap().unwrap_or_default().into();
+        let value = value.into_gap().unwrap_or_default();
+        self.retain_expressions(&value);
+        self.layout_mut().gap = value.into();
         self
     }
 }
@@ -4,7 +4,7 @@ use crate::{
         IntoMargin,
         IntoMarginPair,
     },
-    primitives::IntoOptionalLength,
+    primitives::IntoOptionalLayoutLength,
 };
 
 macro_rules! impl_side {
@@ -12,16 +12,17 @@ macro_rules! impl_side {
         #[doc = concat!("Sets the margin for the `", stringify!($field), "` side.")]
         #[doc = ""]
         #[doc = "# Arguments"]
-        #[doc = "- `value`: The margin value convertible using [`IntoOptionalLength`]."]
+        #[doc = "- `value`: The margin value convertible using [`IntoOptionalLayoutLength`]."]
         #[doc = ""]
         #[doc = "# Returns"]
         #[doc = "- [`Self`]"]
         fn $method<T>(mut self, value: T) -> Self
         where
-            T: IntoOptionalLength,
+            T: IntoOptionalLayoutLength,
         {
-            self.layout_mut().margin.$field =
-                value.into_optional_length().unwrap_or_default().into();
+            let value = value.into_optional_layout_length().unwrap_or_default();
+            self.retain_expressions(&value);
+            self.layout_mut().margin.$field = value.into();
             self
         }
     };
@@ -40,7 +41,9 @@ pub trait Margin: Drawable {
     where
         T: IntoMargin,
     {
-        self.layout_mut().margin = value.into_margin().unwrap_or_default().into();
+        let value = value.into_margin().unwrap_or_default();
+        self.retain_expressions(&value);
+        self.layout_mut().margin = value.into();
         self
     }
 
@@ -57,6 +60,8 @@ pub trait Margin: Drawable {
         T: IntoMarginPair,
     {
         let (left, right) = value.into_margin_pair().unwrap_or_default();
+        self.retain_expressions(&left);
+        self.retain_expressions(&right);
         self.layout_mut().margin.left = left.into();
         self.layout_mut().margin.right = right.into();
         self
@@ -75,6 +80,8 @@ pub trait Margin: Drawable {
         T: IntoMarginPair,
     {
         let (top, bottom) = value.into_margin_pair().unwrap_or_default();
+        self.retain_expressions(&top);
+        self.retain_expressions(&bottom);
         self.layout_mut().margin.top = top.into();
         self.layout_mut().margin.bottom = bottom.into();
         self
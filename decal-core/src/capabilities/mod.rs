@@ -7,6 +7,7 @@ mod container_alignment;
 mod corner_radius;
 mod dimensions;
 mod drawable;
+mod fillable;
 mod filter_effects;
 mod flex_container;
 mod gap;
@@ -17,6 +18,7 @@ mod padding;
 mod positioned;
 mod sealed;
 mod self_alignment;
+mod strokable;
 mod textual;
 mod transformation;
 mod visibility;
@@ -30,6 +32,7 @@ pub use container_alignment::*;
 pub use corner_radius::*;
 pub use dimensions::*;
 pub use drawable::*;
+pub use fillable::*;
 pub use filter_effects::*;
 pub use flex_container::*;
 pub use gap::*;
@@ -39,6 +42,7 @@ pub use opacity::*;
 pub use padding::*;
 pub use positioned::*;
 pub use self_alignment::*;
+pub use strokable::*;
 pub use textual::*;
 pub use transformation::*;
 pub use visibility::*;
@@ -4,7 +4,7 @@ use crate::{
         IntoPadding,
         IntoPaddingPair,
     },
-    primitives::IntoOptionalLength,
+    primitives::IntoOptionalLayoutLength,
 };
 
 macro_rules! impl_side {
@@ -12,16 +12,17 @@ macro_rules! impl_side {
         #[doc = concat!("Sets the padding for the `", stringify!($field), "` side.")]
         #[doc = ""]
         #[doc = "# Arguments"]
-        #[doc = "- `value`: The padding value convertible using [`IntoOptionalLength`]."]
+        #[doc = "- `value`: The padding value convertible using [`IntoOptionalLayoutLength`]."]
         #[doc = ""]
         #[doc = "# Returns"]
         #[doc = "- [`Self`]"]
         fn $method<T>(mut self, value: T) -> Self
         where
-            T: IntoOptionalLength<false, true>,
+            T: IntoOptionalLayoutLength<false, true>,
         {
-            self.layout_mut().padding.$field =
-                value.into_optional_length().unwrap_or_default().into();
+            let value = value.into_optional_layout_length().unwrap_or_default();
+            self.retain_expressions(&value);
+            self.layout_mut().padding.$field = value.into();
             self
         }
     };
@@ -40,7 +41,9 @@ pub trait Padding: Drawable {
     where
         T: IntoPadding,
     {
-        self.layout_mut().padding = value.into_padding().unwrap_or_default().into();
+        let value = value.into_padding().unwrap_or_default();
+        self.retain_expressions(&value);
+        self.layout_mut().padding = value.into();
         self
     }
 
@@ -57,6 +60,8 @@ pub trait Padding: Drawable {
         T: IntoPaddingPair,
     {
         let (left, right) = value.into_padding_pair().unwrap_or_default();
+        self.retain_expressions(&left);
+        self.retain_expressions(&right);
         self.layout_mut().padding.left = left.into();
         self.layout_mut().padding.right = right.into();
         self
@@ -75,6 +80,8 @@ pub trait Padding: Drawable {
         T: IntoPaddingPair,
     {
         let (top, bottom) = value.into_padding_pair().unwrap_or_default();
+        self.retain_expressions(&top);
+        self.retain_expressions(&bottom);
         self.layout_mut().padding.top = top.into();
         self.layout_mut().padding.bottom = bottom.into();
         self
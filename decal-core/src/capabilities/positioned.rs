@@ -1,7 +1,45 @@
 use super::Drawable;
-use crate::primitives::Position;
+use crate::{
+    attributes::{
+        IntoInset,
+        IntoInsetPair,
+    },
+    primitives::{
+        IntoOptionalLayoutLength,
+        Length,
+        Position,
+    },
+};
+
+macro_rules! impl_side {
+    ($method:ident, $field:ident) => {
+        #[doc = concat!("Sets the inset of the `", stringify!($field), "` edge.")]
+        #[doc = ""]
+        #[doc = "# Arguments"]
+        #[doc = "- `value`: The inset value convertible using [`IntoOptionalLayoutLength`]. `None` resets the edge to `auto`."]
+        #[doc = ""]
+        #[doc = "# Returns"]
+        #[doc = "- [`Self`]"]
+        fn $method<T>(mut self, value: T) -> Self
+        where
+            T: IntoOptionalLayoutLength,
+        {
+            let value = value
+                .into_optional_layout_length()
+                .unwrap_or(Length::auto().into());
+            self.retain_expressions(&value);
+            self.layout_mut().inset.$field = value.into();
+            self
+        }
+    };
+}
 
 /// Capability for configuring the positioning of a node.
+///
+/// Insets offset a relatively positioned node from its position in the
+/// normal flow, and place an absolutely positioned node relative to the
+/// padding box of its parent. Percentages resolve against the size of the
+/// parent along the same axis.
 pub trait Positioned: Drawable {
     /// Sets the positioning mode.
     ///
@@ -14,4 +52,70 @@ pub trait Positioned: Drawable {
         self.layout_mut().position = value.into();
         self
     }
+
+    /// Sets the inset for all four edges of the node.
+    ///
+    /// # Arguments
+    /// - `value`: The inset definition convertible using [`IntoInset`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn inset<T>(mut self, value: T) -> Self
+    where
+        T: IntoInset,
+    {
+        let value = value.into_inset();
+        self.retain_expressions(&value);
+        self.layout_mut().inset = value.map(Into::into).unwrap_or(taffy::Rect::auto());
+        self
+    }
+
+    /// Sets the horizontal insets for the left and right edges.
+    ///
+    /// # Arguments
+    /// - `value`: The horizontal inset values convertible using
+    ///   [`IntoInsetPair`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn inset_x<T>(mut self, value: T) -> Self
+    where
+        T: IntoInsetPair,
+    {
+        let (left, right) = value
+            .into_inset_pair()
+            .unwrap_or((Length::auto().into(), Length::auto().into()));
+        self.retain_expressions(&left);
+        self.retain_expressions(&right);
+        self.layout_mut().inset.left = left.into();
+        self.layout_mut().inset.right = right.into();
+        self
+    }
+
+    /// Sets the vertical insets for the top and bottom edges.
+    ///
+    /// # Arguments
+    /// - `value`: The vertical inset values convertible using
+    ///   [`IntoInsetPair`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn inset_y<T>(mut self, value: T) -> Self
+    where
+        T: IntoInsetPair,
+    {
+        let (top, bottom) = value
+            .into_inset_pair()
+            .unwrap_or((Length::auto().into(), Length::auto().into()));
+        self.retain_expressions(&top);
+        self.retain_expressions(&bottom);
+        self.layout_mut().inset.top = top.into();
+        self.layout_mut().inset.bottom = bottom.into();
+        self
+    }
+
+    impl_side!(top, top);
+    impl_side!(right, right);
+    impl_side!(bottom, bottom);
+    impl_side!(left, left);
 }
@@ -6,6 +6,10 @@ pub(crate) mod private {
             IntoResources,
             Resource,
         },
+        primitives::{
+            Calc,
+            Expressions,
+        },
     };
     use taffy::Style;
 
@@ -42,6 +46,10 @@ pub(crate) mod private {
         #[allow(private_interfaces)]
         fn resources_mut(&mut self) -> &mut Vec<Resource>;
 
+        /// Returns a mutable reference to the expressions referenced by the
+        /// node layout.
+        fn expressions_mut(&mut self) -> &mut Vec<Calc>;
+
         /// Adds resources derived from the provided value to the node.
         ///
         /// # Arguments
@@ -53,5 +61,19 @@ pub(crate) mod private {
         {
             self.resources_mut().extend(value.into_resources());
         }
+
+        /// Keeps the expressions held by the provided value alive with the
+        /// node. The layout only stores pointers to the expressions, so this
+        /// must be called before the value is written into it.
+        ///
+        /// # Arguments
+        /// - `value`: The value holding the expressions.
+        #[allow(private_bounds)]
+        fn retain_expressions<T>(&mut self, value: &T)
+        where
+            T: Expressions,
+        {
+            value.collect_expressions(self.expressions_mut());
+        }
     }
 }
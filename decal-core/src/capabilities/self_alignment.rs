@@ -3,7 +3,7 @@ use super::Drawable;
 use crate::primitives::JustifySelf;
 use crate::primitives::{
     AlignSelf,
-    IntoOptionalLength,
+    IntoOptionalLayoutLength,
 };
 #[cfg(feature = "grid")]
 use taffy::style::GridPlacement;
@@ -32,18 +32,17 @@ pub trait SelfAlignment: Drawable {
     ///
     /// # Arguments
     /// - `value`: The flex basis length convertible using
-    ///   [`IntoOptionalLength`].
+    ///   [`IntoOptionalLayoutLength`].
     ///
     /// # Returns
     /// - [`Self`]
     fn flex_basis<T>(mut self, value: T) -> Self
     where
-        T: IntoOptionalLength,
+        T: IntoOptionalLayoutLength,
     {
-        self.layout_mut().flex_basis = value
-            .into_optional_length()
-            .map(Into::into)
-            .unwrap_or(taffy::Dimension::auto());
+        let value = value.into_optional_layout_length();
+        self.retain_expressions(&value);
+        self.layout_mut().flex_basis = value.map(Into::into).unwrap_or(taffy::Dimension::auto());
         self
     }
 
@@ -0,0 +1,106 @@
+use super::Drawable;
+use crate::{
+    attributes::IntoPaintStack,
+    primitives::{
+        StrokeLineCap,
+        StrokeLineJoin,
+    },
+};
+
+/// Capability for configuring the stroke of a shape node.
+pub trait Strokable: Drawable {
+    /// Sets the paint used to stroke the outline of the shape.
+    ///
+    /// # Arguments
+    /// - `value`: The stroke paint configuration convertible into a paint stack
+    ///   using [`IntoPaintStack`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke<T>(mut self, value: T) -> Self
+    where
+        T: IntoPaintStack,
+    {
+        let stroke = value.into_paint_stack();
+        self.visual_mut().stroke.paint = stroke.clone();
+        self.add_resources(stroke);
+        self
+    }
+
+    /// Sets the width of the stroke (clamped to `>= 0.0`).
+    ///
+    /// # Arguments
+    /// - `value`: The stroke width in pixels.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke_width(mut self, value: f32) -> Self {
+        self.visual_mut().stroke.width = value.max(0.0);
+        self
+    }
+
+    /// Sets the dash pattern of the stroke.
+    ///
+    /// # Arguments
+    /// - `value`: The alternating dash and gap lengths. Negative lengths are
+    ///   clamped to `0.0`, and an empty pattern draws a solid stroke.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke_dasharray<I>(mut self, value: I) -> Self
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        self.visual_mut().stroke.dash_array = value.into_iter().map(|x| x.max(0.0)).collect();
+        self
+    }
+
+    /// Sets the offset into the dash pattern at which the stroke starts.
+    ///
+    /// # Arguments
+    /// - `value`: The dash offset in pixels.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke_dashoffset(mut self, value: f32) -> Self {
+        self.visual_mut().stroke.dash_offset = value;
+        self
+    }
+
+    /// Sets the shape drawn at the ends of open subpaths.
+    ///
+    /// # Arguments
+    /// - `value`: The [`StrokeLineCap`] applied to the stroke.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke_linecap(mut self, value: StrokeLineCap) -> Self {
+        self.visual_mut().stroke.line_cap = value;
+        self
+    }
+
+    /// Sets the shape drawn at the corners of the stroke.
+    ///
+    /// # Arguments
+    /// - `value`: The [`StrokeLineJoin`] applied to the stroke.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke_linejoin(mut self, value: StrokeLineJoin) -> Self {
+        self.visual_mut().stroke.line_join = value;
+        self
+    }
+
+    /// Sets the limit on the ratio of the miter length to the stroke width
+    /// (clamped to `>= 1.0`).
+    ///
+    /// # Arguments
+    /// - `value`: The miter limit.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn stroke_miterlimit(mut self, value: f32) -> Self {
+        self.visual_mut().stroke.miter_limit = value.max(1.0);
+        self
+    }
+}
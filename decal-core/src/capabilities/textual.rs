@@ -1,10 +1,15 @@
 use super::Drawable;
 use crate::primitives::{
     Ellipsize,
+    FontFeature,
     FontStyle,
+    FontVariation,
     FontWeight,
+    IntoOptionalLayoutLength,
+    IntoOptionalTextSpacing,
     Paint,
     TextAlign,
+    TextDecoration,
     TextWrap,
 };
 
@@ -45,31 +50,36 @@ pub trait Textual: Drawable {
 
     /// Sets the font size of text.
     ///
+    /// Sizes may be given in viewport units, such as `vw(4.0)`, to scale text
+    /// with the scene.
+    ///
     /// # Arguments
-    /// - `font_size`: The font size.
+    /// - `font_size`: The font size convertible using
+    ///   [`IntoOptionalLayoutLength`].
     ///
     /// # Returns
     /// - [`Self`]
     fn font_size<T>(mut self, font_size: T) -> Self
     where
-        T: Into<Option<f32>>,
+        T: IntoOptionalLayoutLength<false, false>,
     {
-        self.typography_mut().size = font_size.into();
+        self.typography_mut().size = font_size.into_optional_layout_length();
         self
     }
 
     /// Sets the line height of text.
     ///
     /// # Arguments
-    /// - `line_height`: The line height.
+    /// - `line_height`: The line height convertible using
+    ///   [`IntoOptionalLayoutLength`].
     ///
     /// # Returns
     /// - [`Self`]
     fn line_height<T>(mut self, line_height: T) -> Self
     where
-        T: Into<Option<f32>>,
+        T: IntoOptionalLayoutLength<false, false>,
     {
-        self.typography_mut().line_height = line_height.into();
+        self.typography_mut().line_height = line_height.into_optional_layout_length();
         self
     }
 
@@ -90,16 +100,100 @@ pub trait Textual: Drawable {
 
     /// Sets the letter spacing applied between text glyphs.
     ///
+    /// Bare numbers are relative to the font size, in ems. Lengths such as
+    /// `Length::units(2.0)` or `vw(1.0)` are given in layout units, see
+    /// [`TextSpacing`].
+    ///
     /// # Arguments
-    /// - `letter_spacing`: The spacing between characters.
+    /// - `letter_spacing`: The spacing between characters convertible using
+    ///   [`IntoOptionalTextSpacing`].
     ///
     /// # Returns
     /// - [`Self`]
+    ///
+    /// [`TextSpacing`]: crate::primitives::TextSpacing
     fn letter_spacing<T>(mut self, letter_spacing: T) -> Self
     where
-        T: Into<Option<f32>>,
+        T: IntoOptionalTextSpacing,
     {
-        self.typography_mut().letter_spacing = letter_spacing.into();
+        self.typography_mut().letter_spacing = letter_spacing.into_optional_text_spacing();
+        self
+    }
+
+    /// Sets the additional spacing applied to word separators, such as spaces.
+    ///
+    /// # Arguments
+    /// - `word_spacing`: The spacing added to each word separator, in the same
+    ///   units as [`letter_spacing`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    ///
+    /// [`letter_spacing`]: Textual::letter_spacing
+    fn word_spacing<T>(mut self, word_spacing: T) -> Self
+    where
+        T: IntoOptionalTextSpacing,
+    {
+        self.typography_mut().word_spacing = word_spacing.into_optional_text_spacing();
+        self
+    }
+
+    /// Sets the OpenType features applied while shaping text.
+    ///
+    /// # Arguments
+    /// - `features`: The [`FontFeature`] settings to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Text("1,234,567")
+    ///         .font_features([FontFeature::tabular_nums(), FontFeature::off(b"liga")])
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn font_features<I>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = FontFeature>,
+    {
+        self.typography_mut().features = Some(features.into_iter().collect());
+        self
+    }
+
+    /// Sets the variable font axis values used for text.
+    ///
+    /// The `wght` axis takes precedence over the [`font_weight`] and selects
+    /// the weight used for shaping. The remaining axes, such as `wdth` and
+    /// `opsz`, are applied to the glyph outlines only, so glyph advances keep
+    /// the widths of the shaped weight.
+    ///
+    /// # Arguments
+    /// - `variations`: The [`FontVariation`] settings to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Text("condensed")
+    ///         .font_variations([FontVariation::weight(650.0), FontVariation::width(75.0)])
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    ///
+    /// [`font_weight`]: Textual::font_weight
+    fn font_variations<I>(mut self, variations: I) -> Self
+    where
+        I: IntoIterator<Item = FontVariation>,
+    {
+        self.typography_mut().variations = Some(variations.into_iter().collect());
         self
     }
 
@@ -162,4 +256,36 @@ pub trait Textual: Drawable {
         self.typography_mut().ellipsize = value.into();
         self
     }
+
+    /// Sets the decoration line drawn on the text.
+    ///
+    /// # Arguments
+    /// - `value`: The [`TextDecoration`] to apply.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let scene = decal! {
+    ///     Text("underlined")
+    ///         .text_decoration(TextDecoration::underline().paint(rgb(0x0000ff)))
+    /// };
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn text_decoration<T>(mut self, value: T) -> Self
+    where
+        T: Into<Option<TextDecoration>>,
+    {
+        let decoration = value.into();
+
+        if let Some(ref decoration) = decoration {
+            self.add_resources(decoration.clone());
+        }
+
+        self.typography_mut().decoration = decoration;
+        self
+    }
 }
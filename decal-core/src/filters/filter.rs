@@ -6,7 +6,11 @@ use crate::{
         context::FilterContext,
         primitives::FilterPrimitive,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         FilterUnits,
@@ -151,11 +155,11 @@ impl FilterRegionConfig for Filter {}
 impl IsDefault for Filter {}
 impl ResourceIri for Filter {}
 
-impl Display for Filter {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Filter {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "filter")?
             .write(|out| self.region.fmt(out))?
-            .attr("id", (self.iri(),))?
+            .attr("id", (namespace.iri(self.iri()),))?
             .attr_if(
                 "filterUnits",
                 (&self.filter_units,),
@@ -174,7 +178,7 @@ impl Display for Filter {
             .content(|out| {
                 self.primitives
                     .iter()
-                    .try_for_each(|primitive| primitive.fmt(out))
+                    .try_for_each(|primitive| primitive.fmt_in(out, namespace))
             })?
             .close()
     }
@@ -203,13 +207,16 @@ impl<const N: usize> From<[Filter; N]> for Filter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
 
     #[test]
     fn renders_with_no_primitives() {
         let filter = Filter::new(|_| {});
         assert_xml(
-            filter.to_string(),
+            render(&filter),
             format!(r#"<filter id="{}"></filter>"#, filter.iri()),
         );
     }
@@ -218,7 +225,7 @@ mod tests {
     fn renders_with_filter_region() {
         let filter = Filter::new(|_| {}).x(0.5).y(0.6).width(110).height(120);
         assert_xml(
-            filter.to_string(),
+            render(&filter),
             format!(
                 r#"<filter id="{}" x="0.5" y="0.6" width="110" height="120"></filter>"#,
                 filter.iri()
@@ -232,11 +239,11 @@ mod tests {
             ctx.flood().finish();
             ctx.gaussian_blur().finish();
         });
-        let flood = &filter.primitives[0];
-        let blur = &filter.primitives[1];
+        let flood = render(&filter.primitives[0]);
+        let blur = render(&filter.primitives[1]);
 
         assert_xml(
-            filter.to_string(),
+            render(&filter),
             format!(
                 r#"
 <filter id="{}">
@@ -260,7 +267,7 @@ mod tests {
             .color_interpolation(color_interpolation);
 
         assert_xml(
-            filter.to_string(),
+            render(&filter),
             format!(
                 r#"
 <filter
@@ -291,7 +298,7 @@ mod tests {
         let primitives = &filter.primitives;
 
         assert_xml(
-            filter.to_string(),
+            render(&filter),
             format!(
                 r#"
 <filter id="{}">
@@ -299,9 +306,9 @@ mod tests {
 </filter>
 "#,
                 filter.iri(),
-                primitives[0],
-                primitives[1],
-                primitives[2]
+                render(&primitives[0]),
+                render(&primitives[1]),
+                render(&primitives[2]),
             ),
         );
     }
@@ -311,7 +318,7 @@ mod tests {
         let filter = Filter::from([Filter::new(|_| {}).x(-45), Filter::new(|_| {}).x(-30)]);
 
         assert_xml(
-            filter.to_string(),
+            render(&filter),
             format!(
                 r#"
 <filter id="{}" x="-30"></filter>
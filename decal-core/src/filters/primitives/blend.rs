@@ -4,7 +4,11 @@ use crate::{
         HasFilterRegion,
         primitives::PrimitiveBuilder,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         BlendMode,
         ColorInterpolation,
@@ -50,13 +54,19 @@ impl HasFilterRegion for Blend {
 
 impl ResourceIri for Blend {}
 
-impl Display for Blend {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Blend {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feBlend")?
             .write(|out| self.region.fmt(out))?
             .attrs([
-                ("in", self.input.map(|x| (x,))),
-                ("in2", self.input2.map(|x| (x,))),
+                (
+                    "in",
+                    self.input.as_ref().map(|x| (x.display_in(namespace),)),
+                ),
+                (
+                    "in2",
+                    self.input2.as_ref().map(|x| (x.display_in(namespace),)),
+                ),
             ])?
             .attr_if("mode", (self.mode,), !self.mode.is_default())?
             .attr_if(
@@ -64,7 +74,7 @@ impl Display for Blend {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -133,7 +143,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -143,7 +156,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feBlend x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -158,7 +171,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feBlend result="{}" />"#, node.iri()),
         );
     }
@@ -181,7 +194,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feBlend
@@ -192,7 +205,9 @@ mod tests {
     result="{}"
 />
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
+                input2 = render(&input2),
             ),
         );
     }
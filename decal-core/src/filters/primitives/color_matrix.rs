@@ -8,7 +8,11 @@ use crate::{
         ff32,
         pf32,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         FilterInput,
@@ -98,11 +102,14 @@ impl HasFilterRegion for ColorMatrix {
 
 impl ResourceIri for ColorMatrix {}
 
-impl Display for ColorMatrix {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for ColorMatrix {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         let mut color_matrix = ElementWriter::new(f, "feColorMatrix")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?;
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?;
 
         color_matrix = match self.kind {
             ColorMatrixType::Matrix(matrix) => {
@@ -129,7 +136,7 @@ impl Display for ColorMatrix {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -225,7 +232,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -241,7 +251,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feColorMatrix type="luminanceToAlpha" x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -256,7 +266,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feColorMatrix type="matrix" values="1 0 0 0 0 0 1 0 0 0 0 0 1 0 0 0 0 0 1 0" result="{}" />"#,
                 node.iri()
@@ -280,7 +290,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feColorMatrix type="matrix" values="1 0 0 0 1 0 1 0 0 2 0 0 1 0 3 0 0 0 1 4" result="{}" />"#,
                 node.iri()
@@ -295,7 +305,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feColorMatrix type="saturate" values="0.5" result="{}" />"#,
                 node.iri()
@@ -310,7 +320,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feColorMatrix type="hueRotate" values="30" result="{}" />"#,
                 node.iri()
@@ -325,7 +335,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feColorMatrix type="luminanceToAlpha" result="{}" />"#,
                 node.iri()
@@ -348,7 +358,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feColorMatrix
@@ -358,7 +368,8 @@ mod tests {
     result="{}"
 />
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -8,7 +8,11 @@ use crate::{
         ff32,
         nf32,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         FilterInput,
@@ -210,17 +214,20 @@ impl HasFilterRegion for ComponentTransfer {
 
 impl ResourceIri for ComponentTransfer {}
 
-impl Display for ComponentTransfer {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for ComponentTransfer {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feComponentTransfer")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if(
                 "color-interpolation-filters",
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .content(|out| {
                 self.func_r.serialize(out, "feFuncR")?;
                 self.func_g.serialize(out, "feFuncG")?;
@@ -317,7 +324,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -332,7 +342,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer
@@ -355,7 +365,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feComponentTransfer result="{}"></feComponentTransfer>"#,
                 node.iri()
@@ -375,7 +385,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer result="{}">
@@ -399,7 +409,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feComponentTransfer result="{}"></feComponentTransfer>"#,
                 node.iri()
@@ -416,7 +426,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer result="{}">
@@ -437,7 +447,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer result="{}">
@@ -458,7 +468,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer result="{}">
@@ -479,7 +489,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer result="{}">
@@ -503,7 +513,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComponentTransfer
@@ -512,7 +522,8 @@ mod tests {
     result="{}">
 </feComponentTransfer>
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
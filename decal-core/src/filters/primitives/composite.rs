@@ -5,7 +5,11 @@ use crate::{
         primitives::PrimitiveBuilder,
     },
     macros::ff32,
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         FilterInput,
@@ -151,13 +155,19 @@ impl HasFilterRegion for Composite {
 
 impl ResourceIri for Composite {}
 
-impl Display for Composite {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Composite {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         let mut composite = ElementWriter::new(f, "feComposite")?
             .write(|out| self.region.fmt(out))?
             .attrs([
-                ("in", self.input.map(|x| (x,))),
-                ("in2", self.input2.map(|x| (x,))),
+                (
+                    "in",
+                    self.input.as_ref().map(|x| (x.display_in(namespace),)),
+                ),
+                (
+                    "in2",
+                    self.input2.as_ref().map(|x| (x.display_in(namespace),)),
+                ),
             ])?
             .attr_if("operator", (self.operator,), !self.operator.is_default())?;
 
@@ -171,7 +181,7 @@ impl Display for Composite {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -241,7 +251,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -256,7 +269,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feComposite x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -271,7 +284,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feComposite result="{}" />"#, node.iri()),
         );
     }
@@ -285,7 +298,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComposite
@@ -320,7 +333,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feComposite
@@ -332,7 +345,9 @@ mod tests {
 />
 "#,
                 operator.0,
-                node.iri()
+                node.iri(),
+                input = render(&input),
+                input2 = render(&input2),
             ),
         );
     }
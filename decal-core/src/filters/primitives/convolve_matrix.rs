@@ -5,7 +5,11 @@ use crate::{
         primitives::PrimitiveBuilder,
     },
     macros::ff32,
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         EdgeMode,
@@ -105,11 +109,14 @@ impl HasFilterRegion for ConvolveMatrix {
 
 impl ResourceIri for ConvolveMatrix {}
 
-impl Display for ConvolveMatrix {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for ConvolveMatrix {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feConvolveMatrix")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if("order", (&self.order,), !self.order.is_default())?
             .write_attr("kernelMatrix", |out| {
                 write_spaced(out, self.kernel_matrix.iter(), |out, value| {
@@ -129,7 +136,7 @@ impl Display for ConvolveMatrix {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -267,7 +274,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -282,7 +292,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feConvolveMatrix
@@ -306,7 +316,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feConvolveMatrix kernelMatrix="0 0.1 0.2" result="{}" />"#,
                 node.iri()
@@ -336,7 +346,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feConvolveMatrix
@@ -353,7 +363,8 @@ mod tests {
     result="{}"
 />
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
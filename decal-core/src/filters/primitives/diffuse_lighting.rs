@@ -8,7 +8,11 @@ use crate::{
         ff32,
         pf32,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         Color,
         ColorInterpolation,
@@ -67,11 +71,14 @@ impl HasFilterRegion for DiffuseLighting {
 
 impl ResourceIri for DiffuseLighting {}
 
-impl Display for DiffuseLighting {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for DiffuseLighting {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feDiffuseLighting")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if(
                 "surfaceScale",
                 self.surface_scale,
@@ -88,7 +95,7 @@ impl Display for DiffuseLighting {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .content(|out| self.light_source.fmt(out))?
             .close()
     }
@@ -170,7 +177,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -186,7 +196,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feDiffuseLighting x="0.5" y="0.6" width="110" height="120" result="{}">
@@ -205,7 +215,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feDiffuseLighting result="{}">{light_source}</feDiffuseLighting>"#,
                 node.iri()
@@ -232,7 +242,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feDiffuseLighting
@@ -246,7 +256,8 @@ mod tests {
     {light_source}
 </feDiffuseLighting>
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
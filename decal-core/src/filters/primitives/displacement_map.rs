@@ -5,7 +5,11 @@ use crate::{
         primitives::PrimitiveBuilder,
     },
     macros::ff32,
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         FilterInput,
@@ -75,13 +79,16 @@ impl HasFilterRegion for DisplacementMap {
 
 impl ResourceIri for DisplacementMap {}
 
-impl Display for DisplacementMap {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for DisplacementMap {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feDisplacementMap")?
             .write(|out| self.region.fmt(out))?
             .attrs([
-                ("in", self.input.map(|x| (x,))),
-                ("in2", self.map.map(|x| (x,))),
+                (
+                    "in",
+                    self.input.as_ref().map(|x| (x.display_in(namespace),)),
+                ),
+                ("in2", self.map.as_ref().map(|x| (x.display_in(namespace),))),
             ])?
             .attr_if("scale", self.scale, self.scale.get() != 0.0)?
             .attr_if(
@@ -99,7 +106,7 @@ impl Display for DisplacementMap {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -192,7 +199,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -207,7 +217,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feDisplacementMap x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -222,7 +232,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feDisplacementMap result="{}" />"#, node.iri()),
         );
     }
@@ -248,7 +258,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feDisplacementMap
@@ -261,7 +271,9 @@ mod tests {
     result="{}"
 />
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
+                map = render(&map),
             ),
         );
     }
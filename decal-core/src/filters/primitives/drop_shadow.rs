@@ -8,7 +8,11 @@ use crate::{
         ff32,
         nf32,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         Color,
         ColorInterpolation,
@@ -62,11 +66,14 @@ impl HasFilterRegion for DropShadow {
 
 impl ResourceIri for DropShadow {}
 
-impl Display for DropShadow {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for DropShadow {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feDropShadow")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if("dx", self.dx, self.dx.get() != 2.0)?
             .attr_if("dy", self.dy, self.dy.get() != 2.0)?
             .attr_if(
@@ -81,7 +88,7 @@ impl Display for DropShadow {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -193,7 +200,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -208,7 +218,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feDropShadow x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -223,7 +233,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feDropShadow result="{}" />"#, node.iri()),
         );
     }
@@ -248,7 +258,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feDropShadow
@@ -262,7 +272,8 @@ mod tests {
     result="{}"
 />
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
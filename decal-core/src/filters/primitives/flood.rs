@@ -5,7 +5,11 @@ use crate::{
         primitives::PrimitiveBuilder,
     },
     macros::nf32,
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::Color,
     utils::ElementWriter,
 };
@@ -44,8 +48,8 @@ impl HasFilterRegion for Flood {
 
 impl ResourceIri for Flood {}
 
-impl Display for Flood {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Flood {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feFlood")?
             .write(|out| self.region.fmt(out))?
             .attr("flood-color", (self.color,))?
@@ -54,7 +58,7 @@ impl Display for Flood {
                 self.opacity,
                 self.opacity != NormalizedF32::ONE,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -94,7 +98,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -104,7 +111,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feFlood
@@ -129,7 +136,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feFlood flood-color="{}" result="{}" />"#,
                 Color::rgb(0, 0, 0),
@@ -146,7 +153,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feFlood
@@ -4,7 +4,11 @@ use crate::{
         HasFilterRegion,
         primitives::PrimitiveBuilder,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         EdgeMode,
@@ -51,11 +55,14 @@ impl HasFilterRegion for GaussianBlur {
 
 impl ResourceIri for GaussianBlur {}
 
-impl Display for GaussianBlur {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for GaussianBlur {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feGaussianBlur")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if(
                 "stdDeviation",
                 self.std_deviation,
@@ -67,7 +74,7 @@ impl Display for GaussianBlur {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -136,7 +143,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -151,7 +161,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feGaussianBlur x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -166,7 +176,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feGaussianBlur result="{}" />"#, node.iri()),
         );
     }
@@ -188,7 +198,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feGaussianBlur
@@ -199,7 +209,8 @@ mod tests {
     result="{}"
 />
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -4,7 +4,11 @@ use crate::{
         HasFilterRegion,
         primitives::PrimitiveBuilder,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::CrossOrigin,
     utils::ElementWriter,
 };
@@ -47,13 +51,13 @@ impl HasFilterRegion for Image {
 
 impl ResourceIri for Image {}
 
-impl Display for Image {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Image {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feImage")?
             .write(|out| self.region.fmt(out))?
             .attr("href", self.href.as_str())?
             .attr("crossorigin", self.cross_origin.map(|x| (x,)))?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -83,7 +87,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -98,7 +105,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feImage
@@ -122,7 +129,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feImage href="test" result="{}" />"#, node.iri()),
         );
     }
@@ -135,7 +142,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feImage
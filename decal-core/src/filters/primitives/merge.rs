@@ -4,7 +4,11 @@ use crate::{
         HasFilterRegion,
         primitives::PrimitiveBuilder,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         ColorInterpolation,
         FilterInput,
@@ -46,8 +50,8 @@ impl HasFilterRegion for Merge {
 
 impl ResourceIri for Merge {}
 
-impl Display for Merge {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Merge {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         if self.inputs.is_empty() {
             return Ok(());
         }
@@ -59,11 +63,11 @@ impl Display for Merge {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .content(|out| {
                 self.inputs.iter().try_for_each(|node| {
                     ElementWriter::new(out, "feMergeNode")?
-                        .attr("in", (node,))?
+                        .attr("in", (node.display_in(namespace),))?
                         .close()
                 })
             })?
@@ -128,7 +132,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -147,14 +154,15 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feMerge x="0.5" y="0.6" width="110" height="120" result="{}">
     <feMergeNode in="{input}" />
 </feMerge>
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -164,7 +172,7 @@ mod tests {
         let ctx = FilterContext::default();
         ctx.merge().finish();
         let node = &ctx.into_primitives()[0];
-        assert!(node.to_string().is_empty());
+        assert!(render(&node).is_empty());
     }
 
     #[test]
@@ -175,14 +183,15 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feMerge result="{}">
     <feMergeNode in="{input}" />
 </feMerge>
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -200,7 +209,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feMerge result="{}">
@@ -211,10 +220,10 @@ mod tests {
 </feMerge>
 "#,
                 node.iri(),
-                FilterInput::source_graphic(),
-                FilterInput::source_alpha(),
-                FilterInput::source_alpha(),
-                FilterInput::source_graphic(),
+                render(&FilterInput::source_graphic()),
+                render(&FilterInput::source_alpha()),
+                render(&FilterInput::source_alpha()),
+                render(&FilterInput::source_graphic()),
             ),
         );
     }
@@ -233,14 +242,15 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feMerge color-interpolation-filters="{color_interpolation}" result="{}">
     <feMergeNode in="{input}" />
 </feMerge>
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -5,7 +5,11 @@ use crate::{
         primitives::PrimitiveBuilder,
     },
     macros::ff32,
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::FilterInput,
     utils::ElementWriter,
 };
@@ -42,14 +46,17 @@ impl HasFilterRegion for Offset {
 
 impl ResourceIri for Offset {}
 
-impl Display for Offset {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Offset {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feOffset")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if("dx", self.dx, self.dx.get() != 0.0)?
             .attr_if("dy", self.dy, self.dy.get() != 0.0)?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -103,7 +110,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -113,7 +123,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feOffset x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -128,7 +138,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feOffset result="{}" />"#, node.iri()),
         );
     }
@@ -141,10 +151,11 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feOffset in="{input}" dx="2.5" dy="4.5" result="{}" />"#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -8,7 +8,11 @@ use crate::{
         ff32,
         pf32,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         Color,
         ColorInterpolation,
@@ -73,11 +77,14 @@ impl HasFilterRegion for SpecularLighting {
 
 impl ResourceIri for SpecularLighting {}
 
-impl Display for SpecularLighting {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for SpecularLighting {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feSpecularLighting")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
             .attr_if(
                 "surfaceScale",
                 self.surface_scale,
@@ -100,7 +107,7 @@ impl Display for SpecularLighting {
                 (&self.color_interpolation,),
                 self.color_interpolation != ColorInterpolation::LinearRgb,
             )?
-            .attr("result", (self.iri(),))?
+            .attr("result", (namespace.iri(self.iri()),))?
             .content(|out| self.light_source.fmt(out))?
             .close()
     }
@@ -210,7 +217,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -226,7 +236,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feSpecularLighting x="0.5" y="0.6" width="110" height="120" result="{}">
@@ -245,7 +255,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feSpecularLighting result="{}">{light_source}</feSpecularLighting>"#,
                 node.iri()
@@ -274,7 +284,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"
 <feSpecularLighting
@@ -290,7 +300,8 @@ mod tests {
     {light_source}
 </feSpecularLighting>
 "#,
-                node.iri()
+                node.iri(),
+                input = render(&input),
             ),
         );
     }
@@ -4,7 +4,11 @@ use crate::{
         HasFilterRegion,
         primitives::PrimitiveBuilder,
     },
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::FilterInput,
     utils::ElementWriter,
 };
@@ -38,12 +42,15 @@ impl HasFilterRegion for Tile {
 
 impl ResourceIri for Tile {}
 
-impl Display for Tile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Tile {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "feTile")?
             .write(|out| self.region.fmt(out))?
-            .attr("in", self.input.map(|x| (x,)))?
-            .attr("result", (self.iri(),))?
+            .attr(
+                "in",
+                self.input.as_ref().map(|x| (x.display_in(namespace),)),
+            )?
+            .attr("result", (namespace.iri(self.iri()),))?
             .close()
     }
 }
@@ -73,7 +80,10 @@ mod tests {
             FilterContext,
             FilterRegionConfig,
         },
-        test_utils::assert_xml,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
 
     #[test]
@@ -83,7 +93,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(
                 r#"<feTile x="0.5" y="0.6" width="110" height="120" result="{}" />"#,
                 node.iri()
@@ -98,7 +108,7 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
+            render(&node),
             format!(r#"<feTile result="{}" />"#, node.iri()),
         );
     }
@@ -111,8 +121,12 @@ mod tests {
         let node = &ctx.into_primitives()[0];
 
         assert_xml(
-            node.to_string(),
-            format!(r#"<feTile in="{input}" result="{}" />"#, node.iri()),
+            render(&node),
+            format!(
+                r#"<feTile in="{input}" result="{}" />"#,
+                node.iri(),
+                input = render(&input)
+            ),
         );
     }
 }
@@ -20,13 +20,12 @@ use crate::{
     },
     paint::{
         Iri,
+        IriNamespace,
+        NamespacedDisplay,
         ResourceIri,
     },
 };
-use std::fmt::{
-    Display,
-    Formatter,
-};
+use std::fmt::Formatter;
 
 /// The filter primitive wrapper.
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
@@ -74,9 +73,9 @@ macro_rules! delegate_primitive {
     };
 }
 
-impl Display for FilterPrimitive {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        delegate_primitive!(self, fmt(f))
+impl NamespacedDisplay for FilterPrimitive {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
+        delegate_primitive!(self, fmt_in(f, namespace))
     }
 }
 
@@ -1,5 +1,11 @@
 use crate::{
-    layout::Scene,
+    layout::{
+        EmbeddedFonts,
+        PathOptimization,
+        Scene,
+        TextMode,
+    },
+    paint::IriNamespace,
     primitives::Size,
 };
 use std::fmt::Write;
@@ -12,6 +18,16 @@ where
     pub(crate) scene: &'a Scene,
     pub(crate) out: &'a mut T,
     pub(crate) scene_size: Size<f32>,
+    pub(crate) text_mode: TextMode,
+    /// The scale at which color glyph bitmaps are rasterized.
+    pub(crate) bitmap_scale: f32,
+    /// The optimization applied to glyph outlines.
+    pub(crate) path_optimization: Option<PathOptimization>,
+    pub(crate) embedded_fonts: EmbeddedFonts,
+    /// The number of inline SVG images emitted so far.
+    pub(crate) svg_images: usize,
+    /// The namespace of the IRIs referenced by the document.
+    pub(crate) namespace: IriNamespace,
 }
 
 impl<'a, T> RenderContext<'a, T>
@@ -24,6 +40,12 @@ where
             scene,
             out,
             scene_size: Size::from_values(0.0, 0.0),
+            text_mode: TextMode::default(),
+            bitmap_scale: 1.0,
+            path_optimization: None,
+            embedded_fonts: EmbeddedFonts::default(),
+            svg_images: 0,
+            namespace: IriNamespace::default(),
         }
     }
 }
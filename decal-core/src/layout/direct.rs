@@ -0,0 +1,700 @@
+use crate::{
+    layout::{
+        FontRegistry,
+        Node,
+        NodeKind,
+        RasterizeOptions,
+        Scene,
+        SvgDimensions,
+        SvgFormat,
+        ROOT_ID,
+    },
+    paint::{
+        write_border_path,
+        write_fill_path,
+        Stroke,
+    },
+    primitives::{
+        Color,
+        FillRule,
+        PaintStack,
+        Rect,
+        StrokeLineCap,
+        StrokeLineJoin,
+    },
+    utils::{
+        round_float,
+        IsDefault,
+        PathSink,
+    },
+};
+use tiny_skia::{
+    ColorU8,
+    LineCap,
+    LineJoin,
+    Paint,
+    PathBuilder,
+    PixmapMut,
+    StrokeDash,
+    Transform,
+};
+
+/// Draws scenes made of solid fills and strokes directly into a pixmap.
+///
+/// Each element is drawn the way `resvg` draws the vectorized scene: paths are
+/// traced by the same geometry code that writes the path data and built the
+/// way `usvg` builds them from it, and node transforms are rounded to the
+/// written precision. The output is therefore identical to rasterizing the SVG
+/// document, without writing and parsing it.
+pub(crate) struct DirectRenderer<'a, 'p> {
+    target: &'a mut PixmapMut<'p>,
+    anti_alias: bool,
+    /// The builder of the path being drawn.
+    path: PathBuilderSink,
+}
+
+impl<'a, 'p> DirectRenderer<'a, 'p> {
+    /// Returns `true` if the scene can be drawn without vectorizing it.
+    ///
+    /// Scenes are supported when every visible node is a container, a text or
+    /// a shape without effects, clipping or layered paints, and filled or
+    /// stroked with a single opaque solid color.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to draw.
+    /// - `options`: The [`RasterizeOptions`] value.
+    pub(crate) fn supports(scene: &Scene, options: &RasterizeOptions) -> bool {
+        let vectorize = &options.vectorize_options;
+
+        if options.debug
+            || vectorize.omit_svg_xmlns
+            || vectorize.path_optimization.is_some()
+            || matches!(vectorize.format, SvgFormat::Minify { .. })
+            || matches!(vectorize.svg_dimensions, SvgDimensions::Custom { .. })
+        {
+            return false;
+        }
+
+        let Some(root) = scene.nodes.get(ROOT_ID) else {
+            return false;
+        };
+
+        if root.final_layout.size.width == 0.0 || root.final_layout.size.height == 0.0 {
+            return false;
+        }
+
+        let mut fonts = scene.fonts.lock();
+        let mut stack = vec![ROOT_ID];
+
+        while let Some(idx) = stack.pop() {
+            let node = &scene.nodes[idx];
+
+            if !is_displayed(node) {
+                continue;
+            }
+
+            if !supports_node(node, &mut fonts, options.scale) {
+                return false;
+            }
+
+            stack.extend(node.children.iter().copied());
+        }
+
+        true
+    }
+
+    /// Draws the scene into the target.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to draw, supported by [`Self::supports`].
+    /// - `target`: The pixmap to draw into.
+    /// - `transform`: The transform placing the scene in the target.
+    /// - `options`: The [`RasterizeOptions`] value.
+    pub(crate) fn draw(
+        scene: &Scene,
+        target: &'a mut PixmapMut<'p>,
+        transform: Transform,
+        options: &RasterizeOptions,
+    ) {
+        let mut renderer = Self {
+            target,
+            anti_alias: options.shape_rendering.use_shape_antialiasing(),
+            path: PathBuilderSink::default(),
+        };
+        let mut fonts = scene.fonts.lock();
+
+        renderer.draw_node(scene, &mut fonts, ROOT_ID, transform);
+    }
+
+    /// Draws a node and its children.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene containing the node.
+    /// - `fonts`: The mutable [`FontRegistry`] reference.
+    /// - `idx`: The index of the node.
+    /// - `transform`: The transform of the parent node.
+    fn draw_node(
+        &mut self,
+        scene: &Scene,
+        fonts: &mut FontRegistry,
+        idx: usize,
+        transform: Transform,
+    ) {
+        let node = &scene.nodes[idx];
+
+        if !is_displayed(node) {
+            return;
+        }
+
+        let taffy::Layout {
+            location: taffy::Point { x, y },
+            size: taffy::Size { width, height },
+            ..
+        } = node.final_layout;
+        let tf = node
+            .visual
+            .transform
+            .resolve((0.0, 0.0), (x, y), (width, height))
+            .map_or(transform, |tf| {
+                transform.pre_concat(Transform::from_row(
+                    round_float(tf.sx),
+                    round_float(tf.ky),
+                    round_float(tf.kx),
+                    round_float(tf.sy),
+                    round_float(tf.tx),
+                    round_float(tf.ty),
+                ))
+            });
+
+        match &node.kind {
+            NodeKind::Shape(shape) => {
+                let size = (width, height);
+                let radii = node.scaled_radii;
+
+                if let Some(color) = node.visual.fill.solid_color() {
+                    if shape.has_interior() {
+                        let fill_rule = node.visual.fill_rule;
+                        self.fill(|d| shape.write_path(d, size, radii), color, fill_rule, tf);
+                    }
+                }
+
+                let stroke = &node.visual.stroke;
+
+                if let Some(color) = stroke.paint.solid_color().filter(|_| !stroke.is_none()) {
+                    self.stroke(|d| shape.write_path(d, size, radii), color, stroke, tf);
+                }
+            }
+            //
+            NodeKind::Text(text) => {
+                self.draw_background(node, tf);
+
+                text.for_each_outline(fonts, |outline, color| {
+                    self.fill(|d| outline.write_path(d), color, FillRule::NonZero, tf);
+                });
+            }
+            //
+            _ => {
+                self.draw_background(node, tf);
+
+                if let Some(color) = node.visual.border.solid_color() {
+                    if node.has_border() {
+                        let radii = node.scaled_radii;
+                        let border = Rect::from(node.final_layout.border);
+
+                        self.fill(
+                            |d| write_border_path(d, width, height, radii, border),
+                            color,
+                            FillRule::EvenOdd,
+                            tf,
+                        );
+                    }
+                }
+            }
+        }
+
+        for child_idx in &node.children {
+            self.draw_node(scene, fonts, *child_idx, tf);
+        }
+    }
+
+    /// Draws the background of a block-level node.
+    ///
+    /// # Arguments
+    /// - `node`: The node to draw.
+    /// - `transform`: The transform of the node.
+    fn draw_background(&mut self, node: &Node, transform: Transform) {
+        let Some(color) = node.visual.background.solid_color() else {
+            return;
+        };
+
+        let taffy::Size { width, height } = node.final_layout.size;
+        let radii = node.scaled_radii;
+
+        self.fill(
+            |d| write_fill_path(d, width, height, radii),
+            color,
+            FillRule::NonZero,
+            transform,
+        );
+    }
+
+    /// Builds the path traced by the closure.
+    ///
+    /// # Arguments
+    /// - `trace_fn`: The closure tracing the path.
+    ///
+    /// # Returns
+    /// - The built path, or `None` if it has less than two segments.
+    fn build<F>(&mut self, trace_fn: F) -> Option<tiny_skia::Path>
+    where
+        F: FnOnce(&mut PathBuilderSink) -> std::fmt::Result,
+    {
+        let traced = trace_fn(&mut self.path);
+        let path = self.path.finish();
+        traced.ok().and(path)
+    }
+
+    /// Fills a path with a solid color.
+    ///
+    /// # Arguments
+    /// - `trace_fn`: The closure tracing the path.
+    /// - `color`: The fill [`Color`].
+    /// - `fill_rule`: The [`FillRule`] of the path.
+    /// - `transform`: The transform of the path.
+    fn fill<F>(&mut self, trace_fn: F, color: Color, fill_rule: FillRule, transform: Transform)
+    where
+        F: FnOnce(&mut PathBuilderSink) -> std::fmt::Result,
+    {
+        let Some(path) = self.build(trace_fn) else {
+            return;
+        };
+
+        // horizontal and vertical lines cannot be filled
+        if path.bounds().width() == 0.0 || path.bounds().height() == 0.0 {
+            return;
+        }
+
+        let fill_rule = match fill_rule {
+            FillRule::NonZero => tiny_skia::FillRule::Winding,
+            FillRule::EvenOdd => tiny_skia::FillRule::EvenOdd,
+        };
+
+        self.target
+            .fill_path(&path, &self.paint(color), fill_rule, transform, None);
+        self.path.reuse(path);
+    }
+
+    /// Strokes a path with a solid color.
+    ///
+    /// # Arguments
+    /// - `trace_fn`: The closure tracing the path.
+    /// - `color`: The stroke [`Color`].
+    /// - `stroke`: The stroke properties.
+    /// - `transform`: The transform of the path.
+    fn stroke<F>(&mut self, trace_fn: F, color: Color, stroke: &Stroke, transform: Transform)
+    where
+        F: FnOnce(&mut PathBuilderSink) -> std::fmt::Result,
+    {
+        let Some(path) = self.build(trace_fn) else {
+            return;
+        };
+
+        let stroke = tiny_skia::Stroke {
+            width: round_float(stroke.width),
+            miter_limit: round_float(stroke.miter_limit).max(1.0),
+            line_cap: match stroke.line_cap {
+                StrokeLineCap::Butt => LineCap::Butt,
+                StrokeLineCap::Round => LineCap::Round,
+                StrokeLineCap::Square => LineCap::Square,
+            },
+            line_join: match stroke.line_join {
+                StrokeLineJoin::Miter => LineJoin::Miter,
+                StrokeLineJoin::MiterClip => LineJoin::MiterClip,
+                StrokeLineJoin::Round => LineJoin::Round,
+                StrokeLineJoin::Bevel => LineJoin::Bevel,
+            },
+            dash: stroke_dash(&stroke.dash_array, stroke.dash_offset),
+        };
+
+        self.target
+            .stroke_path(&path, &self.paint(color), &stroke, transform, None);
+        self.path.reuse(path);
+    }
+
+    /// Creates the paint used to draw a solid color.
+    ///
+    /// # Arguments
+    /// - `color`: The [`Color`] to draw with.
+    fn paint(&self, color: Color) -> Paint<'static> {
+        let color = ColorU8::from(color);
+        let mut paint = Paint::default();
+        paint.set_color_rgba8(color.red(), color.green(), color.blue(), color.alpha());
+        paint.anti_alias = self.anti_alias;
+        paint
+    }
+}
+
+/// Returns `true` if the node and its children are rendered.
+///
+/// # Arguments
+/// - `node`: The [`Node`] to check.
+fn is_displayed(node: &Node) -> bool {
+    node.visual.visible && !matches!(node.layout.display, taffy::Display::None)
+}
+
+/// Returns `true` if the paint stack is empty or a single opaque solid color.
+///
+/// # Arguments
+/// - `paint`: The [`PaintStack`] to check.
+fn is_solid(paint: &PaintStack) -> bool {
+    paint.is_none() || paint.solid_color().is_some()
+}
+
+/// Returns `true` if the node itself can be drawn directly.
+///
+/// # Arguments
+/// - `node`: The [`Node`] to check.
+/// - `fonts`: The mutable [`FontRegistry`] reference.
+/// - `bitmap_scale`: The scale at which color glyph bitmaps are rasterized.
+fn supports_node(node: &Node, fonts: &mut FontRegistry, bitmap_scale: f32) -> bool {
+    let visual = &node.visual;
+
+    if visual.opacity != 1.0 || !visual.filter.is_default() || !visual.blend_mode.is_default() {
+        return false;
+    }
+
+    match &node.kind {
+        NodeKind::Text(text) => {
+            is_solid(&visual.background) && text.has_solid_outlines(fonts, bitmap_scale)
+        }
+        NodeKind::Image(_) => false,
+        NodeKind::Shape(shape) => {
+            let stroke = &visual.stroke;
+
+            !shape.needs_clip()
+                && is_solid(&visual.fill)
+                && (stroke.is_none()
+                    || (stroke.paint.solid_color().is_some() && stroke.width > 0.0))
+        }
+        _ => {
+            node.should_clip() == (false, false)
+                && is_solid(&visual.background)
+                && (!node.has_border() || is_solid(&visual.border))
+        }
+    }
+}
+
+/// Converts the dash array of a stroke the way `usvg` reads the written
+/// attributes.
+///
+/// # Arguments
+/// - `dash_array`: The lengths of the dashes and gaps.
+/// - `dash_offset`: The offset into the dash pattern.
+///
+/// # Returns
+/// - The [`StrokeDash`], or `None` if the stroke is solid.
+fn stroke_dash(dash_array: &[f32], dash_offset: f32) -> Option<StrokeDash> {
+    let mut dashes: Vec<f32> = dash_array.iter().map(|x| round_float(*x)).collect();
+
+    // negative lengths are an error and an empty pattern draws a solid line
+    if dashes.iter().any(|x| x.is_sign_negative()) || dashes.iter().sum::<f32>() == 0.0 {
+        return None;
+    }
+
+    // odd patterns are repeated to yield an even number of values
+    if !dashes.len().is_multiple_of(2) {
+        dashes.extend_from_within(..);
+    }
+
+    StrokeDash::new(dashes, round_float(dash_offset))
+}
+
+/// Builds a [`tiny_skia::Path`] the way `usvg` builds it from the written path
+/// data.
+///
+/// Coordinates are rounded to the precision they are written with, and arcs
+/// are converted into cubic Bézier curves with the tolerance used by `usvg`.
+#[derive(Debug, Default)]
+struct PathBuilderSink {
+    builder: PathBuilder,
+    /// The current point.
+    current: (f32, f32),
+    /// The start of the current subpath.
+    start: (f32, f32),
+}
+
+impl PathBuilderSink {
+    /// Takes the built path, leaving the builder empty.
+    ///
+    /// # Returns
+    /// - The path, or `None` if it has less than two segments.
+    fn finish(&mut self) -> Option<tiny_skia::Path> {
+        self.current = (0.0, 0.0);
+        self.start = (0.0, 0.0);
+
+        std::mem::take(&mut self.builder)
+            .finish()
+            .filter(|path| path.len() >= 2)
+    }
+
+    /// Reuses the allocation of a drawn path for the next path.
+    ///
+    /// # Arguments
+    /// - `path`: The drawn path.
+    fn reuse(&mut self, path: tiny_skia::Path) {
+        self.builder = path.clear();
+    }
+}
+
+impl PathSink for PathBuilderSink {
+    fn move_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        let (x, y) = (round_float(x), round_float(y));
+
+        self.builder.move_to(x, y);
+        self.current = (x, y);
+        self.start = (x, y);
+
+        Ok(self)
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        let (x, y) = (round_float(x), round_float(y));
+
+        self.builder.line_to(x, y);
+        self.current = (x, y);
+
+        Ok(self)
+    }
+
+    fn horizontal_to(&mut self, x: f32) -> Result<&mut Self, std::fmt::Error> {
+        self.line_to(x, self.current.1)
+    }
+
+    fn vertical_to(&mut self, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        self.line_to(self.current.0, y)
+    }
+
+    fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        let (x, y) = (round_float(x), round_float(y));
+
+        self.builder.quad_to(round_float(cx), round_float(cy), x, y);
+        self.current = (x, y);
+
+        Ok(self)
+    }
+
+    fn curve_to(
+        &mut self,
+        cx1: f32,
+        cy1: f32,
+        cx2: f32,
+        cy2: f32,
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        let (x, y) = (round_float(x), round_float(y));
+
+        self.builder.cubic_to(
+            round_float(cx1),
+            round_float(cy1),
+            round_float(cx2),
+            round_float(cy2),
+            x,
+            y,
+        );
+        self.current = (x, y);
+
+        Ok(self)
+    }
+
+    fn arc_to(&mut self, rx: f32, ry: f32, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        self.elliptical_arc_to(rx, ry, 0.0, (false, true), x, y)
+    }
+
+    fn elliptical_arc_to(
+        &mut self,
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        (large_arc, sweep): (bool, bool),
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        let (x, y) = (round_float(x), round_float(y));
+        let arc = kurbo::SvgArc {
+            from: kurbo::Point::new(self.current.0 as f64, self.current.1 as f64),
+            to: kurbo::Point::new(x as f64, y as f64),
+            radii: kurbo::Vec2::new(round_float(rx) as f64, round_float(ry) as f64),
+            x_rotation: (round_float(x_axis_rotation) as f64).to_radians(),
+            large_arc,
+            sweep,
+        };
+
+        match kurbo::Arc::from_svg_arc(&arc) {
+            Some(arc) => {
+                let Self {
+                    builder, current, ..
+                } = self;
+
+                arc.to_cubic_beziers(0.1, |p1, p2, p| {
+                    builder.cubic_to(
+                        p1.x as f32,
+                        p1.y as f32,
+                        p2.x as f32,
+                        p2.y as f32,
+                        p.x as f32,
+                        p.y as f32,
+                    );
+                    *current = (p.x as f32, p.y as f32);
+                });
+            }
+            None => {
+                self.builder.line_to(x, y);
+                self.current = (x, y);
+            }
+        }
+
+        Ok(self)
+    }
+
+    fn close(&mut self) -> std::fmt::Result {
+        self.builder.close();
+        self.current = self.start;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{
+            render_region,
+            DirectRenderer,
+            RenderSource,
+        },
+        prelude::*,
+    };
+    use lru::LruCache;
+    use parking_lot::Mutex;
+    use std::sync::Arc;
+    use tiny_skia::IntRect;
+
+    fn scene() -> Scene {
+        let mut scene = Scene::new(
+            Column::new()
+                .size((120, 90))
+                .padding(6)
+                .gap(4)
+                .background(rgb(0xf8fafc))
+                .finish(),
+        );
+        let root = scene.root_id();
+        let row = scene.append_child(
+            root,
+            Row::new()
+                .size((100, 24))
+                .corner_radius(6.0)
+                .border_width(2)
+                .border(rgb(0x0f172a))
+                .background(rgba(0x6366f180))
+                .transform(Transform::new().rotate(8.0))
+                .finish(),
+        );
+        scene.append_child(
+            row,
+            Circle::new(8.0)
+                .fill(rgb(0xef4444))
+                .stroke(rgb(0x111827))
+                .stroke_width(1.5)
+                .finish(),
+        );
+        scene.append_child(
+            row,
+            Polyline::new([(0.0, 8.0), (8.0, 0.0), (16.0, 8.0)])
+                .stroke(rgb(0x16a34a))
+                .stroke_width(2.0)
+                .stroke_linecap(StrokeLineCap::Round)
+                .finish(),
+        );
+        scene.append_child(
+            root,
+            Text::new(vec![
+                TextSpan::new("Direct ".to_string()).color(Paint::color(rgb(0x2563eb))),
+                TextSpan::new("path".to_string()),
+            ])
+            .font_size(14.0)
+            .finish(),
+        );
+        scene.append_child(
+            root,
+            Ellipse::new(13.3, 5.7)
+                .fill(rgb(0xf59e0b))
+                .stroke(rgb(0x78350f))
+                .stroke_width(1.25)
+                .stroke_dasharray([3.0, 1.5, 2.0])
+                .stroke_dashoffset(0.75)
+                .finish(),
+        );
+        scene.append_child(
+            root,
+            Path::parse("M0 0 A12 6 30 1 1 24 12 Q30 0 36 6 Z")
+                .unwrap()
+                .size((30, 10))
+                .fill(rgb(0x0ea5e9))
+                .finish(),
+        );
+        scene
+    }
+
+    #[test]
+    fn draws_like_the_render_tree() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = scene();
+        let image_cache = Arc::new(Mutex::new(LruCache::unbounded()));
+
+        for scale in [1.0, 2.5] {
+            let options = RasterizeOptions {
+                scale,
+                ..Default::default()
+            };
+            let (direct, size) = engine.rasterize(&mut scene, &options).unwrap();
+            let (tree, _) = scene.to_render_tree(&image_cache, &options).unwrap();
+            let region = IntRect::from_xywh(0, 0, direct.width(), direct.height()).unwrap();
+            let parsed =
+                render_region(&RenderSource::Tree(Box::new(tree)), region, &options).unwrap();
+
+            assert!(DirectRenderer::supports(&scene, &options));
+            assert_eq!((size.width(), size.height()), (120.0, 90.0));
+            assert!(direct.data() == parsed.data());
+        }
+    }
+
+    #[test]
+    fn falls_back_for_unsupported_nodes() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let options = RasterizeOptions::default();
+        let mut scene = scene();
+        engine.layout(
+            &mut scene,
+            &LayoutOptions::default(),
+            &ImageOptions::default(),
+        );
+        assert!(DirectRenderer::supports(&scene, &options));
+
+        let root = scene.root_id();
+        scene.append_child(
+            root,
+            Block::new()
+                .size((10, 10))
+                .background(LinearGradient::new())
+                .finish(),
+        );
+        engine.layout(
+            &mut scene,
+            &LayoutOptions::default(),
+            &ImageOptions::default(),
+        );
+        assert!(!DirectRenderer::supports(&scene, &options));
+    }
+}
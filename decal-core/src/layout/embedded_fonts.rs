@@ -0,0 +1,348 @@
+use crate::{
+    paint::{
+        IriNamespace,
+        ResourceIri,
+    },
+    utils::{
+        subset_font,
+        ElementWriter,
+    },
+};
+use base64::{
+    engine::general_purpose::STANDARD as BASE64,
+    Engine,
+};
+use cosmic_text::{
+    fontdb::ID,
+    Font,
+    FontSystem,
+    Weight,
+};
+use hashbrown::HashMap;
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    sync::Arc,
+};
+
+const PRIVATE_USE_START: u32 = 0xE000;
+const PRIVATE_USE_END: u32 = 0xF8FF;
+const SUPPLEMENTARY_PRIVATE_USE_START: u32 = 0xF0000;
+
+/// Collects the glyphs rendered with each font face, so that a subset of every
+/// face can be embedded into the output.
+#[derive(Debug, Default)]
+pub(crate) struct EmbeddedFonts {
+    faces: Vec<EmbeddedFace>,
+}
+
+/// A font face referenced by the output.
+///
+/// Every shaped glyph is mapped from the character it renders in the `cmap`
+/// table of a subset, so that the output renders the same glyphs as the shaper
+/// produced while keeping the original text. A character rendered with several
+/// glyphs, such as the first character of a ligature or an alternate form, is
+/// mapped in one subset per glyph, called a layer. The remaining characters of
+/// a ligature are mapped to a blank glyph.
+#[derive(Debug)]
+pub(crate) struct EmbeddedFace {
+    id: ID,
+    /// The value of the `wght` axis the face is instantiated at, if the face
+    /// is variable.
+    weight: Option<f32>,
+    font: Arc<Font>,
+    index: u32,
+    /// The font family name of the first layer.
+    family: String,
+    /// The mapping from code points to glyph IDs of every layer.
+    layers: Vec<BTreeMap<char, u16>>,
+    /// The glyph drawing nothing, if the face has one.
+    blank_glyph: Option<u16>,
+    /// The private use code point assigned to each glyph rendering no
+    /// character.
+    private_use: HashMap<u16, char>,
+    /// The next private use code point to assign.
+    next_private_use: u32,
+}
+
+/// Identifies a font face by its data.
+#[derive(Hash)]
+struct FaceKey<'a> {
+    data: &'a [u8],
+    index: u32,
+    weight: Option<u32>,
+}
+
+impl ResourceIri for FaceKey<'_> {}
+
+impl EmbeddedFonts {
+    /// Returns `true` if no font face has been registered.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.faces.is_empty()
+    }
+
+    /// Returns the face used to render glyphs with the given font, registering
+    /// it on first use.
+    ///
+    /// # Arguments
+    /// - `namespace`: The [`IriNamespace`] the family name is rendered in.
+    /// - `font_system`: The [`FontSystem`] used to look up the face.
+    /// - `id`: The ID of the font face.
+    /// - `weight`: The weight the glyphs were shaped with.
+    ///
+    /// # Returns
+    /// - The [`EmbeddedFace`] collecting the glyphs.
+    /// - `None` if the face cannot be found.
+    pub(crate) fn face(
+        &mut self,
+        namespace: &IriNamespace,
+        font_system: &mut FontSystem,
+        id: ID,
+        weight: Weight,
+    ) -> Option<&mut EmbeddedFace> {
+        let font = font_system.get_font(id, weight)?;
+
+        // variable faces are instantiated at the weight used for shaping
+        let weight = font
+            .as_swash()
+            .variations()
+            .find_by_tag(swash::tag_from_bytes(b"wght"))
+            .map(|axis| f32::from(weight.0).clamp(axis.min_value(), axis.max_value()));
+
+        if let Some(idx) = self
+            .faces
+            .iter()
+            .position(|face| face.id == id && face.weight == weight)
+        {
+            return Some(&mut self.faces[idx]);
+        }
+
+        let index = font_system.db().face(id)?.index;
+        let blank_glyph = Some(font.as_swash().charmap().map(' ')).filter(|x| *x != 0);
+        let family = namespace
+            .iri(
+                FaceKey {
+                    data: font.data(),
+                    index,
+                    weight: weight.map(f32::to_bits),
+                }
+                .iri(),
+            )
+            .to_string();
+
+        self.faces.push(EmbeddedFace {
+            id,
+            weight,
+            font,
+            index,
+            family,
+            layers: Vec::new(),
+            blank_glyph,
+            private_use: HashMap::new(),
+            next_private_use: PRIVATE_USE_START,
+        });
+
+        self.faces.last_mut()
+    }
+
+    /// Returns the family name and subset data of every layer that has
+    /// rendered glyphs.
+    ///
+    /// # Returns
+    /// - An iterator over the `(family, data)` of each subset.
+    #[cfg(feature = "pdf")]
+    pub(crate) fn subset_fonts(&self) -> impl Iterator<Item = (String, Vec<u8>)> + '_ {
+        self.subsets()
+            .map(|(face, layer, _, subset)| (face.family(layer), subset))
+    }
+
+    /// Subsets every layer of the registered faces that has rendered glyphs.
+    ///
+    /// # Returns
+    /// - An iterator over each face along with the index, characters and subset
+    ///   data of the layer.
+    fn subsets(
+        &self,
+    ) -> impl Iterator<Item = (&EmbeddedFace, usize, &BTreeMap<char, u16>, Vec<u8>)> {
+        self.faces.iter().flat_map(|face| {
+            face.layers
+                .iter()
+                .enumerate()
+                .filter(|(_, glyphs)| !glyphs.is_empty())
+                .filter_map(move |(layer, glyphs)| {
+                    let subset = subset_font(face.font.data(), face.index, glyphs, face.weight)?;
+                    Some((face, layer, glyphs, subset))
+                })
+        })
+    }
+
+    /// Writes a `<style>` element containing an `@font-face` rule for every
+    /// layer of the registered faces.
+    ///
+    /// Faces are named after a hash of their data, while the `unicode-range`
+    /// descriptor restricts each rule to the characters of its subset. This
+    /// keeps the rules of different documents embedded into the same page from
+    /// shadowing each other.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`std::fmt::Error`] if writing fails.
+    pub(crate) fn write_style<W>(&self, out: &mut W) -> std::fmt::Result
+    where
+        W: Write,
+    {
+        ElementWriter::new(out, "style")?
+            .content(|out| {
+                for (face, layer, glyphs, subset) in self.subsets() {
+                    let mime = if subset.starts_with(b"OTTO") {
+                        "font/otf"
+                    } else {
+                        "font/ttf"
+                    };
+
+                    write!(
+                        out,
+                        "@font-face{{font-family:\"{}\";src:url(data:{mime};base64,{});unicode-range:",
+                        face.family(layer),
+                        BASE64.encode(subset),
+                    )?;
+
+                    for (idx, (start, end)) in unicode_ranges(glyphs.keys()).enumerate() {
+                        if idx > 0 {
+                            out.write_char(',')?;
+                        }
+
+                        if start == end {
+                            write!(out, "U+{start:X}")?;
+                        } else {
+                            write!(out, "U+{start:X}-{end:X}")?;
+                        }
+                    }
+
+                    out.write_str("}")?;
+                }
+
+                Ok(())
+            })?
+            .close()
+    }
+}
+
+impl EmbeddedFace {
+    /// Returns the font family name referencing a layer of the face.
+    ///
+    /// # Arguments
+    /// - `layer`: The index of the layer.
+    pub(crate) fn family(&self, layer: usize) -> String {
+        match layer {
+            0 => self.family.clone(),
+            _ => format!("{}-{layer}", self.family),
+        }
+    }
+
+    /// Maps a character to the glyph rendering it.
+    ///
+    /// # Arguments
+    /// - `ch`: The character the glyph renders.
+    /// - `glyph_id`: The ID of the shaped glyph.
+    ///
+    /// # Returns
+    /// - The index of the layer mapping the character to the glyph.
+    pub(crate) fn map_glyph(&mut self, ch: char, glyph_id: u16) -> usize {
+        let layer = self
+            .layers
+            .iter()
+            .position(|glyphs| glyphs.get(&ch).is_none_or(|x| *x == glyph_id))
+            .unwrap_or_else(|| {
+                self.layers.push(BTreeMap::new());
+                self.layers.len() - 1
+            });
+
+        self.layers[layer].insert(ch, glyph_id);
+        layer
+    }
+
+    /// Maps a character drawn by the glyph of a preceding character, such as
+    /// the remaining characters of a ligature, to a blank glyph.
+    ///
+    /// # Arguments
+    /// - `ch`: The character to map.
+    ///
+    /// # Returns
+    /// - The index of the layer mapping the character, or `None` if the face
+    ///   has no blank glyph.
+    pub(crate) fn map_blank(&mut self, ch: char) -> Option<usize> {
+        let glyph_id = self.blank_glyph?;
+        Some(self.map_glyph(ch, glyph_id))
+    }
+
+    /// Returns the private use code point mapped to a glyph rendering no
+    /// character, assigning one on first use.
+    ///
+    /// Such glyphs only occur when the shaper splits a character into several
+    /// glyphs. They are mapped in the first layer.
+    ///
+    /// # Arguments
+    /// - `glyph_id`: The ID of the shaped glyph.
+    ///
+    /// # Returns
+    /// - The code point mapped to the glyph.
+    pub(crate) fn map_private_use(&mut self, glyph_id: u16) -> char {
+        if let Some(code_point) = self.private_use.get(&glyph_id) {
+            return *code_point;
+        }
+
+        if self.layers.is_empty() {
+            self.layers.push(BTreeMap::new());
+        }
+
+        let code_point = self.next_private_use();
+        self.layers[0].insert(code_point, glyph_id);
+        self.private_use.insert(glyph_id, code_point);
+        code_point
+    }
+
+    /// Returns the next private use code point not mapped in the first layer.
+    ///
+    /// The private use areas of the Basic Multilingual Plane and plane 15
+    /// together hold more code points than a font can have glyphs.
+    fn next_private_use(&mut self) -> char {
+        loop {
+            let code = self.next_private_use;
+            self.next_private_use = match code {
+                PRIVATE_USE_END => SUPPLEMENTARY_PRIVATE_USE_START,
+                _ => code + 1,
+            };
+
+            if let Some(ch) = char::from_u32(code).filter(|ch| !self.layers[0].contains_key(ch)) {
+                return ch;
+            }
+        }
+    }
+}
+
+/// Merges sorted characters into inclusive ranges of code points.
+///
+/// # Arguments
+/// - `chars`: The sorted characters.
+///
+/// # Returns
+/// - An iterator over the `(start, end)` code point ranges.
+fn unicode_ranges<'a, I>(chars: I) -> impl Iterator<Item = (u32, u32)>
+where
+    I: Iterator<Item = &'a char>,
+{
+    let mut ranges: Vec<(u32, u32)> = Vec::new();
+
+    for code in chars.map(|ch| *ch as u32) {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == code => *end = code,
+            _ => ranges.push((code, code)),
+        }
+    }
+
+    ranges.into_iter()
+}
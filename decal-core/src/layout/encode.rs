@@ -0,0 +1,626 @@
+use crate::{
+    layout::RasterizeError,
+    primitives::Color,
+};
+use std::{
+    borrow::Cow,
+    io::Write,
+};
+use thiserror::Error;
+use tiny_skia::{
+    ColorU8,
+    Pixmap,
+};
+
+/// The error that may occur while encoding a rasterized scene.
+#[derive(Debug, Error)]
+pub enum EncodeError {
+    #[error("failed to rasterize")]
+    Rasterize(#[from] RasterizeError),
+    #[error("failed to encode png")]
+    Png(#[from] png::EncodingError),
+    #[error("failed to encode jpeg")]
+    Jpeg(#[from] jpeg_encoder::EncodingError),
+    #[error("failed to encode webp")]
+    WebP(#[from] image_webp::EncodingError),
+    #[cfg(feature = "webp-lossy")]
+    #[error("failed to encode lossy webp: {0:?}")]
+    WebPLossy(webp::WebPEncodingError),
+    #[cfg(feature = "avif")]
+    #[error("failed to encode avif")]
+    Avif(#[from] ravif::Error),
+    #[error("image dimensions exceed the limits of the format")]
+    InvalidDimensions,
+    #[error("failed to write to the output stream")]
+    Io(#[from] std::io::Error),
+}
+
+/// The compression applied to PNG output, trading encoding speed for size.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum PngCompression {
+    /// Stores the image data without compression.
+    None,
+    /// Compresses quickly with a decent ratio.
+    Fast,
+    /// Balances encoding speed and size.
+    #[default]
+    Balanced,
+    /// Spends more time to produce a smaller file.
+    Best,
+}
+
+impl From<PngCompression> for png::Compression {
+    #[inline]
+    fn from(value: PngCompression) -> Self {
+        match value {
+            PngCompression::None => png::Compression::NoCompression,
+            PngCompression::Fast => png::Compression::Fast,
+            PngCompression::Balanced => png::Compression::Balanced,
+            PngCompression::Best => png::Compression::High,
+        }
+    }
+}
+
+/// The rendering intent written to the `sRGB` chunk of a PNG image.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum RenderingIntent {
+    /// Favors adaptation to the output device gamut, such as for photographs.
+    #[default]
+    Perceptual,
+    /// Matches colors relative to the output device white point, such as for
+    /// logos.
+    RelativeColorimetric,
+    /// Preserves saturation at the expense of hue and lightness, such as for
+    /// charts.
+    Saturation,
+    /// Preserves absolute colorimetry, such as for proofs.
+    AbsoluteColorimetric,
+}
+
+impl From<RenderingIntent> for png::SrgbRenderingIntent {
+    #[inline]
+    fn from(value: RenderingIntent) -> Self {
+        match value {
+            RenderingIntent::Perceptual => png::SrgbRenderingIntent::Perceptual,
+            RenderingIntent::RelativeColorimetric => png::SrgbRenderingIntent::RelativeColorimetric,
+            RenderingIntent::Saturation => png::SrgbRenderingIntent::Saturation,
+            RenderingIntent::AbsoluteColorimetric => png::SrgbRenderingIntent::AbsoluteColorimetric,
+        }
+    }
+}
+
+/// The color space information embedded in a PNG image.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub enum ColorProfile {
+    /// Embeds no color space information.
+    #[default]
+    None,
+    /// Marks the image as sRGB using an `sRGB` chunk.
+    Srgb(RenderingIntent),
+    /// Embeds the given ICC profile in an `iCCP` chunk.
+    Icc(Vec<u8>),
+}
+
+/// The metadata chunks written alongside the image data of a PNG image.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PngMetadata {
+    /// The physical resolution written to the `pHYs` chunk, in dots per inch.
+    pub dpi: Option<f32>,
+    /// The color space information.
+    pub color_profile: ColorProfile,
+    /// Textual key-value pairs, such as `Title`, `Author` or `Source`. Values
+    /// representable in Latin-1 are written as `tEXt` chunks, other values as
+    /// `iTXt` chunks. Keywords must be 1 to 79 Latin-1 characters long.
+    pub text: Vec<(String, String)>,
+}
+
+impl PngMetadata {
+    /// Creates an empty metadata set.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the physical resolution in dots per inch.
+    ///
+    /// # Arguments
+    /// - `dpi`: The resolution in dots per inch.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn dpi(mut self, dpi: f32) -> Self {
+        self.dpi = Some(dpi);
+        self
+    }
+
+    /// Sets the color space information.
+    ///
+    /// # Arguments
+    /// - `color_profile`: The [`ColorProfile`] value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn color_profile(mut self, color_profile: ColorProfile) -> Self {
+        self.color_profile = color_profile;
+        self
+    }
+
+    /// Appends a textual key-value pair.
+    ///
+    /// # Arguments
+    /// - `keyword`: The keyword, such as `Title`.
+    /// - `text`: The text value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn text<K, V>(mut self, keyword: K, text: V) -> Self
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.text.push((keyword.into(), text.into()));
+        self
+    }
+
+    /// Builds the PNG header information for an image of the given size.
+    ///
+    /// # Arguments
+    /// - `width`: The image width.
+    /// - `height`: The image height.
+    /// - `color_type`: The [`png::ColorType`] of the image data.
+    ///
+    /// # Returns
+    /// - The [`png::Info`] value.
+    fn to_info(&self, width: u32, height: u32, color_type: png::ColorType) -> png::Info<'_> {
+        let mut info = png::Info::with_size(width, height);
+        info.color_type = color_type;
+        info.bit_depth = png::BitDepth::Eight;
+
+        info.pixel_dims = self
+            .dpi
+            .filter(|dpi| dpi.is_finite() && *dpi > 0.0)
+            .map(|dpi| {
+                // pHYs stores pixels per meter
+                let ppm = (dpi / 0.0254).round() as u32;
+                png::PixelDimensions {
+                    xppu: ppm,
+                    yppu: ppm,
+                    unit: png::Unit::Meter,
+                }
+            });
+
+        match &self.color_profile {
+            ColorProfile::None => {}
+            ColorProfile::Srgb(intent) => info.srgb = Some((*intent).into()),
+            ColorProfile::Icc(profile) => info.icc_profile = Some(Cow::Borrowed(profile)),
+        }
+
+        for (keyword, text) in &self.text {
+            if text.chars().all(|ch| (ch as u32) < 256) {
+                info.uncompressed_latin1_text
+                    .push(png::text_metadata::TEXtChunk::new(keyword, text));
+            } else {
+                info.utf8_text
+                    .push(png::text_metadata::ITXtChunk::new(keyword, text));
+            }
+        }
+
+        info
+    }
+}
+
+/// The image format used to encode a rasterized scene.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OutputFormat {
+    /// Lossless PNG, with an alpha channel unless `opaque` is set.
+    Png {
+        /// The compression applied to the image data.
+        compression: PngCompression,
+        /// Writes RGB data without an alpha channel when set to `true`.
+        /// Transparent areas are composited over black, so
+        /// [`RasterizeOptions::background`](crate::layout::RasterizeOptions::background)
+        /// should usually be set alongside.
+        opaque: bool,
+        /// The metadata chunks written alongside the image data.
+        metadata: PngMetadata,
+    },
+    /// Lossy JPEG. As JPEG has no alpha channel, transparent areas are
+    /// composited over an opaque background.
+    Jpeg {
+        /// The encoding quality, from `1` to `100`.
+        quality: u8,
+        /// The background color. Its alpha channel is ignored.
+        background: Color,
+    },
+    /// Lossless WebP with an alpha channel.
+    WebP,
+    /// Lossy WebP with an alpha channel.
+    #[cfg(feature = "webp-lossy")]
+    WebPLossy {
+        /// The encoding quality, from `0.0` to `100.0`.
+        quality: f32,
+    },
+    /// Lossy AVIF with an alpha channel.
+    #[cfg(feature = "avif")]
+    Avif {
+        /// The encoding quality, from `1.0` to `100.0`.
+        quality: f32,
+        /// The encoding speed, from `1` (slowest, smallest output) to `10`
+        /// (fastest).
+        speed: u8,
+    },
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self::Png {
+            compression: PngCompression::default(),
+            opaque: false,
+            metadata: PngMetadata::default(),
+        }
+    }
+}
+
+/// The format used to encode a vectorized scene.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum VectorOutputFormat {
+    /// Uncompressed SVG markup.
+    #[default]
+    Svg,
+    /// Gzip-compressed SVG markup, usually served with the `.svgz` extension.
+    Svgz {
+        /// The compression level, from `0` (no compression) to `9` (best
+        /// compression).
+        level: u8,
+    },
+}
+
+/// Encodes a pixmap into the given format.
+///
+/// # Arguments
+/// - `pixmap`: The [`Pixmap`] to encode.
+/// - `format`: The [`OutputFormat`] value.
+/// - `out`: The output writer.
+///
+/// # Returns
+/// - Empty tuple on success.
+/// - [`EncodeError`] on failure.
+pub(crate) fn encode_pixmap<W>(
+    pixmap: &Pixmap,
+    format: OutputFormat,
+    out: &mut W,
+) -> Result<(), EncodeError>
+where
+    W: Write,
+{
+    let (width, height) = (pixmap.width(), pixmap.height());
+
+    match format {
+        OutputFormat::Png {
+            compression,
+            opaque,
+            metadata,
+        } => {
+            let (color_type, data) = if opaque {
+                (
+                    png::ColorType::Rgb,
+                    flatten(pixmap, ColorU8::from_rgba(0, 0, 0, 255)),
+                )
+            } else {
+                (png::ColorType::Rgba, demultiply(pixmap))
+            };
+
+            let mut encoder =
+                png::Encoder::with_info(out, metadata.to_info(width, height, color_type))?;
+            encoder.set_compression(compression.into());
+            encoder.write_header()?.write_image_data(&data)?;
+        }
+        OutputFormat::Jpeg {
+            quality,
+            background,
+        } => {
+            let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+                return Err(EncodeError::InvalidDimensions);
+            };
+
+            jpeg_encoder::Encoder::new(out, quality.clamp(1, 100)).encode(
+                &flatten(pixmap, background.into()),
+                width,
+                height,
+                jpeg_encoder::ColorType::Rgb,
+            )?;
+        }
+        OutputFormat::WebP => {
+            image_webp::WebPEncoder::new(out).encode(
+                &demultiply(pixmap),
+                width,
+                height,
+                image_webp::ColorType::Rgba8,
+            )?;
+        }
+        #[cfg(feature = "webp-lossy")]
+        OutputFormat::WebPLossy { quality } => {
+            let data = demultiply(pixmap);
+            let image = webp::Encoder::from_rgba(&data, width, height)
+                .encode_simple(false, quality.clamp(0.0, 100.0))
+                .map_err(EncodeError::WebPLossy)?;
+
+            out.write_all(&image)?;
+        }
+        #[cfg(feature = "avif")]
+        OutputFormat::Avif { quality, speed } => {
+            let (Ok(width), Ok(height)) = (usize::try_from(width), usize::try_from(height)) else {
+                return Err(EncodeError::InvalidDimensions);
+            };
+
+            let data = demultiply(pixmap)
+                .chunks_exact(4)
+                .map(|x| ravif::RGBA8::new(x[0], x[1], x[2], x[3]))
+                .collect::<Vec<_>>();
+            // the encoder panics on qualities out of range, including NaN
+            let quality = if quality.is_nan() {
+                1.0
+            } else {
+                quality.clamp(1.0, 100.0)
+            };
+            let image = ravif::Encoder::new()
+                .with_quality(quality)
+                .with_alpha_quality(quality)
+                .with_speed(speed.clamp(1, 10))
+                .encode_rgba(ravif::Img::new(data.as_slice(), width, height))?;
+
+            out.write_all(&image.avif_file)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Converts the premultiplied pixels of a pixmap into straight RGBA.
+///
+/// # Arguments
+/// - `pixmap`: The source [`Pixmap`].
+///
+/// # Returns
+/// - The RGBA pixel data.
+fn demultiply(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect()
+}
+
+/// Composites the pixels of a pixmap over an opaque background.
+///
+/// # Arguments
+/// - `pixmap`: The source [`Pixmap`].
+/// - `background`: The background color.
+///
+/// # Returns
+/// - The RGB pixel data.
+fn flatten(pixmap: &Pixmap, background: ColorU8) -> Vec<u8> {
+    let blend = |channel: u8, background: u8, alpha: u8| {
+        // source-over with premultiplied source
+        channel + ((background as u16 * (255 - alpha) as u16 + 127) / 255) as u8
+    };
+
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let alpha = pixel.alpha();
+            [
+                blend(pixel.red(), background.red(), alpha),
+                blend(pixel.green(), background.green(), alpha),
+                blend(pixel.blue(), background.blue(), alpha),
+            ]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use std::io::Read;
+    use tiny_skia::PremultipliedColorU8;
+
+    fn pixmap() -> Pixmap {
+        let mut pixmap = Pixmap::new(2, 1).unwrap();
+        let pixels = pixmap.pixels_mut();
+        pixels[0] = PremultipliedColorU8::from_rgba(255, 0, 0, 255).unwrap();
+        pixels[1] = PremultipliedColorU8::from_rgba(0, 0, 128, 128).unwrap();
+        pixmap
+    }
+
+    fn encode(format: OutputFormat) -> Vec<u8> {
+        let mut out = Vec::new();
+        encode_pixmap(&pixmap(), format, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn demultiplies_alpha() {
+        assert_eq!(demultiply(&pixmap()), [255, 0, 0, 255, 0, 0, 255, 128]);
+    }
+
+    #[test]
+    fn flattens_onto_background() {
+        assert_eq!(
+            flatten(&pixmap(), ColorU8::from_rgba(255, 255, 255, 255)),
+            [255, 0, 0, 127, 127, 255]
+        );
+    }
+
+    #[test]
+    fn encodes_png() {
+        let data = encode(OutputFormat::Png {
+            compression: PngCompression::Best,
+            opaque: false,
+            metadata: PngMetadata::default(),
+        });
+        let mut reader = png::Decoder::new(std::io::Cursor::new(data))
+            .read_info()
+            .unwrap();
+        let mut buffer = vec![0; reader.output_buffer_size().unwrap()];
+        reader.next_frame(&mut buffer).unwrap();
+
+        assert_eq!(buffer, demultiply(&pixmap()));
+    }
+
+    #[test]
+    fn encodes_opaque_png() {
+        let data = encode(OutputFormat::Png {
+            compression: PngCompression::Fast,
+            opaque: true,
+            metadata: PngMetadata::default(),
+        });
+        let mut reader = png::Decoder::new(std::io::Cursor::new(data))
+            .read_info()
+            .unwrap();
+        let mut buffer = vec![0; reader.output_buffer_size().unwrap()];
+        reader.next_frame(&mut buffer).unwrap();
+
+        assert_eq!(reader.info().color_type, png::ColorType::Rgb);
+        assert_eq!(buffer, [255, 0, 0, 0, 0, 128]);
+    }
+
+    #[test]
+    fn encodes_jpeg_and_webp() {
+        let jpeg = encode(OutputFormat::Jpeg {
+            quality: 90,
+            background: Color::rgb(255, 255, 255),
+        });
+        assert!(jpeg.starts_with(&[0xFF, 0xD8]));
+
+        let webp = encode(OutputFormat::WebP);
+        assert!(webp.starts_with(b"RIFF") && &webp[8..12] == b"WEBP");
+    }
+
+    #[cfg(feature = "avif")]
+    #[test]
+    fn encodes_avif() {
+        let avif = encode(OutputFormat::Avif {
+            quality: 80.0,
+            speed: 10,
+        });
+        assert_eq!(&avif[4..12], b"ftypavif");
+    }
+
+    #[test]
+    fn writes_png_metadata() {
+        let data = encode(OutputFormat::Png {
+            compression: PngCompression::Fast,
+            opaque: false,
+            metadata: PngMetadata::new()
+                .dpi(300.0)
+                .color_profile(ColorProfile::Srgb(RenderingIntent::Perceptual))
+                .text("Title", "Card")
+                .text("Author", "Zoë 陳"),
+        });
+        let reader = png::Decoder::new(std::io::Cursor::new(data))
+            .read_info()
+            .unwrap();
+        let info = reader.info();
+
+        let dims = info.pixel_dims.unwrap();
+        assert_eq!(
+            (dims.xppu, dims.yppu, dims.unit),
+            (11811, 11811, png::Unit::Meter)
+        );
+        assert_eq!(info.srgb, Some(png::SrgbRenderingIntent::Perceptual));
+        assert_eq!(info.uncompressed_latin1_text[0].keyword, "Title");
+        assert_eq!(info.uncompressed_latin1_text[0].text, "Card");
+        assert_eq!(info.utf8_text[0].keyword, "Author");
+        assert_eq!(info.utf8_text[0].get_text().unwrap(), "Zoë 陳");
+    }
+
+    #[test]
+    fn writes_icc_profile() {
+        let profile = vec![7; 64];
+        let data = encode(OutputFormat::Png {
+            compression: PngCompression::Fast,
+            opaque: false,
+            metadata: PngMetadata::new().color_profile(ColorProfile::Icc(profile.clone())),
+        });
+        let reader = png::Decoder::new(std::io::Cursor::new(data))
+            .read_info()
+            .unwrap();
+
+        assert_eq!(
+            reader.info().icc_profile.as_deref(),
+            Some(profile.as_slice())
+        );
+    }
+
+    fn scene() -> Scene {
+        Scene::new(
+            Block::new()
+                .size((20, 10))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        )
+    }
+
+    fn encode_vector(format: VectorOutputFormat) -> (Vec<u8>, String) {
+        let mut engine = Engine::new(EngineOptions::default());
+        let options = VectorizeOptions::default();
+        let (svg, _) = engine.vectorize(&mut scene(), &options).unwrap();
+        let mut out = Vec::new();
+        engine
+            .encode_vector(&mut out, &mut scene(), format, &options)
+            .unwrap();
+
+        (out, svg)
+    }
+
+    #[test]
+    fn streams_svg() {
+        let (data, svg) = encode_vector(VectorOutputFormat::Svg);
+        assert_eq!(data, svg.as_bytes());
+    }
+
+    #[test]
+    fn encodes_svgz() {
+        let (data, svg) = encode_vector(VectorOutputFormat::Svgz { level: 9 });
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(data.as_slice())
+            .read_to_string(&mut decoded)
+            .unwrap();
+
+        assert_eq!(decoded, svg);
+    }
+
+    #[test]
+    fn reports_io_errors() {
+        struct FullWriter;
+
+        impl Write for FullWriter {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::StorageFull.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let result = Engine::new(EngineOptions::default()).encode_vector(
+            &mut FullWriter,
+            &mut scene(),
+            VectorOutputFormat::Svg,
+            &VectorizeOptions::default(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(VectorizeError::Io(error)) if error.kind() == std::io::ErrorKind::StorageFull
+        ));
+    }
+}
@@ -1,8 +1,24 @@
+#[cfg(feature = "pdf")]
+use crate::layout::{
+    PdfDocument,
+    PdfError,
+    PdfOptions,
+};
 use crate::{
     layout::{
+        encode_pixmap,
+        pixel_size,
+        render_into,
+        render_region,
+        EncodeError,
+        ImageOptions,
+        LayoutOptions,
+        OutputFormat,
         RasterizeError,
         RasterizeOptions,
         Scene,
+        Tiles,
+        VectorOutputFormat,
         VectorizeError,
         VectorizeOptions,
         font::FontRegistry,
@@ -15,10 +31,20 @@ use smart_default::SmartDefault;
 use std::{
     fmt::Write,
     num::NonZeroUsize,
-    sync::Arc,
+    sync::{
+        Arc,
+        OnceLock,
+    },
+};
+use tiny_skia::{
+    IntRect,
+    Pixmap,
+    PixmapMut,
+};
+use usvg::{
+    fontdb::Database,
+    ImageKind,
 };
-use tiny_skia::Pixmap;
-use usvg::ImageKind;
 
 /// Shared image cache type used to deduplicate decoded raster images across
 /// renders.
@@ -45,6 +71,9 @@ pub struct Engine {
     fonts: Arc<Mutex<FontRegistry>>,
     /// Global image cache.
     image_cache: ImageCache,
+    /// The fonts used to parse inline SVG images, copied from the font registry
+    /// once and shared by every scene rendered with the engine.
+    svg_fonts: OnceLock<Arc<Database>>,
 }
 
 impl Engine {
@@ -59,6 +88,7 @@ impl Engine {
         Self {
             fonts: Arc::new(Mutex::new(options.fonts)),
             image_cache: Arc::new(Mutex::new(LruCache::new(options.image_cache_capacity))),
+            svg_fonts: OnceLock::new(),
         }
     }
 
@@ -75,6 +105,58 @@ impl Engine {
         T: Into<Vec<u8>>,
     {
         self.fonts.lock().append_font(alias, data);
+        self.svg_fonts.take();
+    }
+
+    /// Computes the layout of the given scene without rendering it.
+    ///
+    /// The computed boxes of each node can then be queried with
+    /// [`Scene::layout`].
+    ///
+    /// Images are loaded with `image_options` to resolve their intrinsic
+    /// sizes, so they should match the options the scene is rendered with.
+    /// Remote URLs are only loaded through
+    /// [`ImageOptions::href_string_resolver`].
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to lay out.
+    /// - `options`: The [`LayoutOptions`] value.
+    /// - `image_options`: The [`ImageOptions`] used to load images.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let mut engine = Engine::new(EngineOptions::default());
+    /// let mut scene = Scene::new(Column::new().padding(8).finish());
+    /// let block = scene.append_child(scene.root_id(), Block::new().size((64, 32)).finish());
+    ///
+    /// engine.layout(
+    ///     &mut scene,
+    ///     &LayoutOptions::default(),
+    ///     &ImageOptions::default(),
+    /// );
+    ///
+    /// let root = scene.layout(scene.root_id()).unwrap();
+    /// let block = scene.layout(block).unwrap();
+    ///
+    /// assert_eq!(
+    ///     root.absolute.border,
+    ///     LayoutRect::from_values(0.0, 0.0, 80.0, 48.0)
+    /// );
+    /// assert_eq!(
+    ///     block.absolute.border,
+    ///     LayoutRect::from_values(8.0, 8.0, 64.0, 32.0)
+    /// );
+    /// ```
+    pub fn layout(
+        &mut self,
+        scene: &mut Scene,
+        options: &LayoutOptions,
+        image_options: &ImageOptions,
+    ) {
+        self.prepare(scene, &image_options.offline(), options);
     }
 
     /// Rasterizes the given scene into a [`Pixmap`].
@@ -93,11 +175,205 @@ impl Engine {
         scene: &mut Scene,
         options: &RasterizeOptions,
     ) -> Result<(Pixmap, Size<f32>), RasterizeError> {
-        self.prepare(scene).rasterize(&self.image_cache, options)
+        self.prepare(scene, &options.image, &options.vectorize_options.layout)
+            .rasterize(&self.image_cache, options)
+    }
+
+    /// Rasterizes the given scene into an existing pixmap, reusing its buffer.
+    ///
+    /// The scene is composited over the current contents of the target unless
+    /// `clear` is set, so several scenes can be rendered into a single pixmap,
+    /// such as a sprite sheet. The [`RasterizeOptions::background`] only fills
+    /// the bounds of the scene.
+    ///
+    /// # Arguments
+    /// - `target`: The pixmap to render into.
+    /// - `scene`: The scene to rasterize.
+    /// - `offset`: The position of the scene in the target, in pixels.
+    /// - `clear`: Whether to clear the whole target before rendering.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Returns
+    /// - Scene size on success.
+    /// - [`RasterizeError`] on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    /// # use tiny_skia::Pixmap;
+    ///
+    /// let mut engine = Engine::new(EngineOptions::default());
+    /// let mut sheet = Pixmap::new(128, 64).unwrap();
+    ///
+    /// for (index, color) in [rgb(0x6366f1), rgb(0xec4899)].into_iter().enumerate() {
+    ///     let mut scene = decal! {
+    ///         Block {}
+    ///             .size((64, 64))
+    ///             .background(color)
+    ///     };
+    ///
+    ///     engine
+    ///         .rasterize_into(
+    ///             &mut sheet.as_mut(),
+    ///             &mut scene,
+    ///             (index as i32 * 64, 0),
+    ///             false,
+    ///             &RasterizeOptions::default(),
+    ///         )
+    ///         .unwrap();
+    /// }
+    ///
+    /// assert_eq!(sheet.pixel(0, 0).unwrap().red(), 0x63);
+    /// assert_eq!(sheet.pixel(64, 0).unwrap().red(), 0xec);
+    /// ```
+    pub fn rasterize_into(
+        &mut self,
+        target: &mut PixmapMut,
+        scene: &mut Scene,
+        offset: (i32, i32),
+        clear: bool,
+        options: &RasterizeOptions,
+    ) -> Result<Size<f32>, RasterizeError> {
+        let (source, size) = self
+            .prepare(scene, &options.image, &options.vectorize_options.layout)
+            .to_render_source(&self.image_cache, options)?;
+        render_into(&source, size, target, offset, clear, options);
+        Ok(size)
+    }
+
+    /// Rasterizes a region of the given scene into a [`Pixmap`].
+    ///
+    /// The region is given in output pixels, after [`RasterizeOptions::scale`]
+    /// is applied, and may extend past the scene bounds.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to rasterize.
+    /// - `region`: The region to rasterize.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Returns
+    /// - [`Pixmap`] with the size of the region on success.
+    /// - [`RasterizeError`] on failure.
+    pub fn rasterize_region(
+        &mut self,
+        scene: &mut Scene,
+        region: IntRect,
+        options: &RasterizeOptions,
+    ) -> Result<Pixmap, RasterizeError> {
+        let (source, _) = self
+            .prepare(scene, &options.image, &options.vectorize_options.layout)
+            .to_render_source(&self.image_cache, options)?;
+        render_region(&source, region, options)
+    }
+
+    /// Rasterizes the given scene tile by tile, so that large scenes can be
+    /// streamed without allocating a pixmap for the whole image.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to rasterize.
+    /// - `tile_size`: The width and height of each tile, in output pixels.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Returns
+    /// - [`Tiles`] iterator yielding the tiles in row-major order on success.
+    /// - [`RasterizeError`] on failure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let mut engine = Engine::new(EngineOptions::default());
+    /// let mut scene = decal! {
+    ///     Block {}
+    ///         .size((1200, 630))
+    ///         .background(rgb(0x6366f1))
+    /// };
+    ///
+    /// let options = RasterizeOptions::default();
+    /// for tile in engine
+    ///     .rasterize_tiles(&mut scene, (512, 512), &options)
+    ///     .unwrap()
+    /// {
+    ///     let tile = tile.unwrap();
+    ///     assert!(tile.pixmap.width() <= 512 && tile.pixmap.height() <= 512);
+    /// }
+    /// ```
+    pub fn rasterize_tiles<'a>(
+        &mut self,
+        scene: &mut Scene,
+        tile_size: (u32, u32),
+        options: &'a RasterizeOptions<'a>,
+    ) -> Result<Tiles<'a>, RasterizeError> {
+        let (tree, size) = self
+            .prepare(scene, &options.image, &options.vectorize_options.layout)
+            .to_render_tree(&self.image_cache, options)?;
+        Ok(Tiles::new(
+            tree,
+            pixel_size(size, options.scale),
+            tile_size,
+            options,
+        ))
+    }
+
+    /// Rasterizes the given scene and encodes it into the provided destination.
+    ///
+    /// # Arguments
+    /// - `destination`: The output writer.
+    /// - `scene`: The scene to encode.
+    /// - `format`: The [`OutputFormat`] value.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let mut engine = Engine::new(EngineOptions::default());
+    /// let mut scene = decal! {
+    ///     Block {}
+    ///         .size((120, 63))
+    ///         .background(rgb(0x6366f1))
+    /// };
+    ///
+    /// let mut jpeg = Vec::new();
+    /// engine
+    ///     .encode(
+    ///         &mut jpeg,
+    ///         &mut scene,
+    ///         OutputFormat::Jpeg {
+    ///             quality: 85,
+    ///             background: rgb(0xffffff),
+    ///         },
+    ///         &RasterizeOptions::default(),
+    ///     )
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Returns
+    /// - Scene size on success.
+    /// - [`EncodeError`] on failure.
+    pub fn encode<T>(
+        &mut self,
+        destination: &mut T,
+        scene: &mut Scene,
+        format: OutputFormat,
+        options: &RasterizeOptions,
+    ) -> Result<Size<f32>, EncodeError>
+    where
+        T: std::io::Write,
+    {
+        let (pixmap, size) = self.rasterize(scene, options)?;
+        encode_pixmap(&pixmap, format, destination)?;
+        Ok(size)
     }
 
     /// Vectorizes the given scene into an SVG string.
     ///
+    /// Images are loaded with [`VectorizeOptions::image`]. Remote URLs are
+    /// never fetched unless its `href_string_resolver` loads them.
+    ///
     /// # Arguments
     /// - `scene`: The scene to vectorize.
     /// - `options`: The [`VectorizeOptions`] value.
@@ -112,7 +388,8 @@ impl Engine {
         scene: &mut Scene,
         options: &VectorizeOptions,
     ) -> Result<(String, Size<f32>), VectorizeError> {
-        self.prepare(scene).vectorize(options)
+        self.prepare(scene, &options.image.offline(), &options.layout)
+            .vectorize(options)
     }
 
     /// Streams the vectorized SVG representation of the scene to the provided
@@ -135,15 +412,175 @@ impl Engine {
     where
         T: Write,
     {
-        self.prepare(scene).stream_vector(destination, options)
+        self.prepare(scene, &options.image.offline(), &options.layout)
+            .stream_vector(destination, options)
+    }
+
+    /// Streams the vectorized SVG representation of the scene to the provided
+    /// [`std::io::Write`] destination, such as a file or a socket.
+    ///
+    /// The markup is written as it is produced and never held in memory as a
+    /// whole. The [`SvgFormat::Pretty`](crate::layout::SvgFormat::Pretty) and
+    /// [`SvgFormat::Minify`](crate::layout::SvgFormat::Minify) formats only
+    /// hold back the tag being written. Writes are otherwise forwarded as-is,
+    /// so unbuffered destinations should be wrapped in a
+    /// [`std::io::BufWriter`].
+    ///
+    /// # Arguments
+    /// - `destination`: The output writer.
+    /// - `scene`: The scene to vectorize.
+    /// - `format`: The [`VectorOutputFormat`] value.
+    /// - `options`: The [`VectorizeOptions`] value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let mut engine = Engine::new(EngineOptions::default());
+    /// let mut scene = decal! {
+    ///     Block {}
+    ///         .size((120, 63))
+    ///         .background(rgb(0x6366f1))
+    /// };
+    ///
+    /// let mut svgz = Vec::new();
+    /// engine
+    ///     .encode_vector(
+    ///         &mut svgz,
+    ///         &mut scene,
+    ///         VectorOutputFormat::Svgz { level: 9 },
+    ///         &VectorizeOptions::default(),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(svgz[..2], [0x1f, 0x8b]);
+    /// ```
+    ///
+    /// # Returns
+    /// - Scene size on success.
+    /// - [`VectorizeError`] on failure.
+    pub fn encode_vector<T>(
+        &mut self,
+        destination: &mut T,
+        scene: &mut Scene,
+        format: VectorOutputFormat,
+        options: &VectorizeOptions,
+    ) -> Result<Size<f32>, VectorizeError>
+    where
+        T: std::io::Write,
+    {
+        self.prepare(scene, &options.image.offline(), &options.layout)
+            .encode_vector(destination, format, options)
+    }
+
+    /// Renders the given scene into a single-page PDF document.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to render.
+    /// - `options`: The [`PdfOptions`] value.
+    ///
+    /// # Returns
+    /// - The PDF document on success.
+    /// - [`PdfError`] on failure.
+    #[cfg(feature = "pdf")]
+    pub fn render_pdf(
+        &mut self,
+        scene: &mut Scene,
+        options: &PdfOptions,
+    ) -> Result<Vec<u8>, PdfError> {
+        self.render_pdf_pages(std::slice::from_mut(scene), options)
+    }
+
+    /// Renders the given scenes into a PDF document, with one page per scene.
+    ///
+    /// # Arguments
+    /// - `scenes`: The scenes to render, in page order.
+    /// - `options`: The [`PdfOptions`] value.
+    ///
+    /// # Returns
+    /// - The PDF document on success.
+    /// - [`PdfError`] on failure.
+    #[cfg(feature = "pdf")]
+    pub fn render_pdf_pages(
+        &mut self,
+        scenes: &mut [Scene],
+        options: &PdfOptions,
+    ) -> Result<Vec<u8>, PdfError> {
+        let mut out = Vec::new();
+        self.stream_pdf(&mut out, scenes, options)?;
+        Ok(out)
+    }
+
+    /// Streams a PDF document rendered from the given scenes, with one page
+    /// per scene, to the provided destination.
+    ///
+    /// # Note
+    /// Each scene is added to the document as soon as it is converted, so a
+    /// single converted page is held at a time. The document itself is
+    /// buffered in memory and written to the destination once every page is
+    /// added, as its cross-reference table lists the offsets of all objects.
+    ///
+    /// # Arguments
+    /// - `destination`: The output writer.
+    /// - `scenes`: The scenes to render, in page order.
+    /// - `options`: The [`PdfOptions`] value.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`PdfError`] on failure.
+    #[cfg(feature = "pdf")]
+    pub fn stream_pdf<T>(
+        &mut self,
+        destination: &mut T,
+        scenes: &mut [Scene],
+        options: &PdfOptions,
+    ) -> Result<(), PdfError>
+    where
+        T: std::io::Write,
+    {
+        let mut document = PdfDocument::new();
+
+        for scene in scenes.iter_mut() {
+            let page = self
+                .prepare(scene, &options.image, &options.layout)
+                .to_pdf_page(&self.image_cache, self.svg_fonts(), options)?;
+            document.push_page(page);
+        }
+
+        document.write(destination)
     }
 
     /// Prepares the scene for rendering.
     ///
-    /// This injects the engine font registry and computes layout.
-    fn prepare<'a>(&self, scene: &'a mut Scene) -> &'a mut Scene {
+    /// This injects the engine font registry, resolves the intrinsic size of
+    /// automatically sized images, parses inline SVG images with the engine
+    /// fonts and computes layout.
+    ///
+    /// # Arguments
+    /// - `scene`: The scene to prepare.
+    /// - `image_options`: The [`ImageOptions`] used to resolve images.
+    /// - `layout_options`: The [`LayoutOptions`] used to compute layout.
+    fn prepare<'a>(
+        &self,
+        scene: &'a mut Scene,
+        image_options: &ImageOptions,
+        layout_options: &LayoutOptions,
+    ) -> &'a mut Scene {
         scene.set_fonts(self.fonts.clone());
-        scene.compute_layout();
+        scene.resolve_image_sizes(&self.image_cache, image_options);
+        scene.resolve_svg_images(&self.image_cache, image_options, self.svg_fonts());
+        scene.compute_layout(layout_options);
         scene
     }
+
+    /// Returns the fonts used to parse inline SVG images.
+    ///
+    /// # Returns
+    /// - The font database shared by the scenes rendered with the engine.
+    fn svg_fonts(&self) -> Arc<Database> {
+        self.svg_fonts
+            .get_or_init(|| Arc::new(self.fonts.lock().system.db().clone()))
+            .clone()
+    }
 }
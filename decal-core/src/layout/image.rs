@@ -1,25 +1,97 @@
 use crate::{
-    layout::RenderContext,
-    primitives::CrossOrigin,
-    utils::ElementWriter,
+    layout::{
+        RenderContext,
+        VectorizeError,
+    },
+    paint::{
+        Iri,
+        ResourceIri,
+    },
+    primitives::{
+        CrossOrigin,
+        ObjectFit,
+        ObjectPosition,
+        PreserveAspectRatio,
+        ViewBox,
+    },
+    utils::{
+        ElementWriter,
+        IsDefault,
+    },
 };
-use enum_display::EnumDisplay;
-use quick_xml::escape::escape;
-use std::fmt::{
-    Display,
-    Formatter,
-    Write,
+use base64::{
+    engine::general_purpose::STANDARD as BASE64,
+    Engine,
+};
+use quick_xml::escape::{
+    escape,
+    unescape,
+};
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+        Write,
+    },
+    sync::Arc,
 };
 use taffy::Size;
+use usvg::{
+    fontdb::Database,
+    ImageHrefResolver,
+    Indent,
+    WriteOptions,
+};
 
-#[derive(Debug, Clone, EnumDisplay)]
+#[derive(Debug, Clone)]
 enum ImageSourceInner {
     /// External image reference.
-    #[display("{0}")]
     Href(String),
-    /// Inline SVG content rendered verbatim.
-    #[display("{0}")]
-    Svg(String),
+    /// Inline SVG markup.
+    Svg(SvgImage),
+}
+
+/// The inline SVG image, parsed with `usvg` when the scene is rendered.
+#[derive(Debug, Clone)]
+struct SvgImage {
+    markup: Arc<str>,
+    /// The IRI derived from the source markup, used to prefix the element ids.
+    iri: Iri,
+}
+
+/// The tree of an inline SVG image parsed by the engine.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedSvg {
+    /// The markup the tree was parsed from.
+    markup: Arc<str>,
+    /// The fonts the tree was parsed with.
+    fontdb: Arc<Database>,
+    tree: Arc<usvg::Tree>,
+}
+
+/// The source markup of an inline SVG image.
+#[derive(Hash)]
+struct SvgMarkup<'a>(&'a str);
+
+impl ResourceIri for SvgMarkup<'_> {}
+
+/// Serializes an SVG tree. Text is written as paths, so that it renders
+/// without the fonts used to parse the markup.
+///
+/// # Arguments
+/// - `tree`: The SVG tree.
+/// - `id_prefix`: The optional prefix prepended to every element id.
+///
+/// # Returns
+/// - The SVG markup.
+fn serialize_svg(tree: &usvg::Tree, id_prefix: Option<String>) -> String {
+    tree.to_string(&WriteOptions {
+        id_prefix,
+        preserve_text: false,
+        indent: Indent::None,
+        attributes_indent: Indent::None,
+        ..Default::default()
+    })
 }
 
 /// The source of an image node.
@@ -28,7 +100,14 @@ pub struct ImageSource(ImageSourceInner);
 
 impl Display for ImageSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+        match &self.0 {
+            ImageSourceInner::Href(href) => f.write_str(href),
+            ImageSourceInner::Svg(svg) => write!(
+                f,
+                "data:image/svg+xml;base64,{}",
+                BASE64.encode(svg.markup.as_bytes())
+            ),
+        }
     }
 }
 
@@ -40,6 +119,15 @@ pub(crate) struct ImageMeta {
     pub(crate) width: f32,
     pub(crate) height: f32,
     pub(crate) cross_origin: Option<CrossOrigin>,
+    pub(crate) preserve_aspect_ratio: PreserveAspectRatio,
+    /// Overrides `preserve_aspect_ratio` when set.
+    pub(crate) object_fit: Option<ObjectFit>,
+    pub(crate) object_position: ObjectPosition,
+    /// Whether the intrinsic size is read from the image data before layout.
+    pub(crate) auto_size: bool,
+    /// The inline SVG image parsed by the engine, reused across renders while
+    /// the markup and fonts are unchanged.
+    pub(crate) resolved_svg: Option<ResolvedSvg>,
 }
 
 impl ImageMeta {
@@ -93,6 +181,116 @@ impl ImageMeta {
         }
     }
 
+    /// Parses the markup of an inline SVG image with the fonts and image
+    /// resolvers of the engine. The parsed tree is kept until the markup or
+    /// the fonts change, so that the markup is not parsed on every render.
+    ///
+    /// Markup that fails to parse is left unresolved and reported when the
+    /// image is rendered.
+    ///
+    /// # Arguments
+    /// - `options`: The [`usvg::Options`] holding the fonts and image resolvers
+    ///   of the engine.
+    ///
+    /// # Returns
+    /// - `true` if the intrinsic size of the image changed.
+    pub(crate) fn resolve_svg(&mut self, options: &usvg::Options) -> bool {
+        let ImageSourceInner::Svg(svg) = &self.source.0 else {
+            return false;
+        };
+
+        if self.resolved_svg.as_ref().is_some_and(|resolved| {
+            resolved.markup == svg.markup && Arc::ptr_eq(&resolved.fontdb, &options.fontdb)
+        }) {
+            return false;
+        }
+
+        let tree = usvg::Tree::from_str(&svg.markup, options).ok();
+        self.resolved_svg = tree.map(|tree| ResolvedSvg {
+            markup: svg.markup.clone(),
+            fontdb: options.fontdb.clone(),
+            tree: Arc::new(tree),
+        });
+
+        let Some(resolved) = self.resolved_svg.as_ref().filter(|_| self.auto_size) else {
+            return false;
+        };
+
+        let size = resolved.tree.size();
+        let changed = (self.width, self.height) != (size.width(), size.height());
+        (self.width, self.height) = (size.width(), size.height());
+
+        changed
+    }
+
+    /// Returns the tree of an inline SVG image, parsing the markup without
+    /// the engine if it has not been resolved yet.
+    ///
+    /// # Arguments
+    /// - `svg`: The inline SVG image.
+    ///
+    /// # Returns
+    /// - The SVG tree on success.
+    /// - [`usvg::Error`] if the markup is not a valid SVG document.
+    fn svg_tree(&self, svg: &SvgImage) -> Result<Arc<usvg::Tree>, usvg::Error> {
+        match &self.resolved_svg {
+            Some(resolved) if resolved.markup == svg.markup => Ok(resolved.tree.clone()),
+            _ => usvg::Tree::from_str(&svg.markup, &detached_options()).map(Arc::new),
+        }
+    }
+
+    /// Returns `true` if the image overflows the layout box and must be
+    /// clipped to it.
+    ///
+    /// # Arguments
+    /// - `size`: The size of the layout box.
+    pub(crate) fn needs_clip(&self, size: Size<f32>) -> bool {
+        match self.object_fit {
+            Some(ObjectFit::Cover) => true,
+            Some(ObjectFit::None) => self.width > size.width || self.height > size.height,
+            _ => false,
+        }
+    }
+
+    /// Computes the viewport of the image within the layout box.
+    ///
+    /// # Arguments
+    /// - `size`: The size of the layout box.
+    ///
+    /// # Returns
+    /// - The `(x, y, width, height)` of the viewport along with the
+    ///   [`PreserveAspectRatio`] applied within it.
+    fn viewport(&self, size: Size<f32>) -> ((f32, f32, f32, f32), PreserveAspectRatio) {
+        let layout_box = (0.0, 0.0, size.width, size.height);
+        let Some(object_fit) = self.object_fit else {
+            return (layout_box, self.preserve_aspect_ratio);
+        };
+
+        let aligned = self.object_position.preserve_aspect_ratio();
+        let fits = self.width <= size.width && self.height <= size.height;
+
+        match object_fit {
+            ObjectFit::Fill => (layout_box, aligned.align_none()),
+            ObjectFit::Contain => (layout_box, aligned.meet()),
+            ObjectFit::ScaleDown if !fits => (layout_box, aligned.meet()),
+            ObjectFit::Cover => (layout_box, aligned.slice()),
+            ObjectFit::None | ObjectFit::ScaleDown => {
+                // position the image at its intrinsic size
+                let (align_x, align_y) = aligned.alignment().unwrap_or((0.5, 0.5));
+
+                (
+                    (
+                        (size.width - self.width) * align_x,
+                        (size.height - self.height) * align_y,
+                        self.width,
+                        self.height,
+                    ),
+                    PreserveAspectRatio::default(),
+                )
+            }
+        }
+    }
+
     /// Renders the image into the output stream.
     ///
     /// # Arguments
@@ -102,18 +300,67 @@ impl ImageMeta {
         &self,
         ctx: &mut RenderContext<W>,
         layout: taffy::Layout,
-    ) -> std::fmt::Result
+    ) -> Result<(), VectorizeError>
     where
         W: Write,
     {
-        let Size { width, height } = layout.size;
+        let ((x, y, width, height), preserve_aspect_ratio) = self.viewport(layout.size);
+
         match &self.source.inner() {
-            ImageSourceInner::Href(href) => ElementWriter::new(ctx.out, "image")?
+            ImageSourceInner::Href(href) => Ok(ElementWriter::new(ctx.out, "image")?
                 .attr("href", href.as_str())?
+                .attr_if("x", x, x != 0.0)?
+                .attr_if("y", y, y != 0.0)?
                 .attrs([("width", width), ("height", height)])?
+                .attr_if(
+                    "preserveAspectRatio",
+                    (preserve_aspect_ratio,),
+                    !preserve_aspect_ratio.is_default(),
+                )?
                 .attr("crossorigin", self.cross_origin.map(|x| (x,)))?
-                .close(),
-            ImageSourceInner::Svg(svg) => ctx.out.write_str(svg.as_str()),
+                .close()?),
+            ImageSourceInner::Svg(svg) => {
+                let tree = self.svg_tree(svg)?;
+                // prefix the ids with the occurrence index, so that the same
+                // markup can be embedded more than once
+                let markup = serialize_svg(
+                    &tree,
+                    Some(format!(
+                        "{}-{}-",
+                        ctx.namespace.iri(svg.iri),
+                        ctx.svg_images
+                    )),
+                );
+                ctx.svg_images += 1;
+
+                // unwrap the root element, which is replaced by a nested
+                // viewport scaled into the layout box
+                let content = match markup.find('>') {
+                    Some(start) if !markup[..start].ends_with('/') => {
+                        &markup[start + 1..markup.rfind("</svg>").unwrap_or(markup.len())]
+                    }
+                    _ => "",
+                };
+                let view_box = ViewBox::new(0.0, 0.0, tree.size().width(), tree.size().height());
+
+                Ok(ElementWriter::new(ctx.out, "svg")?
+                    .attr_if("x", x, x != 0.0)?
+                    .attr_if("y", y, y != 0.0)?
+                    .attrs([("width", width), ("height", height)])?
+                    .attr("viewBox", (view_box,))?
+                    .attr_if(
+                        "preserveAspectRatio",
+                        (preserve_aspect_ratio,),
+                        !preserve_aspect_ratio.is_default(),
+                    )?
+                    .attr_if(
+                        "xmlns:xlink",
+                        "http://www.w3.org/1999/xlink",
+                        content.contains("xlink:"),
+                    )?
+                    .content(|out| out.write_str(content))?
+                    .close()?)
+            }
         }
     }
 }
@@ -134,19 +381,43 @@ impl ImageSource {
         Self(ImageSourceInner::Href(escape(url.as_ref()).to_string()))
     }
 
-    /// Creates an [`ImageSource`] from raw SVG markup. The SVG content is
-    /// emitted verbatim during rendering.
+    /// Creates an [`ImageSource`] from raw SVG markup.
+    ///
+    /// The markup is parsed with `usvg` by the engine rendering the scene, so
+    /// that the image can be scaled into the layout box of the node and its
+    /// ids can be made unique. Images and text in the markup are resolved
+    /// with the [`ImageOptions`] and fonts of the engine. Local files are
+    /// never read.
+    ///
+    /// # Note
+    /// Invalid markup is reported as a [`VectorizeError::SvgImage`] when the
+    /// scene is rendered.
     ///
     /// # Arguments
     /// - `svg`: The SVG markup.
     ///
     /// # Returns
     /// - [`Self`]
+    ///
+    /// [`ImageOptions`]: crate::layout::ImageOptions
     pub fn svg<S>(svg: S) -> Self
     where
         S: AsRef<str>,
     {
-        Self(ImageSourceInner::Svg(svg.as_ref().to_string()))
+        let svg = svg.as_ref();
+
+        Self(ImageSourceInner::Svg(SvgImage {
+            markup: Arc::from(svg),
+            iri: SvgMarkup(svg).iri(),
+        }))
+    }
+
+    /// Returns the unescaped URL of an external image reference.
+    pub(crate) fn url(&self) -> Option<String> {
+        match &self.0 {
+            ImageSourceInner::Href(href) => unescape(href).ok().map(|x| x.into_owned()),
+            ImageSourceInner::Svg(_) => None,
+        }
     }
 
     /// Returns the underlying image source representation.
@@ -155,6 +426,23 @@ impl ImageSource {
     }
 }
 
+/// Returns the options used to parse SVG markup outside of an engine.
+///
+/// External images are not resolved, so that the markup cannot read local
+/// files. Data URLs are still decoded.
+///
+/// # Returns
+/// - The [`usvg::Options`] value.
+fn detached_options() -> usvg::Options<'static> {
+    usvg::Options {
+        image_href_resolver: ImageHrefResolver {
+            resolve_string: Box::new(|_, _| None),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
 impl Default for ImageSource {
     fn default() -> Self {
         ImageSource::href("")
@@ -170,3 +458,230 @@ where
         ImageSource::href(value.as_ref())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Scene;
+
+    const SVG: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10"><rect id="r" width="20" height="10"/></svg>"#;
+
+    fn render(meta: &ImageMeta, ctx: &mut RenderContext<String>) {
+        let mut layout = taffy::Layout::with_order(0);
+        layout.size = Size {
+            width: 40.0,
+            height: 40.0,
+        };
+        meta.render(ctx, layout).unwrap();
+    }
+
+    #[test]
+    fn resolves_svg_once_per_markup_and_fonts() {
+        let mut meta = ImageMeta {
+            auto_size: true,
+            ..ImageMeta::new(ImageSource::svg(SVG), 0.0, 0.0)
+        };
+        let options = usvg::Options::default();
+        let tree = |meta: &ImageMeta| meta.resolved_svg.as_ref().unwrap().tree.clone();
+
+        assert!(meta.resolve_svg(&options));
+        assert_eq!((meta.width, meta.height), (20.0, 10.0));
+
+        let resolved = tree(&meta);
+        assert!(!meta.resolve_svg(&options));
+        assert!(Arc::ptr_eq(&resolved, &tree(&meta)));
+
+        let options = usvg::Options {
+            fontdb: Arc::new(Database::new()),
+            ..Default::default()
+        };
+        assert!(!meta.resolve_svg(&options));
+        assert!(!Arc::ptr_eq(&resolved, &tree(&meta)));
+
+        meta.source = ImageSource::svg("<svg");
+        meta.resolve_svg(&options);
+        assert!(meta.resolved_svg.is_none());
+    }
+
+    #[test]
+    fn renders_svg_into_nested_viewport() {
+        let scene = Scene::empty();
+        let mut out = String::new();
+        let mut ctx = RenderContext::new(&mut out, &scene);
+        let meta = ImageMeta::new(ImageSource::svg(SVG), 20.0, 10.0);
+
+        render(&meta, &mut ctx);
+        render(&meta, &mut ctx);
+
+        let iri = SvgMarkup(SVG).iri();
+        assert!(out.starts_with(r#"<svg width="40" height="40" viewBox="0 0 20 10">"#));
+        assert!(out.contains(&format!(r#"id="{iri}-0-r""#)));
+        assert!(out.contains(&format!(r#"id="{iri}-1-r""#)));
+    }
+
+    #[test]
+    fn fits_image_into_layout_box() {
+        let size = Size {
+            width: 40.0,
+            height: 40.0,
+        };
+        let mut meta = ImageMeta::new("image.png", 80.0, 20.0);
+        assert_eq!(meta.viewport(size).0, (0.0, 0.0, 40.0, 40.0));
+        assert!(!meta.needs_clip(size));
+
+        meta.object_fit = Some(ObjectFit::Cover);
+        meta.object_position = ObjectPosition::Right;
+        assert_eq!(
+            meta.viewport(size).1,
+            PreserveAspectRatio::new().x_max_y_mid().slice()
+        );
+        assert!(meta.needs_clip(size));
+
+        meta.object_fit = Some(ObjectFit::None);
+        assert_eq!(meta.viewport(size).0, (-40.0, 10.0, 80.0, 20.0));
+        assert!(meta.needs_clip(size));
+
+        meta.object_fit = Some(ObjectFit::ScaleDown);
+        assert_eq!(meta.viewport(size).0, (0.0, 0.0, 40.0, 40.0));
+        assert!(!meta.needs_clip(size));
+
+        meta.width = 20.0;
+        assert_eq!(meta.viewport(size).0, (20.0, 10.0, 20.0, 20.0));
+    }
+
+    #[test]
+    fn resolves_intrinsic_size_before_layout() {
+        use crate::{
+            builders::{
+                Column,
+                Image,
+            },
+            capabilities::{
+                Dimensions,
+                Drawable,
+            },
+            layout::{
+                Engine,
+                EngineOptions,
+                VectorizeOptions,
+            },
+        };
+        use base64::{
+            engine::general_purpose::STANDARD as BASE64,
+            Engine as _,
+        };
+
+        let png = tiny_skia::Pixmap::new(30, 20)
+            .unwrap()
+            .encode_png()
+            .unwrap();
+        let href = format!("data:image/png;base64,{}", BASE64.encode(png));
+        let mut engine = Engine::new(EngineOptions::default());
+
+        let mut scene = Scene::new(Column::new().finish());
+        let root = scene.root_id();
+        scene.append_child(root, Image::auto(href.as_str()).finish());
+        scene.append_child(root, Image::auto(href.as_str()).width(60).finish());
+
+        let (_, size) = engine
+            .vectorize(&mut scene, &VectorizeOptions::default())
+            .unwrap();
+        assert_eq!((size.width, size.height), (60.0, 60.0));
+    }
+
+    #[test]
+    fn does_not_fetch_remote_images_when_vectorizing() {
+        use crate::{
+            builders::Image,
+            capabilities::Drawable,
+            layout::{
+                Engine,
+                EngineOptions,
+                ImageOptions,
+                VectorizeOptions,
+            },
+        };
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let href = format!("http://{}/image.png", listener.local_addr().unwrap());
+
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Image::auto(href.as_str()).finish());
+        let mut options = VectorizeOptions::default();
+
+        // the image is left unresolved and the url is never requested
+        assert!(engine.vectorize(&mut scene, &options).is_err());
+        assert!(listener.accept().is_err());
+
+        let png = tiny_skia::Pixmap::new(30, 20)
+            .unwrap()
+            .encode_png()
+            .unwrap();
+        options.image = ImageOptions {
+            href_string_resolver: Some(Arc::new(move |_, _| {
+                Some(usvg::ImageKind::PNG(Arc::new(png.clone())))
+            })),
+            ..Default::default()
+        };
+
+        let (_, size) = engine.vectorize(&mut scene, &options).unwrap();
+        assert_eq!((size.width, size.height), (30.0, 20.0));
+    }
+
+    #[test]
+    fn resolves_svg_images_with_engine() {
+        use crate::{
+            builders::Image,
+            capabilities::Drawable,
+            layout::{
+                Engine,
+                EngineOptions,
+                VectorizeOptions,
+            },
+        };
+
+        let path = std::env::temp_dir().join("decal-svg-image-local-file.png");
+        let png = tiny_skia::Pixmap::new(4, 4).unwrap().encode_png().unwrap();
+        std::fs::write(&path, png).unwrap();
+
+        let markup = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" height="20"><image href="{}" width="4" height="4"/><text x="0" y="16" font-family="DejaVu Sans" font-size="16">Hi</text></svg>"#,
+            path.display()
+        );
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Image::svg(&markup).finish());
+        let (svg, _) = engine
+            .vectorize(&mut scene, &VectorizeOptions::default())
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // local files are not read
+        assert!(!svg.contains("<image"));
+        // text is shaped with the engine fonts and written as paths
+        assert!(!svg.contains("<text"));
+        assert!(svg.contains("<path"));
+    }
+
+    #[test]
+    fn reports_invalid_svg_when_rendered() {
+        use crate::{
+            builders::Image,
+            capabilities::Drawable,
+            layout::{
+                Engine,
+                EngineOptions,
+                VectorizeOptions,
+            },
+        };
+
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Image::new(ImageSource::svg("<svg"), 20.0, 20.0).finish());
+
+        assert!(matches!(
+            engine.vectorize(&mut scene, &VectorizeOptions::default()),
+            Err(VectorizeError::SvgImage(_))
+        ));
+    }
+}
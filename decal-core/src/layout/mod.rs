@@ -1,24 +1,42 @@
 mod context;
+mod direct;
+mod embedded_fonts;
+mod encode;
 mod engine;
 mod font;
 mod image;
 mod node;
 mod node_id;
+mod node_layout;
 mod options;
+#[cfg(feature = "pdf")]
+mod pdf;
 mod scene;
+mod shape;
 mod stencil;
 mod text;
+mod tiles;
 mod typography;
+mod viewport;
 
+pub use encode::*;
 pub use engine::*;
 pub use font::*;
 pub use image::*;
 pub use node::*;
 pub use node_id::*;
+pub use node_layout::*;
 pub use options::*;
+#[cfg(feature = "pdf")]
+pub use pdf::*;
 pub use scene::*;
 pub use stencil::*;
 pub use text::*;
+pub use tiles::*;
 
 pub(crate) use context::*;
+pub(crate) use direct::*;
+pub(crate) use embedded_fonts::*;
+pub(crate) use shape::*;
 pub(crate) use typography::*;
+pub(crate) use viewport::*;
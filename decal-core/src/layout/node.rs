@@ -3,6 +3,7 @@ use crate::{
         text::TextMeta,
         ImageMeta,
         RenderContext,
+        ShapeMeta,
         TextVectorizeError,
         Typography,
     },
@@ -17,8 +18,10 @@ use crate::{
         ScaledRadii,
     },
     primitives::{
+        Calc,
         ClipPath,
         Rect,
+        Size,
     },
     utils::{
         ElementWriter,
@@ -40,6 +43,10 @@ pub enum VectorizeError {
     Write(#[from] std::fmt::Error),
     #[error("failed to vectorize text")]
     TextVectorize(#[from] TextVectorizeError),
+    #[error("failed to parse svg image")]
+    SvgImage(#[from] usvg::Error),
+    #[error("failed to write to the output stream")]
+    Io(#[from] std::io::Error),
 }
 
 #[derive(Debug, Clone, EnumDisplay)]
@@ -54,11 +61,16 @@ pub(crate) enum NodeKind {
     Text(TextMeta),
     #[display("Image: {0:?}")]
     Image(ImageMeta),
+    #[display("Shape: {0:?}")]
+    Shape(ShapeMeta),
 }
 
 impl NodeKind {
     pub(crate) fn is_atomic(&self) -> bool {
-        matches!(self, NodeKind::Text(_) | NodeKind::Image(_))
+        matches!(
+            self,
+            NodeKind::Text(_) | NodeKind::Image(_) | NodeKind::Shape(_)
+        )
     }
 }
 
@@ -71,10 +83,17 @@ pub struct Node {
     pub(crate) children: Vec<usize>,
     pub(crate) resources: Vec<Resource>,
     pub(crate) typography: Typography,
+    /// Expressions referenced by the layout style, which only stores pointers
+    /// to them. They are never read, only kept alive with the node.
+    #[allow(dead_code)]
+    pub(crate) expressions: Vec<Calc>,
     // computed
+    pub(crate) resolved_layout: Option<taffy::Style>,
     pub(crate) cache: taffy::Cache,
     pub(crate) unrounded_layout: taffy::Layout,
     pub(crate) final_layout: taffy::Layout,
+    /// The location of the node relative to the root.
+    pub(crate) absolute_location: taffy::Point<f32>,
     pub(crate) scaled_radii: ScaledRadii,
 }
 
@@ -87,6 +106,7 @@ impl Node {
     /// - `visual`: The [`Appearance`] value.
     /// - `typography`: Optional [`Typography`] value.
     /// - `resources`: Resources referenced by the node.
+    /// - `expressions`: Expressions referenced by the layout style.
     ///
     /// # Returns
     /// - [`Self`]
@@ -96,6 +116,7 @@ impl Node {
         visual: Appearance,
         typography: Option<Typography>,
         resources: Vec<Resource>,
+        expressions: Vec<Calc>,
     ) -> Self {
         Self {
             kind,
@@ -104,24 +125,37 @@ impl Node {
             children: Vec::new(),
             resources,
             typography: typography.unwrap_or(Typography::default()),
+            expressions,
+            resolved_layout: None,
             cache: taffy::Cache::new(),
             unrounded_layout: taffy::Layout::with_order(0),
             final_layout: taffy::Layout::with_order(0),
+            absolute_location: taffy::Point::ZERO,
             scaled_radii: ScaledRadii::default(),
         }
     }
 
+    /// Returns the layout style used to compute layout, with the viewport
+    /// units resolved.
+    pub(crate) fn style(&self) -> &taffy::Style {
+        self.resolved_layout.as_ref().unwrap_or(&self.layout)
+    }
+
     /// Applies layout-dependent visual effects after layout resolution.
-    pub(crate) fn apply_layout_effects(&mut self) {
+    ///
+    /// # Arguments
+    /// - `viewport`: The size viewport units resolve against.
+    pub(crate) fn apply_layout_effects(&mut self, viewport: Size<f32>) {
         self.scaled_radii = compute_scaled_radii(
-            self.visual.corner_radius,
+            &self.visual.corner_radius,
             self.final_layout.size.width,
             self.final_layout.size.height,
+            viewport,
         );
     }
 
     /// Returns `true` if the node has a visible border.
-    fn has_border(&self) -> bool {
+    pub(crate) fn has_border(&self) -> bool {
         let taffy::Rect {
             top,
             right,
@@ -133,7 +167,7 @@ impl Node {
 
     /// Returns `true` if any corner radius is non-zero.
     fn has_radius(&self) -> bool {
-        let radius = self.visual.corner_radius;
+        let radius = &self.visual.corner_radius;
         !radius.top_left.is_zero()
             || !radius.top_right.is_zero()
             || !radius.bottom_left.is_zero()
@@ -141,7 +175,7 @@ impl Node {
     }
 
     /// Determines whether clipping should be applied on each axis.
-    fn should_clip(&self) -> (bool, bool) {
+    pub(crate) fn should_clip(&self) -> (bool, bool) {
         let clip_x = self.layout.overflow.x == taffy::Overflow::Hidden;
         let clip_y = self.layout.overflow.y == taffy::Overflow::Hidden;
         (clip_x, clip_y)
@@ -162,7 +196,10 @@ impl Node {
             .attr_if("opacity", self.visual.opacity, self.visual.opacity != 1.0)?
             .attr_if(
                 "filter",
-                (format_args!("url(#{})", self.visual.filter.iri()),),
+                (format_args!(
+                    "url(#{})",
+                    ctx.namespace.iri(self.visual.filter.iri())
+                ),),
                 !self.visual.filter.is_default(),
             )?
             .attr_if(
@@ -264,7 +301,10 @@ impl Node {
         })?;
 
         ElementWriter::new(ctx.out, "g")?
-            .attr("clip-path", (format_args!("url(#{})", clip.iri()),))?
+            .attr(
+                "clip-path",
+                (format_args!("url(#{})", ctx.namespace.iri(clip.iri())),),
+            )?
             .open()?;
 
         ctx.scene.resources.lock().get_or_add_resource(clip.into());
@@ -313,14 +353,23 @@ impl Node {
             }
             //
             NodeKind::Image(image) => {
-                let has_radius = self.has_radius();
+                let clipped = self.has_radius() || image.needs_clip(self.final_layout.size);
 
                 self.open_block_group(ctx)?;
                 self.render_block_background(ctx)?;
                 self.render_block_border(ctx)?;
-                self.open_block_clip(ctx, (has_radius, has_radius))?;
+                self.open_block_clip(ctx, (clipped, clipped))?;
                 image.render(ctx, self.final_layout)?;
-                Self::close_block_group(has_radius, ctx)?;
+                Self::close_block_group(clipped, ctx)?;
+            }
+            //
+            NodeKind::Shape(shape) => {
+                let clipped = shape.needs_clip();
+
+                self.open_block_group(ctx)?;
+                self.open_block_clip(ctx, (clipped, clipped))?;
+                shape.render(ctx, &self.visual, self.scaled_radii, self.final_layout)?;
+                Self::close_block_group(clipped, ctx)?;
             }
         };
 
@@ -350,7 +399,7 @@ impl Node {
                 )?;
             }
             //
-            NodeKind::Text(_) | NodeKind::Image(_) => {}
+            NodeKind::Text(_) | NodeKind::Image(_) | NodeKind::Shape(_) => {}
         }
 
         Ok(())
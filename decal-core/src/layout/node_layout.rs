@@ -0,0 +1,138 @@
+/// An axis-aligned rectangle positioned by its top-left corner.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct LayoutRect {
+    /// The horizontal position of the left edge.
+    pub x: f32,
+    /// The vertical position of the top edge.
+    pub y: f32,
+    /// The width of the rectangle.
+    pub width: f32,
+    /// The height of the rectangle.
+    pub height: f32,
+}
+
+impl LayoutRect {
+    /// Creates a new [`LayoutRect`] instance.
+    ///
+    /// # Arguments
+    /// - `x`: The horizontal position of the left edge.
+    /// - `y`: The vertical position of the top edge.
+    /// - `width`: The width of the rectangle.
+    /// - `height`: The height of the rectangle.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    #[must_use]
+    pub const fn from_values(x: f32, y: f32, width: f32, height: f32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Shrinks the rectangle by the given edge widths.
+    ///
+    /// # Arguments
+    /// - `edges`: The edge widths to remove.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn inset(self, edges: taffy::Rect<f32>) -> Self {
+        Self {
+            x: self.x + edges.left,
+            y: self.y + edges.top,
+            width: (self.width - edges.left - edges.right).max(0.0),
+            height: (self.height - edges.top - edges.bottom).max(0.0),
+        }
+    }
+}
+
+/// The border, padding and content boxes of a node.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct LayoutBoxes {
+    /// The box enclosing the border of the node.
+    pub border: LayoutRect,
+    /// The box enclosing the padding of the node, inside the border.
+    pub padding: LayoutRect,
+    /// The box enclosing the content of the node, inside the padding.
+    pub content: LayoutRect,
+}
+
+impl LayoutBoxes {
+    /// Creates the boxes of a computed layout placed at the given position.
+    ///
+    /// # Arguments
+    /// - `x`: The horizontal position of the border box.
+    /// - `y`: The vertical position of the border box.
+    /// - `layout`: The computed [`taffy::Layout`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new(x: f32, y: f32, layout: &taffy::Layout) -> Self {
+        let border = LayoutRect::from_values(x, y, layout.size.width, layout.size.height);
+        let padding = border.inset(layout.border);
+        let content = padding.inset(layout.padding);
+
+        Self {
+            border,
+            padding,
+            content,
+        }
+    }
+}
+
+/// The computed layout of a node.
+///
+/// Boxes reflect the position and size assigned during layout and ignore
+/// transforms applied to the node or its ancestors.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct NodeLayout {
+    /// The boxes relative to the border box of the parent node.
+    pub relative: LayoutBoxes,
+    /// The boxes relative to the origin of the scene.
+    pub absolute: LayoutBoxes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insets_padding_and_content_boxes() {
+        let layout = taffy::Layout {
+            size: taffy::Size {
+                width: 100.0,
+                height: 50.0,
+            },
+            border: taffy::Rect {
+                left: 1.0,
+                right: 2.0,
+                top: 3.0,
+                bottom: 4.0,
+            },
+            padding: taffy::Rect {
+                left: 5.0,
+                right: 5.0,
+                top: 10.0,
+                bottom: 10.0,
+            },
+            ..taffy::Layout::new()
+        };
+        let boxes = LayoutBoxes::new(10.0, 20.0, &layout);
+
+        assert_eq!(
+            boxes.border,
+            LayoutRect::from_values(10.0, 20.0, 100.0, 50.0)
+        );
+        assert_eq!(
+            boxes.padding,
+            LayoutRect::from_values(11.0, 23.0, 97.0, 43.0)
+        );
+        assert_eq!(
+            boxes.content,
+            LayoutRect::from_values(16.0, 33.0, 87.0, 23.0)
+        );
+    }
+}
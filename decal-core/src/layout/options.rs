@@ -1,3 +1,9 @@
+use crate::primitives::{
+    AvailableSpace,
+    Color,
+    Size,
+};
+use smart_default::SmartDefault;
 use std::{
     fmt::{
         Debug,
@@ -32,13 +38,113 @@ pub enum SvgDimensions {
     },
 }
 
-/// Options controlling scene vectorization output.
+/// Controls how text nodes are emitted during vectorization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextMode {
+    /// Convert every glyph into a `<path>` element. The output does not depend
+    /// on any font and renders identically everywhere.
+    #[default]
+    Paths,
+    /// Emit `<text>` elements with positioned glyph runs, keeping the text
+    /// selectable and accessible. A subset of every font used is embedded as a
+    /// base64 `@font-face` rule.
+    ///
+    /// The subsets map every character to the glyph it was shaped with, so
+    /// that ligatures, OpenType features and contextual forms render as in
+    /// [`TextMode::Paths`] while the text keeps its original characters, in
+    /// logical order. A character shaped into different glyphs is mapped in
+    /// several subsets. The rare glyphs rendering no character are written as
+    /// private use code points, in which case the original text of the line
+    /// is kept in an `aria-label`.
+    Text,
+}
+
+/// Controls the formatting of the SVG markup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SvgFormat {
+    /// Write the markup on a single line.
+    #[default]
+    Compact,
+    /// Write every element on its own line, indented by its depth. The
+    /// content of `<text>` elements is kept on a single line, as whitespace
+    /// inside it is significant.
+    Pretty,
+    /// Write the smallest markup: path data and glyph positions are rounded
+    /// to fewer decimal places, attributes matching their initial value are
+    /// dropped and resource ids are replaced with short sequential ids.
+    Minify {
+        /// The number of decimal places kept for path data and glyph
+        /// positions. Other values, such as opacities, gradient stops and
+        /// transforms, keep the `4` decimal places of the compact output.
+        precision: u8,
+    },
+}
+
+/// Controls the optimization of glyph outlines written in
+/// [`TextMode::Paths`].
+///
+/// Glyph outlines are written with relative commands. Outlines used more than
+/// once in a text node are written once inside `<defs>` and drawn with `<use>`
+/// elements, and adjacent glyphs filled with the same color are merged into a
+/// single `<path>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmartDefault)]
+pub struct PathOptimization {
+    /// The number of decimal places kept for path coordinates.
+    #[default(2)]
+    pub precision: u8,
+}
+
+/// Options controlling the layout of a scene.
 #[derive(Debug, Clone, Default)]
-pub struct VectorizeOptions {
+pub struct LayoutOptions {
+    /// The size viewport units, such as `vw` and `vmin`, resolve against.
+    ///
+    /// When `None`, the absolute size of the root node is used. An axis of the
+    /// root without an absolute size falls back to the definite
+    /// [`available_space`](Self::available_space) along it, and resolves
+    /// viewport units to zero otherwise.
+    pub viewport: Option<Size<f32>>,
+    /// The space available to the root node along each axis. Defaults to
+    /// [`AvailableSpace::MaxContent`] along both axes, so that the size of the
+    /// scene depends entirely on its content.
+    ///
+    /// A definite width with a max-content height fits the scene to a fixed
+    /// width, wrapping text, and lets it grow in height. A root sized with a
+    /// percentage, such as `width(pct(100.0))`, fills the definite space.
+    pub available_space: Size<AvailableSpace>,
+}
+
+/// Options controlling scene vectorization output.
+#[derive(Debug, Clone, SmartDefault)]
+pub struct VectorizeOptions<'a> {
     /// Controls how `width` and `height` are emitted on the `<svg>` element.
     pub svg_dimensions: SvgDimensions,
     /// Omits the `xmlns` attribute when set to `true`.
     pub omit_svg_xmlns: bool,
+    /// Controls how text is emitted. Rasterization always uses
+    /// [`TextMode::Paths`].
+    pub text_mode: TextMode,
+    /// The prefix of the ids assigned to resources, such as gradients, masks
+    /// and filters, and of every reference to them. Characters that are not
+    /// valid in an XML name are replaced with `_`.
+    #[default("decal".to_string())]
+    pub id_prefix: String,
+    /// A salt mixed into resource ids, keeping them unique when several
+    /// documents are inlined into the same page, such as the index of the
+    /// document.
+    pub id_salt: Option<u64>,
+    /// Controls the formatting of the markup.
+    pub format: SvgFormat,
+    /// Optimizes the glyph outlines of text nodes when set. Disabled by
+    /// default.
+    pub path_optimization: Option<PathOptimization>,
+    /// The options used to lay out the scene.
+    pub layout: LayoutOptions,
+    /// The options used to load automatically sized images and the images
+    /// referenced by inline SVG images. Remote URLs are only loaded through
+    /// [`ImageOptions::href_string_resolver`]. Rasterization uses
+    /// [`RasterizeOptions::image`] instead.
+    pub image: ImageOptions<'a>,
 }
 
 /// Resolver function for [Data URL](https://developer.mozilla.org/en-US/docs/Web/HTTP/Basics_of_HTTP/Data_URIs) based image references.
@@ -79,13 +185,29 @@ impl<'a> Debug for ImageOptions<'a> {
     }
 }
 
+impl ImageOptions<'_> {
+    /// Returns a copy of the options that never fetches remote URLs. External
+    /// images are only loaded through `href_string_resolver`.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn offline(&self) -> Self {
+        let mut options = self.clone();
+        options
+            .href_string_resolver
+            .get_or_insert_with(|| Arc::new(|_, _| None));
+
+        options
+    }
+}
+
 /// Options controlling scene rasterization behavior.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, SmartDefault)]
 pub struct RasterizeOptions<'a> {
     /// Draws node bounding boxes if set to `true`.
     pub debug: bool,
     /// The options used during the scene vectorization stage.
-    pub vectorize_options: VectorizeOptions,
+    pub vectorize_options: VectorizeOptions<'a>,
     /// Controls shape rendering quality and hinting.
     pub shape_rendering: ShapeRendering,
     /// Controls text rendering quality and hinting.
@@ -94,6 +216,14 @@ pub struct RasterizeOptions<'a> {
     pub image_rendering: ImageRendering,
     /// Transform applied to the scene before rasterization.
     pub root_transform: Transform,
+    /// The output scale, such as the device pixel ratio. The size of the
+    /// pixmap and the root transform are both multiplied by this value, and
+    /// color glyph bitmaps, such as emoji, are rasterized at it.
+    #[default(1.0)]
+    pub scale: f32,
+    /// The color the pixmap is filled with before the scene is rendered. The
+    /// pixmap is left transparent when `None`.
+    pub background: Option<Color>,
     /// Image loading and caching options.
     pub image: ImageOptions<'a>,
 }
@@ -0,0 +1,500 @@
+use crate::layout::{
+    image_href_resolver,
+    resolve_image,
+    resolve_image_data,
+    EmbeddedFonts,
+    ImageCache,
+    ImageOptions,
+    LayoutOptions,
+    Scene,
+    TextMode,
+    VectorizeError,
+    VectorizeOptions,
+};
+use pdf_writer::{
+    Chunk,
+    Content,
+    Name,
+    Pdf,
+    Rect,
+    Ref,
+    TextStr,
+};
+use smart_default::SmartDefault;
+use std::{
+    collections::HashMap,
+    io::Write,
+    sync::Arc,
+};
+use svg2pdf::{
+    usvg,
+    usvg::fontdb::{
+        Database,
+        Language,
+        Source,
+    },
+    ConversionError,
+    ConversionOptions,
+};
+use thiserror::Error;
+
+/// The error that may occur while rendering scenes into a PDF document.
+#[derive(Debug, Error)]
+pub enum PdfError {
+    #[error("failed to vectorize")]
+    Vectorize(#[from] VectorizeError),
+    #[error("failed to parse svg")]
+    Parse(#[from] usvg::Error),
+    #[error("failed to convert svg to pdf: {0}")]
+    Convert(ConversionError),
+    #[error("failed to write to the output stream")]
+    Io(#[from] std::io::Error),
+    #[error("cannot render a document without pages")]
+    EmptyDocument,
+    #[error("invalid resolution: {0} dpi")]
+    InvalidDpi(f32),
+}
+
+/// Options controlling PDF output.
+#[derive(Debug, Clone, SmartDefault)]
+pub struct PdfOptions<'a> {
+    /// The resolution at which layout pixels are converted into points. At the
+    /// default of `72`, one pixel maps onto one point. Must be finite and
+    /// positive.
+    #[default(72.0)]
+    pub dpi: f32,
+    /// Compresses content streams when set to `true`.
+    #[default(true)]
+    pub compress: bool,
+    /// Embeds text as selectable text along with a subset of every font used
+    /// when set to `true`. Otherwise, text is converted into paths.
+    #[default(true)]
+    pub embed_text: bool,
+    /// Image loading and caching options.
+    pub image: ImageOptions<'a>,
+    /// The options used to lay out each scene.
+    pub layout: LayoutOptions,
+}
+
+/// A single page of a PDF document.
+pub(crate) struct PdfPage {
+    chunk: Chunk,
+    x_object: Ref,
+    width: f32,
+    height: f32,
+}
+
+impl Scene {
+    /// Converts the scene into a PDF page.
+    ///
+    /// The scene is vectorized and converted into a form XObject, which is
+    /// drawn over the entire page.
+    ///
+    /// # Arguments
+    /// - `image_cache`: Shared image cache.
+    /// - `fontdb`: The fonts used to parse the SVG images of the scene.
+    /// - `options`: The [`PdfOptions`] value.
+    ///
+    /// # Returns
+    /// - [`PdfPage`] on success.
+    /// - [`PdfError`] on failure.
+    pub(crate) fn to_pdf_page(
+        &self,
+        image_cache: &ImageCache,
+        fontdb: Arc<::usvg::fontdb::Database>,
+        options: &PdfOptions,
+    ) -> Result<PdfPage, PdfError> {
+        if !options.dpi.is_finite() || options.dpi <= 0.0 {
+            return Err(PdfError::InvalidDpi(options.dpi));
+        }
+
+        let vectorize_options = VectorizeOptions {
+            text_mode: if options.embed_text {
+                TextMode::Text
+            } else {
+                TextMode::Paths
+            },
+            ..Default::default()
+        };
+
+        let mut svg = String::new();
+        let (size, embedded_fonts) = self.write_vector(&mut svg, &vectorize_options, 1.0)?;
+
+        let usvg_options = pdf_usvg_options(
+            image_cache,
+            &options.image,
+            fontdb,
+            font_database(&embedded_fonts),
+        );
+        let tree = usvg::Tree::from_str(&svg, &usvg_options)?;
+        let (chunk, x_object) = svg2pdf::to_chunk(
+            &tree,
+            ConversionOptions {
+                compress: options.compress,
+                embed_text: options.embed_text,
+                ..Default::default()
+            },
+        )
+        .map_err(PdfError::Convert)?;
+
+        let scale = 72.0 / options.dpi;
+
+        Ok(PdfPage {
+            chunk,
+            x_object,
+            width: size.width * scale,
+            height: size.height * scale,
+        })
+    }
+}
+
+/// A PDF document assembled from pages as they are converted.
+pub(crate) struct PdfDocument {
+    pdf: Pdf,
+    alloc: Ref,
+    catalog_ref: Ref,
+    page_tree_ref: Ref,
+    page_refs: Vec<Ref>,
+}
+
+impl PdfDocument {
+    /// Creates an empty [`PdfDocument`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new() -> Self {
+        let mut alloc = Ref::new(1);
+        let catalog_ref = alloc.bump();
+        let page_tree_ref = alloc.bump();
+
+        Self {
+            pdf: Pdf::new(),
+            alloc,
+            catalog_ref,
+            page_tree_ref,
+            page_refs: Vec::new(),
+        }
+    }
+
+    /// Appends a page to the document.
+    ///
+    /// # Arguments
+    /// - `page`: The [`PdfPage`] to append.
+    pub(crate) fn push_page(&mut self, page: PdfPage) {
+        let Self {
+            pdf,
+            alloc,
+            page_tree_ref,
+            page_refs,
+            ..
+        } = self;
+
+        // move the objects of each page into a distinct range
+        let mut refs = HashMap::new();
+        let chunk = page
+            .chunk
+            .renumber(|old| *refs.entry(old).or_insert_with(|| alloc.bump()));
+        let x_object = refs[&page.x_object];
+        let page_ref = alloc.bump();
+        let content_ref = alloc.bump();
+        let name = Name(b"S1");
+
+        let mut writer = pdf.page(page_ref);
+        writer
+            .media_box(Rect::new(0.0, 0.0, page.width, page.height))
+            .parent(*page_tree_ref)
+            .contents(content_ref);
+        writer.resources().x_objects().pair(name, x_object);
+        drop(writer);
+
+        let mut content = Content::new();
+        content
+            .transform([page.width, 0.0, 0.0, page.height, 0.0, 0.0])
+            .x_object(name);
+
+        pdf.stream(content_ref, &content.finish());
+        pdf.extend(&chunk);
+        page_refs.push(page_ref);
+    }
+
+    /// Finishes the document and writes it to the destination.
+    ///
+    /// # Arguments
+    /// - `destination`: The output writer.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`PdfError`] on failure.
+    pub(crate) fn write<W>(self, destination: &mut W) -> Result<(), PdfError>
+    where
+        W: Write,
+    {
+        let Self {
+            mut pdf,
+            mut alloc,
+            catalog_ref,
+            page_tree_ref,
+            page_refs,
+        } = self;
+
+        if page_refs.is_empty() {
+            return Err(PdfError::EmptyDocument);
+        }
+
+        pdf.catalog(catalog_ref).pages(page_tree_ref);
+        pdf.pages(page_tree_ref)
+            .count(page_refs.len() as i32)
+            .kids(page_refs);
+        pdf.document_info(alloc.bump()).producer(TextStr("decal"));
+
+        destination.write_all(&pdf.finish())?;
+
+        Ok(())
+    }
+}
+
+/// Creates the options used to parse a vectorized scene for the PDF converter.
+///
+/// Images are resolved the way they are for rasterization, with SVG images
+/// parsed with the fonts of the scene, and then converted into the image type
+/// used by the PDF converter.
+///
+/// # Arguments
+/// - `image_cache`: Shared image cache.
+/// - `image_options`: The [`ImageOptions`] value.
+/// - `fontdb`: The fonts used to parse SVG images.
+/// - `embedded_fonts`: The [`Database`] of the embedded font subsets.
+///
+/// # Returns
+/// - The [`usvg::Options`] value.
+fn pdf_usvg_options<'a>(
+    image_cache: &ImageCache,
+    image_options: &ImageOptions<'a>,
+    fontdb: Arc<::usvg::fontdb::Database>,
+    embedded_fonts: Database,
+) -> usvg::Options<'a> {
+    let mut usvg_options = usvg::Options {
+        fontdb: Arc::new(embedded_fonts),
+        ..Default::default()
+    };
+
+    let (cache, opts, fonts) = (image_cache.clone(), image_options.clone(), fontdb.clone());
+    usvg_options.image_href_resolver.resolve_string =
+        Box::new(move |href: &str, pdf_options: &usvg::Options| {
+            resolve_image(&cache, href, &opts, &svg_options(&cache, &opts, &fonts))
+                .and_then(|image| to_pdf_image_kind(image, pdf_options))
+        });
+
+    let (cache, opts) = (image_cache.clone(), image_options.clone());
+    usvg_options.image_href_resolver.resolve_data = Box::new(
+        move |mime: &str, data: Arc<Vec<u8>>, pdf_options: &usvg::Options| {
+            resolve_image_data(mime, data, &opts, &svg_options(&cache, &opts, &fontdb))
+                .and_then(|image| to_pdf_image_kind(image, pdf_options))
+        },
+    );
+
+    usvg_options
+}
+
+/// Creates the options used to parse SVG images.
+///
+/// # Arguments
+/// - `image_cache`: Shared image cache.
+/// - `image_options`: The [`ImageOptions`] value.
+/// - `fontdb`: The fonts used to parse SVG images.
+///
+/// # Returns
+/// - The [`Options`] value.
+///
+/// [`Options`]: ::usvg::Options
+fn svg_options<'a>(
+    image_cache: &'a ImageCache,
+    image_options: &'a ImageOptions,
+    fontdb: &Arc<::usvg::fontdb::Database>,
+) -> ::usvg::Options<'a> {
+    ::usvg::Options {
+        fontdb: fontdb.clone(),
+        image_href_resolver: image_href_resolver(image_cache, image_options),
+        ..Default::default()
+    }
+}
+
+/// Creates a font database containing the embedded font subsets.
+///
+/// Each subset is registered under the family name referenced by the `<text>`
+/// elements of the vectorized scene.
+///
+/// # Arguments
+/// - `embedded_fonts`: The [`EmbeddedFonts`] of the scene.
+///
+/// # Returns
+/// - [`Database`]
+fn font_database(embedded_fonts: &EmbeddedFonts) -> Database {
+    let mut database = Database::new();
+
+    for (family, data) in embedded_fonts.subset_fonts() {
+        for id in database.load_font_source(Source::Binary(Arc::new(data))) {
+            let Some(mut face) = database.face(id).cloned() else {
+                continue;
+            };
+
+            database.remove_face(id);
+            face.families = vec![(family.clone(), Language::English_UnitedStates)];
+            database.push_face_info(face);
+        }
+    }
+
+    database
+}
+
+/// Converts a resolved image into the image type used by the PDF converter.
+///
+/// SVG images are written back with their text converted into paths, and
+/// parsed again with the options of the PDF converter.
+///
+/// # Arguments
+/// - `image`: The resolved [`ImageKind`].
+/// - `options`: The [`usvg::Options`] of the PDF converter.
+///
+/// # Returns
+/// - The converted image, if it can be represented.
+///
+/// [`ImageKind`]: ::usvg::ImageKind
+fn to_pdf_image_kind(image: ::usvg::ImageKind, options: &usvg::Options) -> Option<usvg::ImageKind> {
+    match image {
+        ::usvg::ImageKind::PNG(data) => Some(usvg::ImageKind::PNG(data)),
+        ::usvg::ImageKind::JPEG(data) => Some(usvg::ImageKind::JPEG(data)),
+        ::usvg::ImageKind::WEBP(data) => Some(usvg::ImageKind::WEBP(data)),
+        ::usvg::ImageKind::GIF(data) => Some(usvg::ImageKind::GIF(data)),
+        ::usvg::ImageKind::SVG(tree) => {
+            let svg = tree.to_string(&::usvg::WriteOptions::default());
+            usvg::Tree::from_str(&svg, options)
+                .ok()
+                .map(usvg::ImageKind::SVG)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::{
+            Block,
+            Text,
+        },
+        capabilities::{
+            Dimensions,
+            Drawable,
+        },
+        layout::{
+            Engine,
+            EngineOptions,
+            FontRegistry,
+        },
+    };
+    use base64::{
+        engine::general_purpose::STANDARD as BASE64,
+        Engine as _,
+    };
+    use lru::LruCache;
+    use parking_lot::Mutex;
+
+    fn scene(text: &str) -> Scene {
+        let mut scene = Scene::new(Block::new().size((200, 100)).finish());
+        scene.append_child(scene.root_id(), Text::new(text).finish());
+        scene
+    }
+
+    #[test]
+    fn renders_one_page_per_scene() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let pdf = engine
+            .render_pdf_pages(
+                &mut [scene("first"), scene("second")],
+                &PdfOptions::default(),
+            )
+            .unwrap();
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with("%PDF-"));
+        assert!(pdf.contains("/Count 2"));
+        assert_eq!(pdf.matches("/MediaBox [0 0 200 100]").count(), 2);
+    }
+
+    #[test]
+    fn scales_pages_by_dpi() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let pdf = engine
+            .render_pdf(
+                &mut scene("text"),
+                &PdfOptions {
+                    dpi: 144.0,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+
+        assert!(String::from_utf8_lossy(&pdf).contains("/MediaBox [0 0 100 50]"));
+    }
+
+    #[test]
+    fn rejects_invalid_dpi() {
+        let mut engine = Engine::new(EngineOptions::default());
+
+        for dpi in [0.0, -72.0, f32::NAN, f32::INFINITY] {
+            assert!(matches!(
+                engine.render_pdf(
+                    &mut scene("text"),
+                    &PdfOptions {
+                        dpi,
+                        ..Default::default()
+                    },
+                ),
+                Err(PdfError::InvalidDpi(_))
+            ));
+        }
+    }
+
+    fn find_image(group: &usvg::Group) -> Option<&usvg::Image> {
+        group.children().iter().find_map(|node| match node {
+            usvg::Node::Image(image) => Some(image.as_ref()),
+            usvg::Node::Group(group) => find_image(group),
+            _ => None,
+        })
+    }
+
+    #[test]
+    fn parses_svg_images_with_the_scene_fonts() {
+        const NESTED: &str = r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="20"><text y="16" font-family="DejaVu Sans" font-size="16">nested</text></svg>"#;
+
+        let fonts = FontRegistry::default();
+        let image_cache = Arc::new(Mutex::new(LruCache::unbounded()));
+        let options = pdf_usvg_options(
+            &image_cache,
+            &ImageOptions::default(),
+            Arc::new(fonts.system.db().clone()),
+            Database::new(),
+        );
+        let svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="20"><image width="100" height="20" href="data:image/svg+xml;base64,{}"/></svg>"#,
+            BASE64.encode(NESTED)
+        );
+        let tree = usvg::Tree::from_str(&svg, &options).unwrap();
+
+        let Some(usvg::ImageKind::SVG(nested)) = find_image(tree.root()).map(|x| x.kind()) else {
+            panic!("expected an svg image");
+        };
+
+        // the text of the image is kept as paths
+        assert!(nested.root().has_children());
+    }
+
+    #[test]
+    fn rejects_empty_documents() {
+        assert!(matches!(
+            PdfDocument::new().write(&mut Vec::new()),
+            Err(PdfError::EmptyDocument)
+        ));
+    }
+}
@@ -1,22 +1,51 @@
 use crate::{
     layout::{
+        resolve_viewport_style,
+        DirectRenderer,
+        EmbeddedFonts,
         FontRegistry,
         ImageCache,
         ImageOptions,
+        LayoutBoxes,
+        LayoutOptions,
         Node,
         NodeId,
         NodeKind,
+        NodeLayout,
         RasterizeOptions,
         RenderContext,
         SvgDimensions,
+        SvgFormat,
+        TextMode,
         Typography,
+        VectorOutputFormat,
         VectorizeError,
         VectorizeOptions,
     },
-    paint::Resources,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        Resources,
+    },
     prelude::ViewBox,
-    primitives::Size,
-    utils::ElementWriter,
+    primitives::{
+        AvailableSpace,
+        Calc,
+        Size,
+    },
+    utils::{
+        ElementWriter,
+        IoWriter,
+        XmlFormatter,
+    },
+};
+use base64::{
+    engine::general_purpose::STANDARD as BASE64,
+    Engine as _,
+};
+use flate2::{
+    write::GzEncoder,
+    Compression,
 };
 use parking_lot::Mutex;
 use resvg::render;
@@ -33,7 +62,6 @@ use taffy::{
     compute_flexbox_layout,
     compute_leaf_layout,
     compute_root_layout,
-    prelude::TaffyMaxContent,
     print_tree,
     round_layout,
     CacheTree,
@@ -44,14 +72,19 @@ use taffy::{
     TraverseTree,
 };
 use thiserror::Error;
-use tiny_skia::Pixmap;
+use tiny_skia::{
+    IntRect,
+    Pixmap,
+    PixmapMut,
+};
 use usvg::{
+    fontdb::Database,
     ImageHrefResolver,
     ImageKind,
     Tree,
 };
 
-const ROOT_ID: usize = 0;
+pub(crate) const ROOT_ID: usize = 0;
 const INLINE_FRAG_CASCADE: usize = 16;
 
 /// The error that may occur during rasterization of a scene.
@@ -73,7 +106,9 @@ pub enum RasterizeError {
 pub struct Scene {
     pub(crate) fonts: Arc<Mutex<FontRegistry>>,
     pub(crate) resources: Mutex<Resources>,
-    nodes: Vec<Node>,
+    pub(crate) nodes: Vec<Node>,
+    /// The size viewport units resolved against during the last layout.
+    viewport: Size<f32>,
 }
 
 impl Scene {
@@ -96,6 +131,7 @@ impl Scene {
             fonts: Arc::new(Mutex::new(FontRegistry::new())),
             resources: Mutex::new(resources),
             nodes: vec![root],
+            viewport: Size::default(),
         }
     }
 
@@ -105,6 +141,7 @@ impl Scene {
             fonts: Arc::new(Mutex::new(FontRegistry::new())),
             resources: Mutex::new(Resources::default()),
             nodes: vec![],
+            viewport: Size::default(),
         }
     }
 
@@ -124,6 +161,29 @@ impl Scene {
         self.nodes.len()
     }
 
+    /// Returns the computed layout of the node with the given id.
+    ///
+    /// The layout is computed when the scene is rendered, or explicitly with
+    /// [`Engine::layout`](crate::layout::Engine::layout). Until then, every
+    /// box is empty.
+    ///
+    /// # Arguments
+    /// - `node_id`: The [`NodeId`] of the node.
+    ///
+    /// # Returns
+    /// - [`NodeLayout`] of the node.
+    /// - `None` if no node with the given id exists in the scene.
+    pub fn layout(&self, node_id: NodeId) -> Option<NodeLayout> {
+        let node = self.nodes.get(usize::from(node_id))?;
+        let layout = &node.final_layout;
+        let location = node.absolute_location;
+
+        Some(NodeLayout {
+            relative: LayoutBoxes::new(layout.location.x, layout.location.y, layout),
+            absolute: LayoutBoxes::new(location.x, location.y, layout),
+        })
+    }
+
     /// Appends a child node to the scene under the given parent node.
     ///
     /// # Arguments
@@ -219,6 +279,64 @@ impl Scene {
         out: &mut T,
         options: &VectorizeOptions,
     ) -> Result<Size<f32>, VectorizeError>
+    where
+        T: Write,
+    {
+        self.write_vector(out, options, 1.0).map(|(size, _)| size)
+    }
+
+    /// Writes the vectorized SVG representation into the given output writer,
+    /// retaining the font faces embedded into it.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `options`: The [`VectorizeOptions`] value.
+    /// - `bitmap_scale`: The scale at which color glyph bitmaps are rasterized.
+    ///
+    /// # Returns
+    /// - On success, a tuple containing:
+    ///     - [`Size<f32>`]: Scene size.
+    ///     - [`EmbeddedFonts`]: The embedded font faces.
+    /// - [`VectorizeError`] on failure.
+    pub(crate) fn write_vector<T>(
+        &self,
+        out: &mut T,
+        options: &VectorizeOptions,
+        bitmap_scale: f32,
+    ) -> Result<(Size<f32>, EmbeddedFonts), VectorizeError>
+    where
+        T: Write,
+    {
+        let mut formatter = match options.format {
+            SvgFormat::Compact => return self.write_document(out, options, bitmap_scale),
+            SvgFormat::Pretty => XmlFormatter::pretty(out),
+            SvgFormat::Minify { precision } => XmlFormatter::minified(out, precision),
+        };
+
+        let result = self.write_document(&mut formatter, options, bitmap_scale)?;
+        formatter.finish()?;
+
+        Ok(result)
+    }
+
+    /// Writes the SVG document of the scene into the given output writer.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `options`: The [`VectorizeOptions`] value.
+    /// - `bitmap_scale`: The scale at which color glyph bitmaps are rasterized.
+    ///
+    /// # Returns
+    /// - On success, a tuple containing:
+    ///     - [`Size<f32>`]: Scene size.
+    ///     - [`EmbeddedFonts`]: The embedded font faces.
+    /// - [`VectorizeError`] on failure.
+    fn write_document<T>(
+        &self,
+        out: &mut T,
+        options: &VectorizeOptions,
+        bitmap_scale: f32,
+    ) -> Result<(Size<f32>, EmbeddedFonts), VectorizeError>
     where
         T: Write,
     {
@@ -256,29 +374,49 @@ impl Scene {
 
         //
 
-        self.emit_node(
-            &mut RenderContext {
-                out,
-                scene: &self,
-                scene_size: size,
-            },
-            None,
-            None,
-        )?;
+        let mut ctx = RenderContext {
+            out,
+            scene: &self,
+            scene_size: size,
+            text_mode: options.text_mode,
+            bitmap_scale,
+            path_optimization: options.path_optimization,
+            embedded_fonts: EmbeddedFonts::default(),
+            svg_images: 0,
+            namespace: IriNamespace::new(
+                &options.id_prefix,
+                options.id_salt,
+                matches!(options.format, SvgFormat::Minify { .. }),
+            ),
+        };
+
+        self.emit_node(&mut ctx, None, None)?;
 
         //
 
+        let RenderContext {
+            out,
+            embedded_fonts,
+            namespace,
+            ..
+        } = ctx;
         let resources = self.resources.lock();
 
-        if !resources.is_empty() {
+        if !resources.is_empty() || !embedded_fonts.is_empty() {
             ElementWriter::new(out, "defs")?
-                .content(|out| out.write_fmt(format_args!("{resources}")))?
+                .content(|out| {
+                    if !embedded_fonts.is_empty() {
+                        embedded_fonts.write_style(out)?;
+                    }
+
+                    out.write_fmt(format_args!("{}", resources.display_in(&namespace)))
+                })?
                 .close()?;
         }
 
         ElementWriter::close_tag(out, "svg")?;
 
-        Ok(size)
+        Ok((size, embedded_fonts))
     }
 
     /// Vectorizes the scene into an SVG string.
@@ -300,6 +438,46 @@ impl Scene {
             .map(|scene_size| (out, scene_size))
     }
 
+    /// Streams the vectorized SVG representation into the given destination,
+    /// without buffering the whole document. Formatted markup holds back the
+    /// tag being written, see [`XmlFormatter`].
+    ///
+    /// # Arguments
+    /// - `destination`: The output writer.
+    /// - `format`: The [`VectorOutputFormat`] value.
+    /// - `options`: The [`VectorizeOptions`] value.
+    ///
+    /// # Returns
+    /// - Scene size on success.
+    /// - [`VectorizeError`] on failure.
+    pub(crate) fn encode_vector<T>(
+        &self,
+        destination: &mut T,
+        format: VectorOutputFormat,
+        options: &VectorizeOptions,
+    ) -> Result<Size<f32>, VectorizeError>
+    where
+        T: std::io::Write,
+    {
+        match format {
+            VectorOutputFormat::Svg => {
+                let mut out = IoWriter::new(destination);
+                let result = self.stream_vector(&mut out, options);
+                out.into_result(result)
+            }
+            VectorOutputFormat::Svgz { level } => {
+                let mut encoder =
+                    GzEncoder::new(destination, Compression::new(level.min(9).into()));
+                let mut out = IoWriter::new(&mut encoder);
+                let result = self.stream_vector(&mut out, options);
+                let size = out.into_result(result)?;
+
+                encoder.finish()?;
+                Ok(size)
+            }
+        }
+    }
+
     /// Rasterizes the scene into a [`Pixmap`].
     ///
     /// # Arguments
@@ -316,66 +494,217 @@ impl Scene {
         image_cache: &ImageCache,
         options: &RasterizeOptions,
     ) -> Result<(Pixmap, Size<f32>), RasterizeError> {
-        let tf = options.root_transform;
-        let mut usvg_options = usvg::Options {
+        let (source, size) = self.to_render_source(image_cache, options)?;
+        let (width, height) = pixel_size(size, options.scale);
+        let region = IntRect::from_xywh(0, 0, width, height).ok_or(RasterizeError::PixmapAlloc)?;
+
+        render_region(&source, region, options).map(|pixmap| (pixmap, size))
+    }
+
+    /// Prepares the scene for rasterization.
+    ///
+    /// Scenes supported by the [`DirectRenderer`] are drawn as they are, while
+    /// other scenes are vectorized and parsed into a render tree.
+    ///
+    /// # Arguments
+    /// - `image_cache`: Shared image cache.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Returns
+    /// - On success, a tuple containing:
+    ///     - [`RenderSource`]: The source to render.
+    ///     - [`Size<f32>`]: Scene size.
+    /// - [`RasterizeError`] on failure.
+    pub(crate) fn to_render_source(
+        &self,
+        image_cache: &ImageCache,
+        options: &RasterizeOptions,
+    ) -> Result<(RenderSource<'_>, Size<f32>), RasterizeError> {
+        if DirectRenderer::supports(self, options) {
+            let size = Size::from(self.nodes[ROOT_ID].final_layout.size);
+            return Ok((RenderSource::Scene(self), size));
+        }
+
+        self.to_render_tree(image_cache, options)
+            .map(|(tree, size)| (RenderSource::Tree(Box::new(tree)), size))
+    }
+
+    /// Vectorizes the scene and parses the result into a render tree.
+    ///
+    /// # Arguments
+    /// - `image_cache`: Shared image cache.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Returns
+    /// - On success, a tuple containing:
+    ///     - [`Tree`]: The parsed render tree.
+    ///     - [`Size<f32>`]: Scene size.
+    /// - [`RasterizeError`] on failure.
+    pub(crate) fn to_render_tree(
+        &self,
+        image_cache: &ImageCache,
+        options: &RasterizeOptions,
+    ) -> Result<(Tree, Size<f32>), RasterizeError> {
+        let usvg_options = usvg::Options {
             shape_rendering: options.shape_rendering,
             text_rendering: options.text_rendering,
             image_rendering: options.image_rendering,
-            image_href_resolver: ImageHrefResolver {
-                resolve_string: Box::new(move |href: &str, usvg_opts: &usvg::Options| {
-                    fetch_image_cached(image_cache, href, &options.image, usvg_opts)
-                }),
-                ..Default::default()
-            },
+            image_href_resolver: image_href_resolver(image_cache, &options.image),
             ..Default::default()
         };
 
-        if let Some(ref resolve_data) = options.image.href_data_resolver {
-            usvg_options.image_href_resolver.resolve_data = Box::new(
-                move |mime: &str, data: Arc<Vec<u8>>, opts: &usvg::Options| {
-                    resolve_data(mime, data, opts)
-                },
-            );
-        }
+        // embedded fonts are not supported by the renderer
+        let vectorize_options = VectorizeOptions {
+            text_mode: TextMode::Paths,
+            ..options.vectorize_options.clone()
+        };
 
-        let (svg, size) = self.vectorize(&options.vectorize_options)?;
+        let mut svg = String::new();
+        let (size, _) = self.write_vector(&mut svg, &vectorize_options, options.scale)?;
         let tree = Tree::from_str(&svg, &usvg_options).map_err(RasterizeError::Parse)?;
-        let mut pixmap = Pixmap::new(size.width() as u32, size.height() as u32)
-            .ok_or(RasterizeError::PixmapAlloc)?;
-
-        render(&tree, tf, &mut pixmap.as_mut());
 
-        if options.debug {
-            let mut bboxes = Vec::new();
-            let mut stroke_bboxes = Vec::new();
+        Ok((tree, size))
+    }
 
-            collect_bboxes(tree.root(), &mut bboxes, &mut stroke_bboxes);
+    /// Reads the intrinsic size of images that are sized automatically.
+    ///
+    /// Images are resolved through the shared image cache, so that the data
+    /// fetched here is reused during rasterization.
+    ///
+    /// # Arguments
+    /// - `image_cache`: Shared image cache.
+    /// - `options`: The [`ImageOptions`] value.
+    pub(crate) fn resolve_image_sizes(&mut self, image_cache: &ImageCache, options: &ImageOptions) {
+        let usvg_options = usvg::Options::default();
+        let mut resolved = false;
+
+        for node in &mut self.nodes {
+            let NodeKind::Image(ref mut meta) = node.kind else {
+                continue;
+            };
+
+            if !meta.auto_size {
+                continue;
+            }
 
-            let stroke = tiny_skia::Stroke::default();
-            let mut paint = tiny_skia::Paint::default();
-            paint.set_color_rgba8(224, 16, 0, 195);
+            let Some(size) = meta
+                .source
+                .url()
+                .and_then(|href| resolve_image(image_cache, &href, options, &usvg_options))
+                .and_then(|image| image_size(&image))
+            else {
+                continue;
+            };
+
+            (meta.width, meta.height) = size;
+            meta.auto_size = false;
+            resolved = true;
+        }
 
-            for bbox in bboxes {
-                let path = tiny_skia::PathBuilder::from_rect(bbox);
-                pixmap.stroke_path(&path, &paint, &stroke, tf, None);
+        // previously computed layouts depend on the unresolved sizes
+        if resolved {
+            for node in &mut self.nodes {
+                node.cache.clear();
             }
+        }
+    }
 
-            paint.set_color_rgba8(0, 45, 255, 127);
+    /// Parses the markup of inline SVG images with the fonts of the engine,
+    /// resolving the images they reference like any other image.
+    ///
+    /// # Arguments
+    /// - `image_cache`: Shared image cache.
+    /// - `options`: The [`ImageOptions`] value.
+    /// - `fontdb`: The font database of the engine.
+    pub(crate) fn resolve_svg_images(
+        &mut self,
+        image_cache: &ImageCache,
+        options: &ImageOptions,
+        fontdb: Arc<Database>,
+    ) {
+        let usvg_options = usvg::Options {
+            fontdb,
+            image_href_resolver: image_href_resolver(image_cache, options),
+            ..Default::default()
+        };
+        let mut resized = false;
 
-            for bbox in stroke_bboxes {
-                let path = tiny_skia::PathBuilder::from_rect(bbox);
-                pixmap.stroke_path(&path, &paint, &stroke, tf, None);
+        for node in &mut self.nodes {
+            if let NodeKind::Image(ref mut meta) = node.kind {
+                resized |= meta.resolve_svg(&usvg_options);
             }
         }
 
-        Ok((pixmap, size))
+        // previously computed layouts depend on the unresolved sizes
+        if resized {
+            for node in &mut self.nodes {
+                node.cache.clear();
+            }
+        }
     }
 
     /// Computes layout for all nodes in the scene.
-    pub(crate) fn compute_layout(&mut self) {
+    ///
+    /// # Arguments
+    /// - `options`: The [`LayoutOptions`] value.
+    pub(crate) fn compute_layout(&mut self, options: &LayoutOptions) {
+        let available_space = options.available_space;
+        let viewport = options.viewport.unwrap_or_else(|| {
+            let absolute = |x: taffy::Dimension, available_space| {
+                if x.tag() == taffy::CompactLength::LENGTH_TAG {
+                    x.value()
+                } else if let AvailableSpace::Definite(value) = available_space {
+                    value
+                } else {
+                    0.0
+                }
+            };
+            let size = self.nodes[ROOT_ID].layout.size;
+            Size::from_values(
+                absolute(size.width, available_space.width),
+                absolute(size.height, available_space.height),
+            )
+        });
+
+        for node in &mut self.nodes {
+            node.resolved_layout = resolve_viewport_style(&node.layout, viewport);
+        }
+
+        // previously computed layouts and shaped text depend on the viewport
+        if viewport != self.viewport {
+            self.viewport = viewport;
+
+            for node in &mut self.nodes {
+                node.cache.clear();
+
+                if let NodeKind::Text(ref mut meta) = node.kind {
+                    meta.clear_buffer();
+                }
+            }
+        }
+
         let root_id = taffy::NodeId::from(self.root_id());
-        compute_root_layout(self, root_id, taffy::Size::MAX_CONTENT);
+        compute_root_layout(self, root_id, available_space.into());
         round_layout(self, root_id);
+        self.compute_absolute_locations();
+    }
+
+    /// Stores the location of each node relative to the root, accumulating the
+    /// locations of its ancestors in a single pass over the tree.
+    fn compute_absolute_locations(&mut self) {
+        let mut stack = vec![(ROOT_ID, taffy::Point::ZERO)];
+
+        while let Some((idx, parent)) = stack.pop() {
+            let node = &mut self.nodes[idx];
+            let location = node.final_layout.location;
+            node.absolute_location = taffy::Point {
+                x: parent.x + location.x,
+                y: parent.y + location.y,
+            };
+
+            let absolute = node.absolute_location;
+            stack.extend(node.children.iter().map(|child| (*child, absolute)));
+        }
     }
 
     /// Sets the font registry used for layout and rendering.
@@ -455,6 +784,121 @@ fn to_image_kind(data: Arc<Vec<u8>>) -> Option<ImageKind> {
     }
 }
 
+/// Reads the pixel size of an image from its header.
+///
+/// # Arguments
+/// - `image`: The [`ImageKind`] value.
+///
+/// # Returns
+/// - The `(width, height)` of the image, if it can be determined.
+fn image_size(image: &ImageKind) -> Option<(f32, f32)> {
+    match image {
+        ImageKind::PNG(data)
+        | ImageKind::JPEG(data)
+        | ImageKind::WEBP(data)
+        | ImageKind::GIF(data) => imagesize::blob_size(data)
+            .ok()
+            .map(|size| (size.width as f32, size.height as f32)),
+        ImageKind::SVG(tree) => Some((tree.size().width(), tree.size().height())),
+    }
+}
+
+/// Creates the resolver used to load the images referenced by a document.
+///
+/// # Arguments
+/// - `image_cache`: The shared image cache.
+/// - `opts`: The [`ImageOptions`] value.
+///
+/// # Returns
+/// - The [`ImageHrefResolver`] value.
+pub(crate) fn image_href_resolver<'a>(
+    image_cache: &'a ImageCache,
+    opts: &'a ImageOptions,
+) -> ImageHrefResolver<'a> {
+    let mut resolver = ImageHrefResolver {
+        resolve_string: Box::new(move |href: &str, usvg_opts: &usvg::Options| {
+            fetch_image_cached(image_cache, href, opts, usvg_opts)
+        }),
+        ..Default::default()
+    };
+
+    if let Some(ref resolve_data) = opts.href_data_resolver {
+        resolver.resolve_data = Box::new(
+            move |mime: &str, data: Arc<Vec<u8>>, usvg_opts: &usvg::Options| {
+                resolve_data(mime, data, usvg_opts)
+            },
+        );
+    }
+
+    resolver
+}
+
+/// Resolves an image reference, decoding data URLs in place.
+///
+/// # Arguments
+/// - `image_cache`: The shared image cache.
+/// - `href`: The image reference.
+/// - `opts`: The [`ImageOptions`] value.
+/// - `usvg_opts`: The [`Options`] value.
+///
+/// # Returns
+/// - `Some(ImageKind)` on success
+/// - `None` during failure
+///
+/// [`Options`]: usvg::Options
+pub(crate) fn resolve_image(
+    image_cache: &ImageCache,
+    href: &str,
+    opts: &ImageOptions,
+    usvg_opts: &usvg::Options,
+) -> Option<ImageKind> {
+    let Some(data_url) = href.strip_prefix("data:") else {
+        return fetch_image_cached(image_cache, href, opts, usvg_opts);
+    };
+
+    let (meta, payload) = data_url.split_once(',')?;
+    let (mime, is_base64) = match meta.strip_suffix(";base64") {
+        Some(mime) => (mime, true),
+        None => (meta, false),
+    };
+    let data = if is_base64 {
+        BASE64.decode(payload.trim()).ok()?
+    } else {
+        payload.as_bytes().to_vec()
+    };
+
+    resolve_image_data(mime, Arc::new(data), opts, usvg_opts)
+}
+
+/// Resolves the decoded data of a data URL.
+///
+/// # Arguments
+/// - `mime`: The MIME type of the data.
+/// - `data`: The decoded data.
+/// - `opts`: The [`ImageOptions`] value.
+/// - `usvg_opts`: The [`Options`] value.
+///
+/// # Returns
+/// - `Some(ImageKind)` on success
+/// - `None` during failure
+///
+/// [`Options`]: usvg::Options
+pub(crate) fn resolve_image_data(
+    mime: &str,
+    data: Arc<Vec<u8>>,
+    opts: &ImageOptions,
+    usvg_opts: &usvg::Options,
+) -> Option<ImageKind> {
+    if let Some(resolve_data) = &opts.href_data_resolver {
+        return resolve_data(mime, data, usvg_opts);
+    }
+
+    match mime {
+        "image/svg+xml" => Tree::from_data(&data, usvg_opts).ok().map(ImageKind::SVG),
+        _ => to_image_kind(data),
+    }
+}
+
 /// Resolves and caches external images referenced by `href`.
 ///
 /// # Arguments
@@ -536,6 +980,152 @@ fn cascade_typography_subtree(nodes: &mut [Node], parent_typography: &Typography
     }
 }
 
+/// Computes the size of the pixmap holding the scene at the given scale.
+///
+/// # Arguments
+/// - `size`: The scene size.
+/// - `scale`: The output scale.
+///
+/// # Returns
+/// - The pixmap width and height.
+pub(crate) fn pixel_size(size: Size<f32>, scale: f32) -> (u32, u32) {
+    (
+        (size.width() * scale).ceil() as u32,
+        (size.height() * scale).ceil() as u32,
+    )
+}
+
+/// The source a scene is rasterized from.
+#[derive(Debug)]
+pub(crate) enum RenderSource<'a> {
+    /// The scene, drawn by the [`DirectRenderer`].
+    Scene(&'a Scene),
+    /// The render tree parsed from the vectorized scene.
+    Tree(Box<Tree>),
+}
+
+/// Renders a region of the render source into a [`Pixmap`].
+///
+/// # Arguments
+/// - `source`: The [`RenderSource`] value.
+/// - `region`: The region to render, in output pixels.
+/// - `options`: The [`RasterizeOptions`] value.
+///
+/// # Returns
+/// - [`Pixmap`] with the size of the region on success.
+/// - [`RasterizeError`] on failure.
+pub(crate) fn render_region(
+    source: &RenderSource,
+    region: IntRect,
+    options: &RasterizeOptions,
+) -> Result<Pixmap, RasterizeError> {
+    let mut pixmap =
+        Pixmap::new(region.width(), region.height()).ok_or(RasterizeError::PixmapAlloc)?;
+
+    if let Some(background) = options.background {
+        pixmap.fill(background.into());
+    }
+
+    draw_source(
+        source,
+        &mut pixmap.as_mut(),
+        tiny_skia::Transform::from_translate(-region.x() as f32, -region.y() as f32),
+        options,
+    );
+
+    Ok(pixmap)
+}
+
+/// Renders the render source into an existing pixmap at the given offset.
+///
+/// # Arguments
+/// - `source`: The [`RenderSource`] value.
+/// - `size`: The scene size.
+/// - `target`: The pixmap to render into.
+/// - `offset`: The offset of the scene in the target, in pixels.
+/// - `clear`: Whether to clear the whole target before rendering.
+/// - `options`: The [`RasterizeOptions`] value.
+pub(crate) fn render_into(
+    source: &RenderSource,
+    size: Size<f32>,
+    target: &mut PixmapMut,
+    (x, y): (i32, i32),
+    clear: bool,
+    options: &RasterizeOptions,
+) {
+    if clear {
+        target.fill(tiny_skia::Color::TRANSPARENT);
+    }
+
+    if let Some(background) = options.background {
+        let (width, height) = pixel_size(size, options.scale);
+
+        if let Some(rect) =
+            tiny_skia::Rect::from_xywh(x as f32, y as f32, width as f32, height as f32)
+        {
+            let mut paint = tiny_skia::Paint::default();
+            paint.set_color(background.into());
+            paint.blend_mode = tiny_skia::BlendMode::Source;
+            target.fill_rect(rect, &paint, tiny_skia::Transform::identity(), None);
+        }
+    }
+
+    draw_source(
+        source,
+        target,
+        tiny_skia::Transform::from_translate(x as f32, y as f32),
+        options,
+    );
+}
+
+/// Draws the render source, and the debug bounding boxes if enabled.
+///
+/// # Arguments
+/// - `source`: The [`RenderSource`] value.
+/// - `target`: The pixmap to draw into.
+/// - `offset`: The transform placing the scene in the target, in pixels.
+/// - `options`: The [`RasterizeOptions`] value.
+fn draw_source(
+    source: &RenderSource,
+    target: &mut PixmapMut,
+    offset: tiny_skia::Transform,
+    options: &RasterizeOptions,
+) {
+    let tf = offset
+        .pre_scale(options.scale, options.scale)
+        .pre_concat(options.root_transform);
+
+    let tree = match source {
+        RenderSource::Scene(scene) => return DirectRenderer::draw(scene, target, tf, options),
+        RenderSource::Tree(tree) => tree,
+    };
+
+    render(tree, tf, target);
+
+    if options.debug {
+        let mut bboxes = Vec::new();
+        let mut stroke_bboxes = Vec::new();
+
+        collect_bboxes(tree.root(), &mut bboxes, &mut stroke_bboxes);
+
+        let stroke = tiny_skia::Stroke::default();
+        let mut paint = tiny_skia::Paint::default();
+        paint.set_color_rgba8(224, 16, 0, 195);
+
+        for bbox in bboxes {
+            let path = tiny_skia::PathBuilder::from_rect(bbox);
+            target.stroke_path(&path, &paint, &stroke, tf, None);
+        }
+
+        paint.set_color_rgba8(0, 45, 255, 127);
+
+        for bbox in stroke_bboxes {
+            let path = tiny_skia::PathBuilder::from_rect(bbox);
+            target.stroke_path(&path, &paint, &stroke, tf, None);
+        }
+    }
+}
+
 /// Collects bounding boxes and stroke bounding boxes for debugging output.
 fn collect_bboxes(
     parent: &usvg::Group,
@@ -609,11 +1199,11 @@ impl LayoutPartialTree for Scene {
     type CustomIdent = String;
 
     fn get_core_container_style(&self, node_id: taffy::NodeId) -> Self::CoreContainerStyle<'_> {
-        &self.node_from_id(node_id).layout
+        self.node_from_id(node_id).style()
     }
 
-    fn resolve_calc_value(&self, _val: *const (), _basis: f32) -> f32 {
-        0.0
+    fn resolve_calc_value(&self, val: *const (), basis: f32) -> f32 {
+        Calc::resolve_ptr(val, basis, self.viewport)
     }
 
     fn set_unrounded_layout(&mut self, node_id: taffy::NodeId, layout: &taffy::Layout) {
@@ -625,8 +1215,13 @@ impl LayoutPartialTree for Scene {
         node_id: taffy::NodeId,
         inputs: taffy::tree::LayoutInput,
     ) -> taffy::tree::LayoutOutput {
+        let viewport = self.viewport;
+        let resolve_calc = |val, basis| Calc::resolve_ptr(val, basis, viewport);
+
         compute_cached_layout(self, node_id, inputs, |tree, node_id, inputs| {
             let node = &mut tree.nodes[usize::from(node_id)];
+            let style = node.resolved_layout.as_ref().unwrap_or(&node.layout);
+
             match node.kind {
                 NodeKind::Block => compute_block_layout(tree, node_id, inputs),
                 NodeKind::Flex | NodeKind::Column | NodeKind::Row => {
@@ -636,16 +1231,27 @@ impl LayoutPartialTree for Scene {
                 NodeKind::Grid => compute_grid_layout(tree, node_id, inputs),
                 NodeKind::Text(ref mut meta) => compute_leaf_layout(
                     inputs,
-                    &node.layout,
-                    |_val, _basis| 0.0,
+                    style,
+                    resolve_calc,
                     |known_dimensions, available_space| {
-                        meta.measure(known_dimensions, available_space, tree.fonts.clone())
+                        meta.measure(
+                            known_dimensions,
+                            available_space,
+                            tree.fonts.clone(),
+                            viewport,
+                        )
                     },
                 ),
                 NodeKind::Image(ref mut meta) => compute_leaf_layout(
                     inputs,
-                    &node.layout,
-                    |_val, _basis| 0.0,
+                    style,
+                    resolve_calc,
+                    |known_dimensions, _available_space| meta.measure(known_dimensions),
+                ),
+                NodeKind::Shape(ref meta) => compute_leaf_layout(
+                    inputs,
+                    style,
+                    resolve_calc,
                     |known_dimensions, _available_space| meta.measure(known_dimensions),
                 ),
             }
@@ -699,11 +1305,11 @@ impl taffy::LayoutBlockContainer for Scene {
         Self: 'a;
 
     fn get_block_container_style(&self, node_id: taffy::NodeId) -> Self::BlockContainerStyle<'_> {
-        &self.node_from_id(node_id).layout
+        self.node_from_id(node_id).style()
     }
 
     fn get_block_child_style(&self, child_node_id: taffy::NodeId) -> Self::BlockItemStyle<'_> {
-        &self.node_from_id(child_node_id).layout
+        self.node_from_id(child_node_id).style()
     }
 }
 
@@ -722,11 +1328,11 @@ impl taffy::LayoutFlexboxContainer for Scene {
         &self,
         node_id: taffy::NodeId,
     ) -> Self::FlexboxContainerStyle<'_> {
-        &self.node_from_id(node_id).layout
+        self.node_from_id(node_id).style()
     }
 
     fn get_flexbox_child_style(&self, child_node_id: taffy::NodeId) -> Self::FlexboxItemStyle<'_> {
-        &self.node_from_id(child_node_id).layout
+        self.node_from_id(child_node_id).style()
     }
 }
 
@@ -743,11 +1349,11 @@ impl taffy::LayoutGridContainer for Scene {
         Self: 'a;
 
     fn get_grid_container_style(&self, node_id: taffy::NodeId) -> Self::GridContainerStyle<'_> {
-        &self.node_from_id(node_id).layout
+        self.node_from_id(node_id).style()
     }
 
     fn get_grid_child_style(&self, child_node_id: taffy::NodeId) -> Self::GridItemStyle<'_> {
-        &self.node_from_id(child_node_id).layout
+        self.node_from_id(child_node_id).style()
     }
 }
 
@@ -757,9 +1363,10 @@ impl RoundTree for Scene {
     }
 
     fn set_final_layout(&mut self, node_id: taffy::NodeId, layout: &taffy::Layout) {
+        let viewport = self.viewport;
         let node = self.node_from_id_mut(node_id);
         node.final_layout = *layout;
-        node.apply_layout_effects();
+        node.apply_layout_effects(viewport);
     }
 }
 
@@ -774,6 +1381,7 @@ impl PrintTree for Scene {
             NodeKind::Grid => "GRID",
             NodeKind::Text(_) => "TEXT",
             NodeKind::Image(_) => "IMAGE",
+            NodeKind::Shape(_) => "SHAPE",
         }
     }
 
@@ -781,3 +1389,497 @@ impl PrintTree for Scene {
         self.node_from_id(node_id).final_layout
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn rasterize(child: Node) -> tiny_skia::Pixmap {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Column::new().size((50, 30)).padding(5).finish());
+        let root = scene.root_id();
+        scene.append_child(root, child);
+
+        engine
+            .rasterize(&mut scene, &RasterizeOptions::default())
+            .unwrap()
+            .0
+    }
+
+    fn is_filled(pixmap: &tiny_skia::Pixmap, x: u32, y: u32) -> bool {
+        pixmap.pixel(x, y).unwrap().alpha() == 255
+    }
+
+    #[test]
+    fn scales_pixmap_and_root_transform() {
+        let mut scene = Scene::new(
+            Block::new()
+                .size((20, 10))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+        let options = RasterizeOptions {
+            root_transform: tiny_skia::Transform::from_translate(5.0, 0.0),
+            scale: 2.0,
+            ..Default::default()
+        };
+
+        let (pixmap, size) = Engine::new(EngineOptions::default())
+            .rasterize(&mut scene, &options)
+            .unwrap();
+
+        // the scene size stays in scene units, while the pixmap is scaled
+        assert_eq!((size.width, size.height), (20.0, 10.0));
+        assert_eq!((pixmap.width(), pixmap.height()), (40, 20));
+        // the translation is applied in scene units
+        assert!(!is_filled(&pixmap, 9, 0));
+        assert!(is_filled(&pixmap, 10, 0));
+        assert!(is_filled(&pixmap, 39, 19));
+    }
+
+    #[test]
+    fn rasterizes_into_target_at_offset() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut target = tiny_skia::Pixmap::new(40, 20).unwrap();
+        target.fill(tiny_skia::Color::WHITE);
+
+        let mut render = |target: &mut tiny_skia::Pixmap, clear, background| {
+            let mut scene = Scene::new(Block::new().size((10, 10)).finish());
+            let root = scene.root_id();
+            scene.append_child(
+                root,
+                Block::new()
+                    .size((5, 5))
+                    .background(Color::rgb(255, 0, 0))
+                    .finish(),
+            );
+
+            engine
+                .rasterize_into(
+                    &mut target.as_mut(),
+                    &mut scene,
+                    (20, 5),
+                    clear,
+                    &RasterizeOptions {
+                        background,
+                        ..Default::default()
+                    },
+                )
+                .unwrap();
+        };
+        let color = |target: &tiny_skia::Pixmap, x, y| {
+            let pixel = target.pixel(x, y).unwrap();
+            (pixel.red(), pixel.green(), pixel.blue(), pixel.alpha())
+        };
+
+        // composited over the existing contents
+        render(&mut target, false, None);
+        assert_eq!(color(&target, 0, 0), (255, 255, 255, 255));
+        assert_eq!(color(&target, 22, 7), (255, 0, 0, 255));
+        assert_eq!(color(&target, 27, 12), (255, 255, 255, 255));
+
+        // the background only fills the bounds of the scene
+        render(&mut target, true, Some(Color::rgb(0, 0, 255)));
+        assert_eq!(color(&target, 0, 0), (0, 0, 0, 0));
+        assert_eq!(color(&target, 22, 7), (255, 0, 0, 255));
+        assert_eq!(color(&target, 27, 12), (0, 0, 255, 255));
+        assert_eq!(color(&target, 31, 12), (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn anchors_absolute_nodes_with_insets() {
+        let pixmap = rasterize(
+            Block::new()
+                .size((10, 10))
+                .position(Position::Absolute)
+                .right(0)
+                .bottom(0)
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+
+        assert!(is_filled(&pixmap, 45, 25));
+        assert!(is_filled(&pixmap, 40, 20));
+        assert!(!is_filled(&pixmap, 39, 19));
+        assert!(!is_filled(&pixmap, 5, 5));
+    }
+
+    #[test]
+    fn resolves_percentage_insets() {
+        let pixmap = rasterize(
+            Block::new()
+                .position(Position::Absolute)
+                .inset_x(pct(10.0))
+                .inset_y((pct(0.0), pct(50.0)))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+
+        assert!(is_filled(&pixmap, 5, 0));
+        assert!(is_filled(&pixmap, 44, 14));
+        assert!(!is_filled(&pixmap, 4, 0));
+        assert!(!is_filled(&pixmap, 45, 0));
+        assert!(!is_filled(&pixmap, 5, 15));
+    }
+
+    #[test]
+    fn resolves_calc_sizes_and_paddings() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Column::new().size((100, 40)).finish());
+        let root = scene.root_id();
+        let container = scene.append_child(
+            root,
+            Column::new()
+                .size(pct(100.0))
+                .padding(calc(pct(10.0)))
+                .finish(),
+        );
+        scene.append_child(
+            container,
+            Block::new()
+                .size((calc(pct(100.0)) - 20, Calc::min(pct(50.0), 10)))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+
+        let (pixmap, _) = engine
+            .rasterize(&mut scene, &RasterizeOptions::default())
+            .unwrap();
+
+        assert!(is_filled(&pixmap, 10, 10));
+        assert!(is_filled(&pixmap, 69, 19));
+        assert!(!is_filled(&pixmap, 70, 10));
+        assert!(!is_filled(&pixmap, 10, 20));
+        assert!(!is_filled(&pixmap, 9, 10));
+    }
+
+    #[test]
+    fn resolves_calc_insets_margins_and_gaps() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Row::new().size((100, 40)).gap(calc(pct(10.0)) + 5).finish());
+        let root = scene.root_id();
+
+        for _ in 0..2 {
+            scene.append_child(
+                root,
+                Block::new()
+                    .size((10, 10))
+                    .margin_top(Calc::max(pct(10.0), 5))
+                    .background(Color::rgb(255, 0, 0))
+                    .finish(),
+            );
+        }
+
+        scene.append_child(
+            root,
+            Block::new()
+                .size((10, 10))
+                .position(Position::Absolute)
+                .left(calc(pct(50.0)) - 5)
+                .bottom(calc(0))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+
+        let (pixmap, _) = engine
+            .rasterize(&mut scene, &RasterizeOptions::default())
+            .unwrap();
+
+        // the second block follows the first one after a 15 units gap
+        assert!(is_filled(&pixmap, 0, 10));
+        assert!(!is_filled(&pixmap, 0, 9));
+        assert!(!is_filled(&pixmap, 10, 10));
+        assert!(!is_filled(&pixmap, 24, 10));
+        assert!(is_filled(&pixmap, 25, 10));
+        // the absolute block is centered at the bottom
+        assert!(is_filled(&pixmap, 45, 30));
+        assert!(is_filled(&pixmap, 54, 39));
+        assert!(!is_filled(&pixmap, 44, 39));
+        assert!(!is_filled(&pixmap, 55, 39));
+    }
+
+    fn rasterize_in_viewport(
+        root: Column,
+        child: Node,
+        viewport: Option<Size<f32>>,
+    ) -> tiny_skia::Pixmap {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(root.finish());
+        let root = scene.root_id();
+        scene.append_child(root, child);
+
+        let options = RasterizeOptions {
+            vectorize_options: VectorizeOptions {
+                layout: LayoutOptions {
+                    viewport,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        engine.rasterize(&mut scene, &options).unwrap().0
+    }
+
+    #[test]
+    fn resolves_viewport_units_against_the_root() {
+        let block = || {
+            Block::new()
+                .size((vw(25.0), vh(10.0)))
+                .margin_left(vmin(10.0))
+                .background(Color::rgb(255, 0, 0))
+                .finish()
+        };
+
+        let pixmap = rasterize_in_viewport(Column::new().size((200, 100)), block(), None);
+        assert!(!is_filled(&pixmap, 9, 0));
+        assert!(is_filled(&pixmap, 10, 0));
+        assert!(is_filled(&pixmap, 59, 9));
+        assert!(!is_filled(&pixmap, 60, 0));
+        assert!(!is_filled(&pixmap, 10, 10));
+
+        // an explicit viewport takes precedence over the size of the root
+        let pixmap = rasterize_in_viewport(
+            Column::new().size((200, 100)),
+            block(),
+            Some(Size::from_values(400.0, 50.0)),
+        );
+        assert!(!is_filled(&pixmap, 4, 0));
+        assert!(is_filled(&pixmap, 5, 0));
+        assert!(is_filled(&pixmap, 104, 4));
+        assert!(!is_filled(&pixmap, 105, 0));
+        assert!(!is_filled(&pixmap, 5, 5));
+    }
+
+    #[test]
+    fn resolves_viewport_units_in_automatically_sized_parents() {
+        let mut scene = Scene::new(Column::new().width(200).finish());
+        let root = scene.root_id();
+        let row = scene.append_child(root, Row::new().padding_top(vw(5.0)).finish());
+        scene.append_child(
+            row,
+            Block::new()
+                .size((calc(vw(10.0)), vmin(20.0) + 20))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+
+        let (pixmap, size) = Engine::new(EngineOptions::default())
+            .rasterize(&mut scene, &RasterizeOptions::default())
+            .unwrap();
+
+        // the height of the root is automatic, so `vmin` resolves to zero
+        assert_eq!(size.height, 30.0);
+        assert!(!is_filled(&pixmap, 0, 9));
+        assert!(is_filled(&pixmap, 0, 10));
+        assert!(is_filled(&pixmap, 19, 29));
+        assert!(!is_filled(&pixmap, 20, 10));
+    }
+
+    #[test]
+    fn resolves_viewport_font_sizes() {
+        let root = || Column::new().size((200, 50));
+        let expected = rasterize_in_viewport(
+            root(),
+            Text::new("Viewport")
+                .font_size(20.0)
+                .line_height(20.0)
+                .letter_spacing(0.1)
+                .finish(),
+            None,
+        );
+        assert!(expected.pixels().iter().any(|x| x.alpha() > 0));
+
+        let pixmap = rasterize_in_viewport(
+            root(),
+            Text::new("Viewport")
+                .font_size(vw(10.0))
+                .line_height(vh(40.0))
+                .letter_spacing(vw(1.0))
+                .finish(),
+            None,
+        );
+        assert!(pixmap.data() == expected.data());
+
+        let pixmap = rasterize_in_viewport(
+            root(),
+            Text::new("Viewport")
+                .font_size(vmax(10.0))
+                .line_height(vmin(40.0))
+                .letter_spacing(vw(4.0))
+                .finish(),
+            Some(Size::from_values(50.0, 200.0)),
+        );
+        assert!(pixmap.data() == expected.data());
+
+        let width = |text: Text| {
+            let mut scene = Scene::new(Row::new().size((200, 50)).finish());
+            let text = scene.append_child(scene.root_id(), text.finish());
+            Engine::new(EngineOptions::default()).layout(
+                &mut scene,
+                &LayoutOptions::default(),
+                &ImageOptions::default(),
+            );
+            scene.layout(text).unwrap().relative.border.width
+        };
+        let text = || Text::new("Viewport").font_size(vw(10.0)).line_height(20.0);
+
+        // letter spacing is added to the advance of each of the 8 glyphs, bare
+        // numbers being relative to the font size
+        assert_eq!(width(text().letter_spacing(vw(1.0))) - width(text()), 16.0);
+        assert_eq!(width(text().letter_spacing(0.1)) - width(text()), 16.0);
+        assert_eq!(
+            width(text().letter_spacing(units(2.0))) - width(text()),
+            16.0
+        );
+    }
+
+    fn rasterize_with_available_space(
+        mut scene: Scene,
+        available_space: Size<AvailableSpace>,
+    ) -> (tiny_skia::Pixmap, Size<f32>) {
+        let mut engine = Engine::new(EngineOptions::default());
+        let options = RasterizeOptions {
+            vectorize_options: VectorizeOptions {
+                layout: LayoutOptions {
+                    available_space,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        engine.rasterize(&mut scene, &options).unwrap()
+    }
+
+    #[test]
+    fn wraps_root_text_to_the_available_width() {
+        let scene = || {
+            Scene::new(
+                Text::new("The quick brown fox jumps over the lazy dog")
+                    .font_size(16.0)
+                    .line_height(20.0)
+                    .finish(),
+            )
+        };
+
+        let (_, size) = rasterize_with_available_space(scene(), Size::default());
+        assert!(size.width > 100.0);
+        assert_eq!(size.height, 20.0);
+
+        let (_, wrapped) = rasterize_with_available_space(
+            scene(),
+            Size::from_values(AvailableSpace::Definite(100.0), AvailableSpace::MaxContent),
+        );
+        assert!(wrapped.width <= 100.0);
+        assert!(wrapped.height > 20.0);
+
+        let (_, size) = rasterize_with_available_space(
+            scene(),
+            Size::from_values(AvailableSpace::MinContent, AvailableSpace::MaxContent),
+        );
+        assert!(size.width < wrapped.width);
+        assert!(size.height > wrapped.height);
+    }
+
+    #[test]
+    fn fills_the_definite_available_space() {
+        let mut scene = Scene::new(Column::new().width(pct(100.0)).padding(pct(5.0)).finish());
+        let root = scene.root_id();
+        scene.append_child(
+            root,
+            Block::new()
+                .size((calc(pct(50.0)), calc(vw(5.0))))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+
+        let (pixmap, size) = rasterize_with_available_space(
+            scene,
+            Size::from_values(AvailableSpace::Definite(200.0), AvailableSpace::MaxContent),
+        );
+
+        assert_eq!((size.width, size.height), (200.0, 30.0));
+        assert!(!is_filled(&pixmap, 9, 10));
+        assert!(is_filled(&pixmap, 10, 10));
+        assert!(is_filled(&pixmap, 99, 19));
+        assert!(!is_filled(&pixmap, 100, 10));
+        assert!(!is_filled(&pixmap, 10, 20));
+    }
+
+    #[test]
+    fn reports_absolute_and_relative_node_layouts() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Column::new().padding(10).finish());
+        let root = scene.root_id();
+        scene.append_child(root, Block::new().size((20, 5)).finish());
+        let row = scene.append_child(root, Row::new().margin_x(4).padding(2).finish());
+        let block = scene.append_child(
+            row,
+            Block::new()
+                .size((30, 20))
+                .border_width(1)
+                .padding(3)
+                .finish(),
+        );
+
+        engine.layout(
+            &mut scene,
+            &LayoutOptions::default(),
+            &ImageOptions::default(),
+        );
+        let layout = scene.layout(block).unwrap();
+
+        assert_eq!(
+            layout.relative.border,
+            LayoutRect::from_values(2.0, 2.0, 30.0, 20.0)
+        );
+        assert_eq!(
+            layout.absolute.border,
+            LayoutRect::from_values(16.0, 17.0, 30.0, 20.0)
+        );
+        assert_eq!(
+            layout.absolute.padding,
+            LayoutRect::from_values(17.0, 18.0, 28.0, 18.0)
+        );
+        assert_eq!(
+            layout.absolute.content,
+            LayoutRect::from_values(20.0, 21.0, 22.0, 12.0)
+        );
+        assert_eq!(
+            scene.layout(root).unwrap().absolute.border,
+            LayoutRect::from_values(0.0, 0.0, 62.0, 49.0)
+        );
+        assert!(scene.layout(NodeId::from(scene.node_count())).is_none());
+    }
+
+    #[test]
+    fn lays_out_images_with_the_image_options() {
+        let image_options = ImageOptions {
+            href_string_resolver: Some(std::sync::Arc::new(|_, options| {
+                usvg::Tree::from_str(
+                    r#"<svg xmlns="http://www.w3.org/2000/svg" width="30" height="20"/>"#,
+                    options,
+                )
+                .ok()
+                .map(usvg::ImageKind::SVG)
+            })),
+            ..Default::default()
+        };
+
+        let mut scene = Scene::new(Row::new().finish());
+        let image = scene.append_child(scene.root_id(), Image::auto("memory://logo").finish());
+        Engine::new(EngineOptions::default()).layout(
+            &mut scene,
+            &LayoutOptions::default(),
+            &image_options,
+        );
+
+        assert_eq!(
+            scene.layout(image).unwrap().absolute.border,
+            LayoutRect::from_values(0.0, 0.0, 30.0, 20.0)
+        );
+    }
+}
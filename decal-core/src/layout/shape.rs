@@ -0,0 +1,360 @@
+use crate::{
+    layout::RenderContext,
+    paint::{
+        write_fill_path,
+        Appearance,
+        ScaledRadii,
+    },
+    primitives::{
+        PathData,
+        Point,
+        PreserveAspectRatio,
+        ViewBox,
+    },
+    utils::{
+        IsDefault,
+        PathSink,
+    },
+};
+use std::fmt::Write;
+use taffy::Size;
+
+/// The geometry of a shape node.
+#[derive(Debug, Clone)]
+pub(crate) enum Shape {
+    /// Rectangle filling the layout box, rounded by the corner radius of the
+    /// node.
+    Rectangle,
+    /// Circle inscribed in the layout box.
+    Circle,
+    /// Ellipse inscribed in the layout box.
+    Ellipse,
+    /// Straight line between two points.
+    Line(Point<f32>, Point<f32>),
+    /// Closed polygon through the points.
+    Polygon(Vec<Point<f32>>),
+    /// Open polyline through the points.
+    Polyline(Vec<Point<f32>>),
+    /// Path data fitted into the layout box through its view box.
+    Path {
+        data: PathData,
+        view_box: ViewBox,
+        preserve_aspect_ratio: PreserveAspectRatio,
+    },
+}
+
+//
+
+#[derive(Debug, Clone)]
+pub(crate) struct ShapeMeta {
+    pub(crate) shape: Shape,
+    pub(crate) width: f32,
+    pub(crate) height: f32,
+}
+
+impl ShapeMeta {
+    /// Creates a new [`ShapeMeta`] instance.
+    ///
+    /// # Arguments
+    /// - `shape`: The [`Shape`] geometry.
+    /// - `width`: The intrinsic width of the shape.
+    /// - `height`: The intrinsic height of the shape.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new(shape: Shape, width: f32, height: f32) -> Self {
+        Self {
+            shape,
+            width,
+            height,
+        }
+    }
+
+    /// Creates a [`ShapeMeta`] for a sequence of points, using the bounding
+    /// box of the points (anchored at the origin) as the intrinsic size.
+    ///
+    /// # Arguments
+    /// - `points`: The points of the shape.
+    /// - `shape_fn`: The closure wrapping the points into a [`Shape`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn from_points<F>(points: Vec<Point<f32>>, shape_fn: F) -> Self
+    where
+        F: FnOnce(Vec<Point<f32>>) -> Shape,
+    {
+        let (width, height) = points
+            .iter()
+            .fold((0.0f32, 0.0f32), |(w, h), p| (w.max(p.x), h.max(p.y)));
+        Self::new(shape_fn(points), width, height)
+    }
+
+    /// Returns `true` if the shape may overflow its layout box and must be
+    /// clipped.
+    pub(crate) fn needs_clip(&self) -> bool {
+        matches!(
+            self.shape,
+            Shape::Path {
+                preserve_aspect_ratio,
+                ..
+            } if preserve_aspect_ratio.is_slice()
+        )
+    }
+
+    /// Returns `true` if the shape has an interior that can be filled.
+    pub(crate) fn has_interior(&self) -> bool {
+        // lines and polylines have no interior
+        !matches!(self.shape, Shape::Line(..) | Shape::Polyline(_))
+    }
+
+    /// Computes the final size of the shape.
+    ///
+    /// # Note
+    /// Aspect ratio is preserved when only a single dimension is constrained.
+    ///
+    /// # Arguments
+    /// - `known_dimensions`: The known layout dimensions provided by the
+    ///   `taffy`.
+    ///
+    /// # Returns
+    /// - The resolved shape size.
+    pub(crate) fn measure(&self, known_dimensions: Size<Option<f32>>) -> Size<f32> {
+        match (known_dimensions.width, known_dimensions.height) {
+            (Some(width), Some(height)) => Size { width, height },
+            (Some(width), None) => Size {
+                width,
+                height: (width / self.width.max(1.0)) * self.height,
+            },
+            (None, Some(height)) => Size {
+                width: (height / self.height.max(1.0)) * self.width,
+                height,
+            },
+            (None, None) => Size {
+                width: self.width,
+                height: self.height,
+            },
+        }
+    }
+
+    /// Writes the path data of the shape scaled into the layout box.
+    ///
+    /// # Arguments
+    /// - `out`: The output sink for the generated path commands.
+    /// - `(w, h)`: The size of the layout box.
+    /// - `radii`: The [`ScaledRadii`] applied to rectangles.
+    pub(crate) fn write_path<T>(
+        &self,
+        out: &mut T,
+        (w, h): (f32, f32),
+        radii: ScaledRadii,
+    ) -> std::fmt::Result
+    where
+        T: PathSink,
+    {
+        let scale_x = if self.width > 0.0 {
+            w / self.width
+        } else {
+            1.0
+        };
+        let scale_y = if self.height > 0.0 {
+            h / self.height
+        } else {
+            1.0
+        };
+        let scale = |p: &Point<f32>| (p.x * scale_x, p.y * scale_y);
+
+        match &self.shape {
+            Shape::Rectangle => write_fill_path(out, w, h, radii),
+            Shape::Circle => {
+                let r = w.min(h) / 2.0;
+                write_ellipse(out, (w / 2.0, h / 2.0), (r, r))
+            }
+            Shape::Ellipse => write_ellipse(out, (w / 2.0, h / 2.0), (w / 2.0, h / 2.0)),
+            Shape::Line(from, to) => {
+                let (x1, y1) = scale(from);
+                let (x2, y2) = scale(to);
+                out.move_to(x1, y1)?.line_to(x2, y2).map(|_| ())
+            }
+            Shape::Polygon(points) | Shape::Polyline(points) => {
+                for (idx, point) in points.iter().enumerate() {
+                    let (x, y) = scale(point);
+
+                    if idx == 0 {
+                        out.move_to(x, y)?;
+                    } else {
+                        out.line_to(x, y)?;
+                    }
+                }
+
+                if matches!(self.shape, Shape::Polygon(_)) && !points.is_empty() {
+                    out.close()?;
+                }
+
+                Ok(())
+            }
+            Shape::Path {
+                data,
+                view_box,
+                preserve_aspect_ratio,
+            } => data.write_transformed(out, view_box.fit(*preserve_aspect_ratio, w, h)),
+        }
+    }
+
+    /// Renders the fill and stroke of the shape into the output stream.
+    ///
+    /// # Arguments
+    /// - `ctx`: The current [`RenderContext`].
+    /// - `visual`: The [`Appearance`] of the node.
+    /// - `radii`: The [`ScaledRadii`] of the node.
+    /// - `layout`: The computed layout for the shape node.
+    pub(crate) fn render<W>(
+        &self,
+        ctx: &mut RenderContext<W>,
+        visual: &Appearance,
+        radii: ScaledRadii,
+        layout: taffy::Layout,
+    ) -> std::fmt::Result
+    where
+        W: Write,
+    {
+        let size = (layout.size.width, layout.size.height);
+        let fill_rule = visual.fill_rule;
+
+        if self.has_interior() {
+            visual.fill.render(
+                ctx,
+                |out| self.write_path(out, size, radii),
+                |out| self.write_path(out, size, radii),
+                |layer, _| layer.attr_if("fill-rule", (fill_rule,), !fill_rule.is_default()),
+                |x| Ok(x),
+            )?;
+        }
+
+        if visual.stroke.is_none() {
+            return Ok(());
+        }
+
+        visual.stroke.paint.render_stroke(
+            ctx,
+            |out| self.write_path(out, size, radii),
+            |out| self.write_path(out, size, radii),
+            |layer, _| visual.stroke.write_attrs(layer),
+            |x| Ok(x),
+        )
+    }
+}
+
+/// Writes a path describing an ellipse as two half arcs.
+///
+/// # Arguments
+/// - `out`: The output sink for the generated path commands.
+/// - `(cx, cy)`: The center of the ellipse.
+/// - `(rx, ry)`: The radii of the ellipse.
+fn write_ellipse<T>(out: &mut T, (cx, cy): (f32, f32), (rx, ry): (f32, f32)) -> std::fmt::Result
+where
+    T: PathSink,
+{
+    if rx <= 0.0 || ry <= 0.0 {
+        return Ok(());
+    }
+
+    out.move_to(cx - rx, cy)?
+        .arc_to(rx, ry, cx + rx, cy)?
+        .arc_to(rx, ry, cx - rx, cy)?
+        .close()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::str_sink;
+
+    fn path(meta: &ShapeMeta, size: (f32, f32)) -> String {
+        str_sink(|out| meta.write_path(out, size, ScaledRadii::default()))
+    }
+
+    #[test]
+    fn measures_intrinsic_size() {
+        let meta = ShapeMeta::new(Shape::Ellipse, 20.0, 10.0);
+
+        assert_eq!(
+            meta.measure(Size {
+                width: None,
+                height: None,
+            }),
+            Size {
+                width: 20.0,
+                height: 10.0,
+            }
+        );
+        assert_eq!(
+            meta.measure(Size {
+                width: Some(40.0),
+                height: None,
+            }),
+            Size {
+                width: 40.0,
+                height: 20.0,
+            }
+        );
+    }
+
+    #[test]
+    fn uses_bounding_box_of_points() {
+        let meta = ShapeMeta::from_points(
+            vec![Point::from((0.0, 10.0)), Point::from((20.0, 5.0))],
+            Shape::Polyline,
+        );
+
+        assert_eq!((meta.width, meta.height), (20.0, 10.0));
+    }
+
+    #[test]
+    fn writes_circle_path() {
+        let meta = ShapeMeta::new(Shape::Circle, 10.0, 10.0);
+        assert_eq!(
+            path(&meta, (20.0, 10.0)),
+            "M5 5 A5 5 0 0 1 15 5 A5 5 0 0 1 5 5 Z"
+        );
+    }
+
+    #[test]
+    fn scales_polygon_points() {
+        let meta = ShapeMeta::from_points(
+            vec![
+                Point::from((0.0, 0.0)),
+                Point::from((10.0, 0.0)),
+                Point::from((5.0, 10.0)),
+            ],
+            Shape::Polygon,
+        );
+
+        assert_eq!(path(&meta, (20.0, 20.0)), "M0 0 L20 0 L10 20 Z");
+    }
+
+    #[test]
+    fn fits_path_into_layout_box() {
+        let meta = ShapeMeta::new(
+            Shape::Path {
+                data: PathData::parse("M10 0 L20 10").unwrap(),
+                view_box: ViewBox::new(10.0, 0.0, 10.0, 10.0),
+                preserve_aspect_ratio: PreserveAspectRatio::default(),
+            },
+            10.0,
+            10.0,
+        );
+
+        assert_eq!(path(&meta, (40.0, 20.0)), "M10 0 L30 20 ");
+    }
+
+    #[test]
+    fn writes_line_path() {
+        let meta = ShapeMeta::new(
+            Shape::Line(Point::from((0.0, 0.0)), Point::from((10.0, 0.0))),
+            10.0,
+            0.0,
+        );
+
+        assert_eq!(path(&meta, (30.0, 0.0)), "M0 0 L30 0 ");
+    }
+}
@@ -1,11 +1,13 @@
 use crate::{
     builders::TextSpan,
     layout::{
+        EmbeddedFonts,
         FontRegistry,
         RenderContext,
         Stencil,
         StencilScope,
         StencilType,
+        TextMode,
         Typography,
         BASE_FONT_SIZE,
         BASE_LINE_HEIGHT,
@@ -14,20 +16,34 @@ use crate::{
     paint::{
         write_fill_path,
         Iri,
+        IriNamespace,
+        NamespacedDisplay,
         ResourceIri,
+        Resources,
         ScaledRadii,
     },
     primitives::{
         Color,
         FontStyle,
+        FontVariation,
         FontWeight,
+        LayoutLength,
         Mask,
+        Paint,
+        PaintInner,
         PaintStack,
+        Path,
+        Size,
+        TextDecorationLine,
+        TextDecorationStyle,
     },
     utils::{
         encode_image,
+        write_spaced,
         ElementWriter,
-        PathWriter,
+        FloatWriter,
+        PathSink,
+        RelativePathWriter,
     },
 };
 use base64::{
@@ -37,24 +53,48 @@ use base64::{
 use cosmic_text::{
     Attrs,
     Buffer,
+    CacheKey,
+    CacheKeyFlags,
     Command,
     Family,
+    FontFeatures,
+    FontSystem,
+    LayoutGlyph,
+    LayoutRun,
     Metrics,
     Shaping,
+    SwashCache,
+};
+use hashbrown::{
+    HashMap,
+    HashSet,
 };
 use parking_lot::Mutex;
 use png::EncodingError;
+use quick_xml::escape::escape;
 use std::{
+    borrow::Cow,
     fmt::{
         Formatter,
         Write,
     },
     sync::Arc,
 };
-use swash::scale::image::Content;
+use swash::{
+    scale::{
+        image::Content,
+        ScaleContext,
+    },
+    zeno::{
+        Angle,
+        PathData,
+        Transform,
+    },
+    Setting,
+    Tag,
+};
 use taffy::prelude::*;
 use thiserror::Error;
-use zeno::Point;
 
 const DEFAULT_COLOR: Color = Color::rgb(0, 0, 0);
 
@@ -79,6 +119,103 @@ pub(crate) enum GlyphRenderMode {
     Bitmap,
 }
 
+/// The document settings glyphs are rendered with.
+#[derive(Debug, Clone, Copy)]
+struct GlyphOutput<'a> {
+    /// The namespace of the IRIs referenced by the document.
+    namespace: &'a IriNamespace,
+    /// The scale at which color glyph bitmaps are rasterized.
+    bitmap_scale: f32,
+}
+
+/// The outline of a single glyph placed on its baseline.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GlyphOutline<'a> {
+    /// The outline commands relative to the glyph origin.
+    commands: &'a [Command],
+    /// The origin of the glyph on the baseline.
+    origin: (f32, f32),
+}
+
+impl GlyphOutline<'_> {
+    /// Writes the outline with absolute commands.
+    ///
+    /// # Arguments
+    /// - `out`: The [`PathSink`] receiving the outline.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`std::fmt::Error`] if writing fails.
+    pub(crate) fn write_path<T>(&self, out: &mut T) -> std::fmt::Result
+    where
+        T: PathSink,
+    {
+        write_glyph_path(out, self.commands, self.origin)
+    }
+}
+
+/// Writes glyph outlines optimized according to a [`PathOptimization`].
+///
+/// [`PathOptimization`]: crate::layout::PathOptimization
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct GlyphPaths<'a> {
+    /// The number of decimal places kept for path coordinates.
+    precision: u8,
+    /// The resources receiving the outlines of repeated glyphs.
+    resources: &'a Mutex<Resources>,
+}
+
+/// A character of a `<text>` element.
+#[derive(Debug)]
+struct TextChar<'a> {
+    /// The glyph drawing the character.
+    glyph: &'a LayoutGlyph,
+    /// The layer of the embedded font subset mapping the character.
+    layer: usize,
+    ch: char,
+}
+
+/// Adjacent glyph outlines written as a single `<path>` element.
+#[derive(Debug, Default)]
+struct MergedGlyphPath {
+    fill: String,
+    glyphs: Vec<((f32, f32), Vec<Command>)>,
+}
+
+impl MergedGlyphPath {
+    /// Writes the pending glyph outlines, if any, and clears them.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `precision`: The number of decimal places kept for path coordinates.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`std::fmt::Error`] if writing fails.
+    fn flush<W>(&mut self, out: &mut W, precision: u8) -> std::fmt::Result
+    where
+        W: Write,
+    {
+        if self.glyphs.is_empty() {
+            return Ok(());
+        }
+
+        ElementWriter::new(out, "path")?
+            .attr("fill", self.fill.as_str())?
+            .write_attr("d", |out| {
+                let mut d = RelativePathWriter::new(out, precision);
+
+                self.glyphs
+                    .iter()
+                    .try_for_each(|(origin, commands)| write_outline(&mut d, commands, *origin))
+            })?
+            .close()?;
+
+        self.glyphs.clear();
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub(crate) struct TextMeta {
     spans: Vec<TextSpan>,
@@ -134,34 +271,42 @@ impl TextMeta {
         self.stencil.r#type = value;
     }
 
+    /// Discards the shaping buffer, so that the text is shaped again on the
+    /// next measurement.
+    pub(crate) fn clear_buffer(&mut self) {
+        self.buffer = None;
+    }
+
     /// Measures the intrinsic size of the text based on layout constraints.
     ///
     /// # Arguments
     /// - `known_dimensions`: Known size constraints from `taffy`.
     /// - `available_space`: Available layout space from the parent.
     /// - `fonts`: Shared [`FontRegistry`].
+    /// - `viewport`: The size viewport units resolve against.
     ///
     /// # Returns
     /// - The resolved size of the text node.
     pub(crate) fn measure(
         &mut self,
-        known_dimensions: Size<Option<f32>>,
-        available_space: Size<AvailableSpace>,
+        known_dimensions: taffy::Size<Option<f32>>,
+        available_space: taffy::Size<AvailableSpace>,
         fonts: Arc<Mutex<FontRegistry>>,
-    ) -> Size<f32> {
-        if let Size {
+        viewport: Size<f32>,
+    ) -> taffy::Size<f32> {
+        if let taffy::Size {
             width: Some(width),
             height: Some(height),
         } = known_dimensions
         {
-            return Size { width, height };
+            return taffy::Size { width, height };
         }
 
         let mut fonts = fonts.lock();
-        self.init_buffer(&mut fonts);
+        self.init_buffer(&mut fonts, viewport);
 
         let Some(ref mut buffer) = self.buffer else {
-            return Size::zero();
+            return taffy::Size::zero();
         };
 
         let width_constraint = known_dimensions.width.or(match available_space.width {
@@ -180,7 +325,7 @@ impl TextMeta {
             });
         let height = total_lines as f32 * buffer.metrics().line_height;
 
-        Size { width, height }
+        taffy::Size { width, height }
     }
 
     /// Renders the text node.
@@ -200,20 +345,42 @@ impl TextMeta {
     where
         W: Write,
     {
+        let embedded_fonts =
+            matches!(ctx.text_mode, TextMode::Text).then_some(&mut ctx.embedded_fonts);
+        let scene = ctx.scene;
+        let glyph_paths = ctx.path_optimization.map(|x| GlyphPaths {
+            precision: x.precision,
+            resources: &scene.resources,
+        });
+        let output = GlyphOutput {
+            namespace: &ctx.namespace,
+            bitmap_scale: ctx.bitmap_scale,
+        };
+
         if self.stencil.is_none() {
-            self.render_text(ctx.out, &ctx.scene.fonts, GlyphRenderMode::All)
+            self.render_text(
+                ctx.out,
+                output,
+                &ctx.scene.fonts,
+                GlyphRenderMode::All,
+                embedded_fonts,
+                glyph_paths,
+            )
         } else {
-            let Size { width, height } = layout.size;
+            let taffy::Size { width, height } = layout.size;
             let mask = {
                 Mask::build(|out| {
                     self.render_text(
                         out,
+                        output,
                         &ctx.scene.fonts,
                         if matches!(self.stencil.scope, StencilScope::VectorGlyphs) {
                             GlyphRenderMode::Vector
                         } else {
                             GlyphRenderMode::All
                         },
+                        embedded_fonts,
+                        glyph_paths,
                     )
                     .map_err(|_| std::fmt::Error)
                 })?
@@ -225,7 +392,17 @@ impl TextMeta {
 
             // render bitmaps on top
             if matches!(self.stencil.scope, StencilScope::VectorGlyphs) {
-                self.render_text(ctx.out, &ctx.scene.fonts, GlyphRenderMode::Bitmap)?;
+                self.render_text(
+                    ctx.out,
+                    GlyphOutput {
+                        namespace: &ctx.namespace,
+                        bitmap_scale: ctx.bitmap_scale,
+                    },
+                    &ctx.scene.fonts,
+                    GlyphRenderMode::Bitmap,
+                    None,
+                    None,
+                )?;
             }
 
             Ok(())
@@ -239,7 +416,8 @@ impl TextMeta {
     ///
     /// # Arguments
     /// - `fonts`: The mutable [`FontRegistry`] reference.
-    fn init_buffer(&mut self, fonts: &mut FontRegistry) {
+    /// - `viewport`: The size viewport units resolve against.
+    fn init_buffer(&mut self, fonts: &mut FontRegistry, viewport: Size<f32>) {
         if self.buffer.is_some() {
             return;
         }
@@ -252,12 +430,42 @@ impl TextMeta {
             }
 
             span.typography.cascade_from(&self.typography);
-            let (attrs, _) = typography_to_attrs(&mut span.typography, fonts);
-            spans.push((span.content.as_str(), attrs.metadata(idx)));
+            let word_spacing = span.typography.word_spacing.clone();
+            let letter_spacing = span.typography.letter_spacing.clone();
+            let (attrs, metrics) = typography_to_attrs(&mut span.typography, fonts, viewport);
+            let attrs = attrs.metadata(idx);
+            let word_spacing = word_spacing
+                .map(|x| x.resolve(metrics.font_size, viewport))
+                .filter(|x| *x != 0.0);
+            let letter_spacing = letter_spacing
+                .map(|x| x.resolve(metrics.font_size, viewport))
+                .unwrap_or(0.0);
+
+            // Word spacing is emulated by shaping word separators with
+            // additional letter spacing.
+            match word_spacing {
+                Some(word_spacing) => {
+                    let spaced = attrs
+                        .clone()
+                        .letter_spacing(to_em(letter_spacing + word_spacing, metrics.font_size));
+
+                    for (segment, is_separator) in split_word_separators(&span.content) {
+                        spans.push((
+                            segment,
+                            if is_separator {
+                                spaced.clone()
+                            } else {
+                                attrs.clone()
+                            },
+                        ));
+                    }
+                }
+                None => spans.push((span.content.as_str(), attrs)),
+            }
         }
 
         let mut root_tp = self.typography.clone();
-        let (root_attrs, root_metrics) = typography_to_attrs(&mut root_tp, fonts);
+        let (root_attrs, root_metrics) = typography_to_attrs(&mut root_tp, fonts, viewport);
         let mut buf = Buffer::new_empty(root_metrics);
         let mut brw = buf.borrow_with(&mut fonts.system);
 
@@ -283,8 +491,13 @@ impl TextMeta {
     ///
     /// # Arguments
     /// - `out`: The output writer.
+    /// - `output`: The [`GlyphOutput`] settings of the document.
     /// - `font_registry`: Shared [`FontRegistry`].
     /// - `mode`: The [`GlyphRenderMode`] value.
+    /// - `embedded_fonts`: The [`EmbeddedFonts`] collecting the rendered
+    ///   characters. Vector glyphs are emitted as `<text>` elements instead of
+    ///   paths when provided.
+    /// - `glyph_paths`: The [`GlyphPaths`] optimizing vector glyph outlines.
     ///
     /// # Returns
     /// - Empty tuple on success.
@@ -292,8 +505,11 @@ impl TextMeta {
     fn render_text<W>(
         &self,
         out: &mut W,
+        output: GlyphOutput,
         font_registry: &Arc<Mutex<FontRegistry>>,
         mode: GlyphRenderMode,
+        mut embedded_fonts: Option<&mut EmbeddedFonts>,
+        glyph_paths: Option<GlyphPaths>,
     ) -> Result<(), TextVectorizeError>
     where
         W: Write,
@@ -302,6 +518,10 @@ impl TextMeta {
             return Ok(());
         };
 
+        let GlyphOutput {
+            namespace,
+            bitmap_scale,
+        } = output;
         let mut font_registry = font_registry.lock();
         let FontRegistry {
             swash_cache: cache,
@@ -311,76 +531,122 @@ impl TextMeta {
 
         let skip_vector = matches!(mode, GlyphRenderMode::Bitmap);
         let skip_bitmap = matches!(mode, GlyphRenderMode::Vector);
+        let mut scale_context: Option<ScaleContext> = None;
+
+        let glyph_paths = glyph_paths.filter(|_| !skip_vector && embedded_fonts.is_none());
+        let repeated_outlines = match glyph_paths {
+            Some(GlyphPaths { precision, .. }) => {
+                let mut counts = HashMap::<String, usize>::new();
+
+                for run in buffer.layout_runs() {
+                    for glyph in run.glyphs.iter() {
+                        if let Some(outline_commands) =
+                            self.glyph_outline(cache, font_system, &mut scale_context, glyph)
+                        {
+                            *counts
+                                .entry(local_outline_data(&outline_commands, precision)?)
+                                .or_default() += 1;
+                        }
+                    }
+                }
+
+                counts
+                    .into_iter()
+                    .filter_map(|(data, count)| (count > 1).then_some(data))
+                    .collect()
+            }
+            None => HashSet::new(),
+        };
+        let mut merged = MergedGlyphPath::default();
 
         for run in buffer.layout_runs() {
             let line_y = run.line_y;
 
+            let mut text_glyphs = Vec::new();
+
+            if !skip_vector {
+                self.render_decorations(out, namespace, font_system, &run, false)?;
+            }
+
             for glyph in run.glyphs.iter() {
                 let physical = glyph.physical((0.0, 0.0), 1.0);
                 let glyph_x = physical.x as f32;
                 let glyph_y = physical.y as f32;
-                let cache_key = physical.cache_key;
 
-                if let Some(outline_commands) = cache
-                    .get_outline_commands(font_system, cache_key)
-                    .filter(|x| is_drawable(*x))
+                if let Some(outline_commands) =
+                    self.glyph_outline(cache, font_system, &mut scale_context, glyph)
                 {
                     if skip_vector {
                         continue;
                     }
 
+                    if embedded_fonts.is_some() {
+                        text_glyphs.push(glyph);
+                        continue;
+                    }
+
+                    if let Some(GlyphPaths {
+                        precision,
+                        resources,
+                    }) = glyph_paths
+                    {
+                        let fill = self.span_color(glyph.metadata);
+                        let fill_value = fill.display_in(namespace).to_string();
+                        let origin = (glyph_x, line_y + glyph_y);
+
+                        if merged.fill != fill_value {
+                            merged.flush(out, precision)?;
+                        }
+
+                        let data = local_outline_data(&outline_commands, precision)?;
+
+                        if repeated_outlines.contains(&data) {
+                            let path = Path::build(|out| out.write_str(&data))?;
+
+                            ElementWriter::new(out, "use")?
+                                .attr("href", (format_args!("#{}", namespace.iri(path.iri())),))?
+                                .attrs([("x", origin.0), ("y", origin.1)])?
+                                .attr("fill", fill_value.as_str())?
+                                .close()?;
+
+                            resources.lock().get_or_add_resource(path.into());
+                        } else {
+                            merged.fill = fill_value;
+                            merged.glyphs.push((origin, outline_commands.into_owned()));
+
+                            // bounding box relative paints must be applied
+                            // to each glyph
+                            if !matches!(fill.0, PaintInner::Color(_)) {
+                                merged.flush(out, precision)?;
+                            }
+                        }
+
+                        continue;
+                    }
+
                     ElementWriter::new(out, "path")?
                         .attr(
                             "fill",
-                            (self
-                                .spans
-                                .get(glyph.metadata)
-                                .and_then(|span| span.typography.color.clone())
-                                .unwrap_or(DEFAULT_COLOR.into()),),
+                            (self.span_color(glyph.metadata).display_in(namespace),),
                         )?
                         .write_attr("d", |out| {
-                            let mut d = PathWriter::new(out);
-
-                            for command in outline_commands.iter() {
-                                match *command {
-                                    Command::MoveTo(Point { x, y }) => {
-                                        d.move_to(x + glyph_x, line_y + glyph_y - y)?;
-                                    }
-                                    Command::LineTo(Point { x, y }) => {
-                                        d.line_to(x + glyph_x, line_y + glyph_y - y)?;
-                                    }
-                                    Command::CurveTo(
-                                        Point { x: x1, y: y1 },
-                                        Point { x: x2, y: y2 },
-                                        Point { x, y },
-                                    ) => {
-                                        d.curve_to(
-                                            x1 + glyph_x,
-                                            line_y + glyph_y - y1,
-                                            x2 + glyph_x,
-                                            line_y + glyph_y - y2,
-                                            x + glyph_x,
-                                            line_y + glyph_y - y,
-                                        )?;
-                                    }
-                                    Command::QuadTo(Point { x: cx, y: cy }, Point { x, y }) => {
-                                        d.quad_to(
-                                            cx + glyph_x,
-                                            line_y + glyph_y - cy,
-                                            x + glyph_x,
-                                            line_y + glyph_y - y,
-                                        )?;
-                                    }
-                                    Command::Close => d.close()?,
-                                }
-                            }
-
-                            Ok(())
+                            write_glyph_path(out, &outline_commands, (glyph_x, line_y + glyph_y))
                         })?
                         .close()?;
-                } else if let Some(image) = cache.get_image(font_system, cache_key) {
+                } else if let Some((bitmap, image)) = {
+                    // rasterize bitmaps at the output scale
+                    let bitmap = glyph.physical((0.0, 0.0), bitmap_scale);
+                    cache
+                        .get_image(font_system, bitmap.cache_key)
+                        .as_ref()
+                        .map(|image| (bitmap, image))
+                } {
                     // handle emoji/color glyphs
                     if !skip_bitmap && image.content == Content::Color {
+                        if let Some(GlyphPaths { precision, .. }) = glyph_paths {
+                            merged.flush(out, precision)?;
+                        }
+
                         ElementWriter::new(out, "image")?
                             .attr(
                                 "href",
@@ -390,17 +656,446 @@ impl TextMeta {
                                 ),),
                             )?
                             .attrs([
-                                ("x", glyph_x + image.placement.left as f32),
-                                ("y", line_y + glyph_y - image.placement.top as f32),
-                                ("width", image.placement.width as f32),
-                                ("height", image.placement.height as f32),
+                                ("x", (bitmap.x + image.placement.left) as f32 / bitmap_scale),
+                                (
+                                    "y",
+                                    line_y + (bitmap.y - image.placement.top) as f32 / bitmap_scale,
+                                ),
+                                ("width", image.placement.width as f32 / bitmap_scale),
+                                ("height", image.placement.height as f32 / bitmap_scale),
                             ])?
                             .close()?;
+                    } else if embedded_fonts.is_some() {
+                        text_glyphs.push(glyph);
+                    }
+                } else if embedded_fonts.is_some() {
+                    text_glyphs.push(glyph);
+                }
+            }
+
+            if let Some(embedded_fonts) = embedded_fonts.as_deref_mut().filter(|_| !skip_vector) {
+                self.render_text_run(
+                    out,
+                    namespace,
+                    font_system,
+                    embedded_fonts,
+                    &run,
+                    &text_glyphs,
+                )?;
+            }
+
+            if let Some(GlyphPaths { precision, .. }) = glyph_paths {
+                merged.flush(out, precision)?;
+            }
+
+            if !skip_vector {
+                self.render_decorations(out, namespace, font_system, &run, true)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Renders the glyphs of a single layout run as a `<text>` element.
+    ///
+    /// Every glyph is written as the character it renders, in the logical
+    /// order of the text, and positioned at the location computed during
+    /// shaping. Consecutive characters sharing a span, font and layer of the
+    /// embedded font subset are written into a single `<tspan>`. When a glyph
+    /// cannot be mapped from the characters it renders, the source text of the
+    /// run is kept in an `aria-label`.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `namespace`: The [`IriNamespace`] of the document.
+    /// - `font_system`: The [`FontSystem`] used to look up fonts.
+    /// - `embedded_fonts`: The [`EmbeddedFonts`] collecting the rendered
+    ///   glyphs.
+    /// - `run`: The [`LayoutRun`] containing the glyphs.
+    /// - `glyphs`: The glyphs to render.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`TextVectorizeError`] if writing fails.
+    fn render_text_run<W>(
+        &self,
+        out: &mut W,
+        namespace: &IriNamespace,
+        font_system: &mut FontSystem,
+        embedded_fonts: &mut EmbeddedFonts,
+        run: &LayoutRun,
+        glyphs: &[&LayoutGlyph],
+    ) -> Result<(), TextVectorizeError>
+    where
+        W: Write,
+    {
+        if glyphs.is_empty() {
+            return Ok(());
+        }
+
+        let mut chars = Vec::with_capacity(glyphs.len());
+        let mut is_labelled = false;
+
+        for cluster in glyphs.chunk_by(|a, b| a.start == b.start) {
+            let mut cluster = cluster.to_vec();
+
+            // glyphs of right-to-left text are in visual order
+            if cluster[0].level.is_rtl() {
+                cluster.reverse();
+            }
+
+            let mut text = run.text[cluster[0].start..cluster[0].end].chars();
+            let mut last = None;
+
+            for glyph in cluster {
+                let Some(face) =
+                    embedded_fonts.face(namespace, font_system, glyph.font_id, glyph.font_weight)
+                else {
+                    continue;
+                };
+
+                let (ch, layer) = match text.next() {
+                    Some(ch) => (ch, face.map_glyph(ch, glyph.glyph_id)),
+                    None => {
+                        is_labelled = true;
+                        (face.map_private_use(glyph.glyph_id), 0)
                     }
+                };
+
+                chars.push(TextChar { glyph, layer, ch });
+                last = Some(glyph);
+            }
+
+            // the remaining characters are drawn by the last glyph, such as the
+            // characters of a ligature
+            for ch in text {
+                let layer = last.and_then(|glyph| {
+                    embedded_fonts
+                        .face(namespace, font_system, glyph.font_id, glyph.font_weight)?
+                        .map_blank(ch)
+                        .map(|layer| (glyph, layer))
+                });
+
+                match layer {
+                    Some((glyph, layer)) => chars.push(TextChar { glyph, layer, ch }),
+                    None => is_labelled = true,
                 }
             }
         }
 
+        chars.sort_by_key(|x| x.glyph.start);
+
+        let label = is_labelled.then(|| {
+            let start = run.glyphs.iter().map(|x| x.start).min().unwrap_or_default();
+            let end = run.glyphs.iter().map(|x| x.end).max().unwrap_or_default();
+            escape(&run.text[start..end])
+        });
+
+        // every character is placed by its left edge, so the anchoring must
+        // not follow a direction inherited from an embedding document
+        ElementWriter::new(out, "text")?
+            .attr("xml:space", "preserve")?
+            .attr("direction", "ltr")?
+            .attr("aria-label", label.map(|x| (x,)))?
+            .content(|out| {
+                for chars in chars.chunk_by(|a, b| {
+                    let (a_glyph, b_glyph) = (a.glyph, b.glyph);
+
+                    a.layer == b.layer
+                        && a_glyph.metadata == b_glyph.metadata
+                        && a_glyph.font_id == b_glyph.font_id
+                        && a_glyph.font_weight == b_glyph.font_weight
+                        && a_glyph.font_size == b_glyph.font_size
+                        && a_glyph.cache_key_flags == b_glyph.cache_key_flags
+                }) {
+                    let first = chars[0].glyph;
+                    let Some(face) = embedded_fonts.face(
+                        namespace,
+                        font_system,
+                        first.font_id,
+                        first.font_weight,
+                    ) else {
+                        continue;
+                    };
+
+                    let text = chars.iter().map(|x| x.ch).collect::<String>();
+                    let xs = chars
+                        .iter()
+                        .map(|x| x.glyph.x + x.glyph.font_size * x.glyph.x_offset)
+                        .collect::<Vec<_>>();
+                    let ys = chars
+                        .iter()
+                        .map(|x| run.line_y + x.glyph.y - x.glyph.font_size * x.glyph.y_offset)
+                        .collect::<Vec<_>>();
+
+                    let tspan = ElementWriter::new(out, "tspan")?.write_attr("x", |out| {
+                        write_spaced(out, xs.into_iter(), |out, value| out.write_float(value))
+                    })?;
+                    let tspan = if ys.iter().all(|y| *y == ys[0]) {
+                        tspan.attr("y", ys[0])?
+                    } else {
+                        tspan.write_attr("y", |out| {
+                            write_spaced(out, ys.into_iter(), |out, value| out.write_float(value))
+                        })?
+                    };
+
+                    tspan
+                        .attr("font-family", face.family(chars[0].layer))?
+                        .attr("font-size", first.font_size)?
+                        .attr_if(
+                            "font-style",
+                            "oblique",
+                            first.cache_key_flags.contains(CacheKeyFlags::FAKE_ITALIC),
+                        )?
+                        .attr(
+                            "fill",
+                            (self.span_color(first.metadata).display_in(namespace),),
+                        )?
+                        .content(|out| out.write_str(&escape(text.as_str())))?
+                        .close()?;
+                }
+
+                Ok(())
+            })?
+            .close()?;
+
+        Ok(())
+    }
+
+    /// Returns the outline of a glyph, if it contains drawable geometry.
+    ///
+    /// # Arguments
+    /// - `cache`: The [`SwashCache`] caching glyph outlines.
+    /// - `font_system`: The [`FontSystem`] used to look up fonts.
+    /// - `scale_context`: The [`ScaleContext`] used for glyphs with variable
+    ///   font axis values, created on first use.
+    /// - `glyph`: The glyph to outline.
+    ///
+    /// # Returns
+    /// - The outline commands of the glyph, if drawable.
+    fn glyph_outline<'c>(
+        &self,
+        cache: &'c mut SwashCache,
+        font_system: &mut FontSystem,
+        scale_context: &mut Option<ScaleContext>,
+        glyph: &LayoutGlyph,
+    ) -> Option<Cow<'c, [Command]>> {
+        let cache_key = glyph.physical((0.0, 0.0), 1.0).cache_key;
+        let variations = self.span_variations(glyph.metadata);
+        let outline_commands = if variations.iter().all(FontVariation::is_weight) {
+            cache
+                .get_outline_commands(font_system, cache_key)
+                .map(Cow::Borrowed)
+        } else {
+            scale_outline_commands(
+                font_system,
+                scale_context.get_or_insert_with(ScaleContext::new),
+                cache_key,
+                variations,
+            )
+            .map(Cow::Owned)
+        };
+
+        outline_commands.filter(|x| is_drawable(x))
+    }
+
+    /// Returns the text color of the span with the given index.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the span.
+    fn span_color(&self, index: usize) -> Paint {
+        self.spans
+            .get(index)
+            .and_then(|span| span.typography.color.clone())
+            .unwrap_or(DEFAULT_COLOR.into())
+    }
+
+    /// Returns the variable font axis values of the span with the given index.
+    ///
+    /// # Arguments
+    /// - `index`: The index of the span.
+    fn span_variations(&self, index: usize) -> &[FontVariation] {
+        self.spans
+            .get(index)
+            .and_then(|span| span.typography.variations.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` if the text is drawn with solid color glyph outlines
+    /// only, without a stencil, decorations or color bitmap glyphs.
+    ///
+    /// # Arguments
+    /// - `fonts`: The mutable [`FontRegistry`] reference.
+    /// - `bitmap_scale`: The scale at which color glyph bitmaps are rasterized.
+    pub(crate) fn has_solid_outlines(&self, fonts: &mut FontRegistry, bitmap_scale: f32) -> bool {
+        let solid_spans = self.spans.iter().enumerate().all(|(index, span)| {
+            !matches!(&span.typography.decoration, Some(x) if !x.is_none())
+                && matches!(self.span_color(index).0, PaintInner::Color(_))
+        });
+
+        if !solid_spans || !self.stencil.is_none() {
+            return false;
+        }
+
+        let Some(ref buffer) = self.buffer else {
+            return true;
+        };
+
+        let FontRegistry {
+            swash_cache: cache,
+            system: font_system,
+            ..
+        } = fonts;
+        let mut scale_context: Option<ScaleContext> = None;
+
+        buffer.layout_runs().all(|run| {
+            run.glyphs.iter().all(|glyph| {
+                self.glyph_outline(cache, font_system, &mut scale_context, glyph)
+                    .is_some()
+                    || cache
+                        .get_image(
+                            font_system,
+                            glyph.physical((0.0, 0.0), bitmap_scale).cache_key,
+                        )
+                        .as_ref()
+                        .is_none_or(|image| image.content != Content::Color)
+            })
+        })
+    }
+
+    /// Calls `fill` with every solid color glyph outline and its color, in
+    /// the order the glyphs are rendered.
+    ///
+    /// # Arguments
+    /// - `fonts`: The mutable [`FontRegistry`] reference.
+    /// - `fill`: The closure drawing a single [`GlyphOutline`].
+    pub(crate) fn for_each_outline<F>(&self, fonts: &mut FontRegistry, mut fill: F)
+    where
+        F: FnMut(GlyphOutline<'_>, Color),
+    {
+        let Some(ref buffer) = self.buffer else {
+            return;
+        };
+
+        let FontRegistry {
+            swash_cache: cache,
+            system: font_system,
+            ..
+        } = fonts;
+        let mut scale_context: Option<ScaleContext> = None;
+
+        for run in buffer.layout_runs() {
+            for glyph in run.glyphs.iter() {
+                let PaintInner::Color(color) = self.span_color(glyph.metadata).0 else {
+                    continue;
+                };
+                let Some(outline_commands) =
+                    self.glyph_outline(cache, font_system, &mut scale_context, glyph)
+                else {
+                    continue;
+                };
+
+                let physical = glyph.physical((0.0, 0.0), 1.0);
+                let outline = GlyphOutline {
+                    commands: &outline_commands,
+                    origin: (physical.x as f32, run.line_y + physical.y as f32),
+                };
+
+                fill(outline, color);
+            }
+        }
+    }
+
+    /// Renders the decoration lines of a single layout run.
+    ///
+    /// Consecutive glyphs belonging to the same span share a single decoration
+    /// line, positioned using the metrics of the font of their first glyph.
+    /// Underlines and overlines are drawn below the glyphs, while line-through
+    /// decorations are drawn on top of them.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `namespace`: The [`IriNamespace`] of the document.
+    /// - `font_system`: The [`FontSystem`] used to look up font metrics.
+    /// - `run`: The [`LayoutRun`] to decorate.
+    /// - `line_through`: Whether to render line-through decorations instead of
+    ///   underlines and overlines.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`TextVectorizeError`] if writing fails.
+    fn render_decorations<W>(
+        &self,
+        out: &mut W,
+        namespace: &IriNamespace,
+        font_system: &mut FontSystem,
+        run: &LayoutRun,
+        line_through: bool,
+    ) -> Result<(), TextVectorizeError>
+    where
+        W: Write,
+    {
+        for glyphs in run.glyphs.chunk_by(|a, b| a.metadata == b.metadata) {
+            let first = &glyphs[0];
+            let Some(span) = self.spans.get(first.metadata) else {
+                continue;
+            };
+
+            let Some(decoration) = span
+                .typography
+                .decoration
+                .as_ref()
+                .filter(|x| !x.is_none())
+                .filter(|x| (x.line == TextDecorationLine::LineThrough) == line_through)
+            else {
+                continue;
+            };
+
+            let Some(font) = font_system.get_font(first.font_id, first.font_weight) else {
+                continue;
+            };
+
+            let metrics = font.as_swash().metrics(&[]).scale(first.font_size);
+            let thickness = decoration
+                .thickness
+                .unwrap_or(if metrics.stroke_size > 0.0 {
+                    metrics.stroke_size
+                } else {
+                    first.font_size / 14.0
+                });
+            let top = match decoration.line {
+                TextDecorationLine::Underline => -metrics.underline_offset,
+                TextDecorationLine::Overline => -metrics.ascent,
+                TextDecorationLine::LineThrough if metrics.strikeout_offset != 0.0 => {
+                    -metrics.strikeout_offset
+                }
+                _ => -metrics.ascent / 3.0,
+            };
+            let y = run.line_y + top + thickness / 2.0 + decoration.offset;
+
+            let (start, end) = glyphs.iter().fold((f32::MAX, f32::MIN), |(start, end), x| {
+                (start.min(x.x), end.max(x.x + x.w))
+            });
+
+            let paint = decoration
+                .paint
+                .clone()
+                .unwrap_or_else(|| self.span_color(first.metadata));
+
+            let path = ElementWriter::new(out, "path")?.write_attr("d", |out| {
+                decoration.write_path(out, start, end - start, y, thickness)
+            })?;
+
+            if decoration.style == TextDecorationStyle::Wavy {
+                path.attr("fill", "none")?
+                    .attr("stroke", (paint.display_in(namespace),))?
+                    .attr("stroke-width", thickness)?
+                    .close()?;
+            } else {
+                path.attr("fill", (paint.display_in(namespace),))?.close()?;
+            }
+        }
+
         Ok(())
     }
 
@@ -421,6 +1116,8 @@ impl TextMeta {
     where
         W: Write,
     {
+        let mask_url = format!("url(#{})", ctx.namespace.iri(mask_iri));
+
         self.stencil.paint.render(
             ctx,
             |out| write_fill_path(out, width, height, ScaledRadii::default()),
@@ -429,10 +1126,10 @@ impl TextMeta {
                 if is_use_element {
                     Ok(layer)
                 } else {
-                    layer.attr("mask", (format_args!("url(#{mask_iri})"),))
+                    layer.attr("mask", mask_url.as_str())
                 }
             },
-            |group| group.attr("mask", (format_args!("url(#{mask_iri})"),)),
+            |group| group.attr("mask", mask_url.as_str()),
         )
     }
 }
@@ -473,6 +1170,133 @@ fn is_drawable(cmds: &[Command]) -> bool {
     false
 }
 
+/// Writes a glyph outline with absolute commands.
+///
+/// # Arguments
+/// - `out`: The output writer.
+/// - `cmds`: A slice of glyph outline [`Command`] values.
+/// - `(x, y)`: The origin of the glyph on the baseline.
+///
+/// # Returns
+/// - Empty tuple on success.
+/// - [`std::fmt::Error`] if writing fails.
+fn write_glyph_path<T>(out: &mut T, cmds: &[Command], (x, y): (f32, f32)) -> std::fmt::Result
+where
+    T: PathSink,
+{
+    for cmd in cmds {
+        match *cmd {
+            Command::MoveTo(p) => {
+                out.move_to(x + p.x, y - p.y)?;
+            }
+            Command::LineTo(p) => {
+                out.line_to(x + p.x, y - p.y)?;
+            }
+            Command::CurveTo(c1, c2, p) => {
+                out.curve_to(x + c1.x, y - c1.y, x + c2.x, y - c2.y, x + p.x, y - p.y)?;
+            }
+            Command::QuadTo(c, p) => {
+                out.quad_to(x + c.x, y - c.y, x + p.x, y - p.y)?;
+            }
+            Command::Close => out.close()?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Writes a glyph outline with relative commands.
+///
+/// # Arguments
+/// - `d`: The [`RelativePathWriter`] receiving the outline.
+/// - `cmds`: A slice of glyph outline [`Command`] values.
+/// - `(x, y)`: The origin of the glyph on the baseline.
+///
+/// # Returns
+/// - Empty tuple on success.
+/// - [`std::fmt::Error`] if writing fails.
+fn write_outline<W>(
+    d: &mut RelativePathWriter<W>,
+    cmds: &[Command],
+    (x, y): (f32, f32),
+) -> std::fmt::Result
+where
+    W: Write,
+{
+    for cmd in cmds {
+        match *cmd {
+            Command::MoveTo(p) => {
+                d.move_to(x + p.x, y - p.y)?;
+            }
+            Command::LineTo(p) => {
+                d.line_to(x + p.x, y - p.y)?;
+            }
+            Command::CurveTo(c1, c2, p) => {
+                d.curve_to(x + c1.x, y - c1.y, x + c2.x, y - c2.y, x + p.x, y - p.y)?;
+            }
+            Command::QuadTo(c, p) => {
+                d.quad_to(x + c.x, y - c.y, x + p.x, y - p.y)?;
+            }
+            Command::Close => d.close()?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the path data of a glyph outline relative to its origin, used to
+/// detect and reference repeated outlines.
+///
+/// # Arguments
+/// - `cmds`: A slice of glyph outline [`Command`] values.
+/// - `precision`: The number of decimal places kept for path coordinates.
+///
+/// # Returns
+/// - The path data on success.
+/// - [`std::fmt::Error`] if writing fails.
+fn local_outline_data(cmds: &[Command], precision: u8) -> Result<String, std::fmt::Error> {
+    let mut data = String::new();
+    write_outline(
+        &mut RelativePathWriter::new(&mut data, precision),
+        cmds,
+        (0.0, 0.0),
+    )?;
+    Ok(data)
+}
+
+/// Resolves a typography length, such as the font size, into an absolute
+/// value.
+///
+/// Typography lengths have no percentage basis, so they only accept absolute
+/// and viewport lengths.
+///
+/// # Arguments
+/// - `value`: The optional length.
+/// - `viewport`: The size viewport units resolve against.
+///
+/// # Returns
+/// - The resolved value, or `None` if the length is not set.
+fn resolve_length(value: &Option<LayoutLength<false, false>>, viewport: Size<f32>) -> Option<f32> {
+    value.as_ref().and_then(|x| x.resolve_abs(0.0, viewport))
+}
+
+/// Converts a length in layout units into ems of the font size, the unit
+/// [`cosmic-text`] expects for letter spacing.
+///
+/// # Arguments
+/// - `value`: The length in layout units.
+/// - `font_size`: The font size the length is relative to.
+///
+/// # Returns
+/// - The length in ems, or zero for an empty font size.
+fn to_em(value: f32, font_size: f32) -> f32 {
+    if font_size > 0.0 {
+        value / font_size
+    } else {
+        0.0
+    }
+}
+
 /// Converts a [`Typography`] value into [`cosmic-text`] shaping attributes.
 ///
 /// The resolved font family name is cached into the provided [`Typography`]
@@ -481,6 +1305,7 @@ fn is_drawable(cmds: &[Command]) -> bool {
 /// # Arguments
 /// - `tp`: The mutable reference to [`Typography`] instance.
 /// - `fonts`: The [`FontRegistry`] used for resolving font families.
+/// - `viewport`: The size viewport units resolve against.
 ///
 /// # Returns
 /// - A tuple containing:
@@ -489,10 +1314,11 @@ fn is_drawable(cmds: &[Command]) -> bool {
 fn typography_to_attrs<'a>(
     tp: &'a mut Typography,
     fonts: &mut FontRegistry,
+    viewport: Size<f32>,
 ) -> (Attrs<'a>, Metrics) {
     let metrics = Metrics {
-        font_size: tp.size.unwrap_or(BASE_FONT_SIZE),
-        line_height: tp.line_height.unwrap_or(BASE_LINE_HEIGHT),
+        font_size: resolve_length(&tp.size, viewport).unwrap_or(BASE_FONT_SIZE),
+        line_height: resolve_length(&tp.line_height, viewport).unwrap_or(BASE_LINE_HEIGHT),
     };
 
     let alias = tp.family.clone().unwrap_or(fonts.get_default_family());
@@ -507,15 +1333,606 @@ fn typography_to_attrs<'a>(
         name => Family::Name(name),
     };
 
+    // the `wght` axis takes precedence over the font weight
+    let weight = tp
+        .variations
+        .iter()
+        .flatten()
+        .rfind(|x| x.is_weight())
+        .map(|x| cosmic_text::Weight(x.value.clamp(1.0, 1000.0) as u16))
+        .unwrap_or(tp.weight.unwrap_or(FontWeight::Normal).into());
+
     let mut attrs = Attrs::new()
         .family(family)
         .metrics(metrics)
         .style(tp.style.unwrap_or(FontStyle::Normal).into())
-        .weight(tp.weight.unwrap_or(FontWeight::Normal).into());
+        .weight(weight);
+
+    if let Some(letter_spacing) = &tp.letter_spacing {
+        let letter_spacing = letter_spacing.resolve(metrics.font_size, viewport);
+        attrs = attrs.letter_spacing(to_em(letter_spacing, metrics.font_size));
+    }
+
+    if let Some(features) = tp.features.as_ref().filter(|x| !x.is_empty()) {
+        let mut font_features = FontFeatures::new();
 
-    if let Some(letter_spacing) = tp.letter_spacing {
-        attrs = attrs.letter_spacing(letter_spacing);
+        for feature in features {
+            font_features.set(cosmic_text::FeatureTag::new(&feature.tag), feature.value);
+        }
+
+        attrs = attrs.font_features(font_features);
     }
 
     (attrs, metrics)
 }
+
+/// Returns `true` if the character separates words, as defined by the CSS
+/// `word-spacing` property.
+fn is_word_separator(ch: char) -> bool {
+    matches!(
+        ch,
+        ' ' | '\u{00A0}' | '\u{1361}' | '\u{10100}' | '\u{10101}' | '\u{1039F}' | '\u{1091F}'
+    )
+}
+
+/// Splits the text into consecutive runs of word separators and other
+/// characters.
+///
+/// # Arguments
+/// - `text`: The text to split.
+///
+/// # Returns
+/// - An iterator over the segments, paired with whether the segment consists of
+///   word separators.
+fn split_word_separators(text: &str) -> impl Iterator<Item = (&str, bool)> {
+    let mut rest = text;
+
+    std::iter::from_fn(move || {
+        let first = rest.chars().next()?;
+        let is_separator = is_word_separator(first);
+        let end = rest
+            .char_indices()
+            .find(|(_, ch)| is_word_separator(*ch) != is_separator)
+            .map_or(rest.len(), |(idx, _)| idx);
+        let (segment, tail) = rest.split_at(end);
+        rest = tail;
+
+        Some((segment, is_separator))
+    })
+}
+
+/// Scales the outline of a glyph with additional variable font axis values.
+///
+/// This mirrors the outline generation of [`cosmic_text::SwashCache`], which
+/// only applies the `wght` axis. Glyph advances are computed during shaping
+/// and therefore only reflect the weight axis.
+///
+/// # Arguments
+/// - `font_system`: The [`FontSystem`] used to look up the font.
+/// - `context`: The [`ScaleContext`] used to build the scaler.
+/// - `cache_key`: The [`CacheKey`] of the glyph.
+/// - `variations`: The [`FontVariation`] values to apply.
+///
+/// # Returns
+/// - The outline commands of the glyph, if available.
+fn scale_outline_commands(
+    font_system: &mut FontSystem,
+    context: &mut ScaleContext,
+    cache_key: CacheKey,
+    variations: &[FontVariation],
+) -> Option<Vec<Command>> {
+    let font = font_system.get_font(cache_key.font_id, cache_key.font_weight)?;
+    let axes = font.as_swash().variations();
+
+    let weight = FontVariation::weight(f32::from(cache_key.font_weight.0));
+    let settings = std::iter::once(weight)
+        .chain(variations.iter().filter(|x| !x.is_weight()).copied())
+        .filter_map(|variation| {
+            let axis = axes.find_by_tag(Tag::from_be_bytes(variation.tag))?;
+            let mut setting: Setting<f32> = variation.into();
+            setting.value = setting.value.clamp(axis.min_value(), axis.max_value());
+            Some(setting)
+        })
+        .collect::<Vec<_>>();
+
+    let mut scaler = context
+        .builder(font.as_swash())
+        .size(f32::from_bits(cache_key.font_size_bits))
+        .hint(!cache_key.flags.contains(CacheKeyFlags::DISABLE_HINTING))
+        .variations(settings)
+        .build();
+
+    let mut outline = scaler
+        .scale_outline(cache_key.glyph_id)
+        .or_else(|| scaler.scale_color_outline(cache_key.glyph_id))?;
+
+    if cache_key.flags.contains(CacheKeyFlags::FAKE_ITALIC) {
+        outline.transform(&Transform::skew(
+            Angle::from_degrees(14.0),
+            Angle::from_degrees(0.0),
+        ));
+    }
+
+    let commands = outline.path().commands().collect();
+
+    Some(commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_word_separator,
+        split_word_separators,
+    };
+    use crate::prelude::*;
+    use base64::{
+        engine::general_purpose::STANDARD as BASE64,
+        Engine as _,
+    };
+
+    fn vectorize(text: Node, path_optimization: Option<PathOptimization>) -> String {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Column::new().size((200, 40)).finish());
+        let root = scene.root_id();
+        scene.append_child(root, text);
+
+        let options = VectorizeOptions {
+            path_optimization,
+            ..Default::default()
+        };
+
+        engine.vectorize(&mut scene, &options).unwrap().0
+    }
+
+    fn measure(text: Node) -> f32 {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Column::new().finish());
+        let text = scene.append_child(scene.root_id(), text);
+        engine.layout(
+            &mut scene,
+            &LayoutOptions::default(),
+            &ImageOptions::default(),
+        );
+
+        scene.layout(text).unwrap().relative.border.width
+    }
+
+    #[test]
+    fn splits_word_separators() {
+        assert!(is_word_separator(' '));
+        assert!(is_word_separator('\u{00A0}'));
+        assert!(!is_word_separator('\t'));
+        assert!(!is_word_separator('a'));
+
+        assert_eq!(
+            split_word_separators("ab  c\u{00A0}d").collect::<Vec<_>>(),
+            [
+                ("ab", false),
+                ("  ", true),
+                ("c", false),
+                ("\u{00A0}", true),
+                ("d", false)
+            ]
+        );
+        assert_eq!(split_word_separators("").count(), 0);
+    }
+
+    #[test]
+    fn applies_word_spacing_to_separators_only() {
+        let spaced = |text: &str| {
+            measure(Text::new(text).font_size(20.0).word_spacing(1.0).finish())
+                - measure(Text::new(text).font_size(20.0).finish())
+        };
+
+        assert_eq!(spaced("abc"), 0.0);
+        assert!(spaced("a b") > 0.0);
+        assert_eq!(spaced("a b c"), spaced("a b") * 2.0);
+    }
+
+    #[test]
+    fn applies_font_features_while_shaping() {
+        let ligatures = vectorize(Text::new("ffi fl").font_size(20.0).finish(), None);
+        let no_ligatures = vectorize(
+            Text::new("ffi fl")
+                .font_size(20.0)
+                .font_features([FontFeature::no_ligatures()])
+                .finish(),
+            None,
+        );
+
+        assert_eq!(ligatures.matches("<path").count(), 2);
+        assert_eq!(no_ligatures.matches("<path").count(), 5);
+    }
+
+    fn vectorize_text(engine: &mut Engine, text: Node) -> String {
+        let mut scene = Scene::new(Column::new().finish());
+        scene.append_child(scene.root_id(), text);
+
+        let options = VectorizeOptions {
+            text_mode: TextMode::Text,
+            ..Default::default()
+        };
+
+        engine.vectorize(&mut scene, &options).unwrap().0
+    }
+
+    /// Returns the content of every `<tspan>` element.
+    fn text_content(svg: &str) -> String {
+        svg.split("</tspan>")
+            .filter_map(|x| x.rsplit('>').next())
+            .collect()
+    }
+
+    /// Returns the embedded font subsets.
+    fn embedded_fonts(svg: &str) -> Vec<Vec<u8>> {
+        svg.split("base64,")
+            .skip(1)
+            .filter_map(|x| x.split(')').next())
+            .map(|x| BASE64.decode(x).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn embeds_shaped_glyphs_in_text_mode() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut render = |features: Vec<FontFeature>| {
+            vectorize_text(
+                &mut engine,
+                Text::new("ffi fl")
+                    .font_size(20.0)
+                    .font_features(features)
+                    .finish(),
+            )
+        };
+
+        let ligatures = render(vec![]);
+        assert_eq!(text_content(&ligatures), "ffi fl");
+        assert!(!ligatures.contains("aria-label"));
+
+        // the ligature glyphs, and the blank glyph drawing the remaining `f`,
+        // are mapped from `f` in separate layers
+        let fonts = embedded_fonts(&ligatures);
+        let maps_f = |font: &Vec<u8>| {
+            swash::FontRef::from_index(font, 0)
+                .unwrap()
+                .charmap()
+                .map('f')
+                != 0
+        };
+        assert_eq!(fonts.iter().filter(|x| maps_f(x)).count(), 3);
+
+        let no_ligatures = render(vec![FontFeature::no_ligatures()]);
+        assert!(no_ligatures.contains(">ffi fl</tspan>"));
+        assert_eq!(embedded_fonts(&no_ligatures).len(), 1);
+    }
+
+    #[test]
+    fn names_embedded_fonts_in_the_id_namespace() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut scene = Scene::new(Column::new().finish());
+        scene.append_child(scene.root_id(), Text::new("text").finish());
+
+        let options = VectorizeOptions {
+            text_mode: TextMode::Text,
+            id_prefix: "card".to_string(),
+            ..Default::default()
+        };
+        let svg = engine.vectorize(&mut scene, &options).unwrap().0;
+        let family = svg
+            .split("@font-face{font-family:\"")
+            .nth(1)
+            .and_then(|x| x.split('"').next())
+            .unwrap();
+
+        assert!(family.starts_with("card-"));
+        assert!(svg.contains(&format!(r#"font-family="{family}""#)));
+    }
+
+    #[test]
+    fn text_mode_renders_like_paths() {
+        let svg = |text_mode| {
+            let mut engine = Engine::new(EngineOptions::default());
+            let mut scene = Scene::new(Column::new().size((600, 150)).finish());
+            scene.append_child(
+                scene.root_id(),
+                Text::new("office flow")
+                    .font_size(100.0)
+                    .line_height(120.0)
+                    .finish(),
+            );
+
+            let options = VectorizeOptions {
+                text_mode,
+                ..Default::default()
+            };
+
+            engine.vectorize(&mut scene, &options).unwrap().0
+        };
+
+        let render = |svg: &str| {
+            let mut options = usvg::Options::default();
+
+            // register every subset under the family of its `@font-face` rule
+            for rule in svg.split("@font-face{font-family:\"").skip(1) {
+                let (family, rest) = rule.split_once('"').unwrap();
+                let data = rest.split("base64,").nth(1).unwrap().split(')').next();
+                let source = usvg::fontdb::Source::Binary(std::sync::Arc::new(
+                    BASE64.decode(data.unwrap()).unwrap(),
+                ));
+                let db = options.fontdb_mut();
+
+                for id in db.load_font_source(source) {
+                    let mut face = db.face(id).unwrap().clone();
+                    db.remove_face(id);
+                    face.families = vec![(
+                        family.to_string(),
+                        usvg::fontdb::Language::English_UnitedStates,
+                    )];
+                    db.push_face_info(face);
+                }
+            }
+
+            let tree = usvg::Tree::from_str(svg, &options).unwrap();
+            let mut pixmap = tiny_skia::Pixmap::new(600, 150).unwrap();
+            resvg::render(&tree, Default::default(), &mut pixmap.as_mut());
+            pixmap
+        };
+
+        let expected = render(&svg(TextMode::Paths));
+        let actual = render(&svg(TextMode::Text));
+
+        // glyph paths are placed at whole pixels, so every pixel is compared
+        // with the neighbourhood of the other image
+        let alpha = |pixmap: &tiny_skia::Pixmap, x: i32, y: i32| {
+            pixmap
+                .pixel(x.clamp(0, 599) as u32, y.clamp(0, 149) as u32)
+                .unwrap()
+                .alpha()
+        };
+        let covers = |a: &tiny_skia::Pixmap, b: &tiny_skia::Pixmap| {
+            (0..600).all(|x| {
+                (0..150).all(|y| {
+                    let (min, max) = (-1..=1)
+                        .flat_map(|dx| (-1..=1).map(move |dy| alpha(b, x + dx, y + dy)))
+                        .fold((u8::MAX, 0), |(min, max), x| (min.min(x), max.max(x)));
+                    (min.saturating_sub(32)..=max.saturating_add(32)).contains(&alpha(a, x, y))
+                })
+            })
+        };
+
+        assert!(expected.data().iter().any(|x| *x != 0));
+        assert!(covers(&actual, &expected));
+        assert!(covers(&expected, &actual));
+    }
+
+    #[test]
+    fn keeps_the_logical_order_of_right_to_left_text() {
+        const FONT: &[u8] = include_bytes!("../../fixtures/fonts/NotoSansHebrew.ttf");
+
+        let mut engine = Engine::new(EngineOptions::default());
+        engine.append_font("Hebrew", FONT);
+
+        let svg = vectorize_text(
+            &mut engine,
+            Text::new("שלום")
+                .font_family("Hebrew")
+                .font_size(20.0)
+                .finish(),
+        );
+
+        assert_eq!(text_content(&svg), "שלום");
+        assert!(!svg.contains("unicode-bidi"));
+
+        // the first character is drawn rightmost
+        let xs = svg
+            .split(" x=\"")
+            .nth(1)
+            .and_then(|x| x.split('"').next())
+            .unwrap()
+            .split(' ')
+            .map(|x| x.parse::<f32>().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(xs.len(), 4);
+        assert!(xs.windows(2).all(|x| x[0] > x[1]));
+    }
+
+    #[test]
+    fn weight_axis_selects_the_shaping_weight() {
+        let normal = measure(Text::new("weight").font_size(20.0).finish());
+        let bold = measure(
+            Text::new("weight")
+                .font_size(20.0)
+                .font_weight(FontWeight::Bold)
+                .finish(),
+        );
+        let variation = measure(
+            Text::new("weight")
+                .font_size(20.0)
+                .font_weight(FontWeight::Light)
+                .font_variations([FontVariation::weight(700.0)])
+                .finish(),
+        );
+
+        assert!(bold > normal);
+        assert_eq!(variation, bold);
+    }
+
+    #[test]
+    fn applies_other_axes_to_glyph_outlines() {
+        const FONT: &[u8] = include_bytes!("../../fixtures/fonts/NotoSansHebrew.ttf");
+
+        let mut engine = Engine::new(EngineOptions::default());
+        engine.append_font("Variable", FONT);
+
+        let mut render = |variations: &[FontVariation]| {
+            let mut scene = Scene::new(Row::new().finish());
+            let root = scene.root_id();
+            let text = scene.append_child(
+                root,
+                Text::new("שלום")
+                    .font_family("Variable")
+                    .font_size(20.0)
+                    .font_variations(variations.to_vec())
+                    .finish(),
+            );
+
+            let svg = engine
+                .vectorize(&mut scene, &VectorizeOptions::default())
+                .unwrap()
+                .0;
+            let width = scene.layout(text).unwrap().relative.border.width;
+
+            (svg, width)
+        };
+
+        let (normal, normal_width) = render(&[]);
+        let (condensed, condensed_width) = render(&[FontVariation::width(62.5)]);
+        let (weighted, _) = render(&[FontVariation::weight(400.0), FontVariation::width(100.0)]);
+
+        assert_eq!(normal.matches("<path").count(), 4);
+        assert_ne!(condensed, normal);
+        assert_eq!(weighted, normal);
+        // advances are shaped with the weight axis alone
+        assert_eq!(condensed_width, normal_width);
+    }
+
+    #[test]
+    fn merges_glyphs_with_the_same_fill() {
+        let svg = vectorize(
+            Text::new("xyz").font_size(16.0).finish(),
+            Some(PathOptimization::default()),
+        );
+
+        assert_eq!(svg.matches("<path fill=").count(), 1);
+        assert!(!svg.contains("<use"));
+        assert!(!svg.contains(" d=\"M"));
+    }
+
+    #[test]
+    fn references_repeated_glyphs() {
+        let svg = vectorize(
+            Text::new("xxyz").font_size(16.0).finish(),
+            Some(PathOptimization::default()),
+        );
+
+        assert_eq!(svg.matches("<use href=").count(), 2);
+        assert_eq!(svg.matches("<path id=").count(), 1);
+        assert_eq!(svg.matches("<path fill=").count(), 1);
+    }
+
+    #[test]
+    fn keeps_glyph_paths_by_default() {
+        let svg = vectorize(Text::new("xxyz").font_size(16.0).finish(), None);
+
+        assert_eq!(svg.matches("<path fill=").count(), 4);
+        assert!(!svg.contains("<use"));
+    }
+
+    #[test]
+    fn optimized_glyphs_render_identically() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut render = |path_optimization| {
+            let mut scene = Scene::new(Column::new().size((120, 40)).finish());
+            let root = scene.root_id();
+            scene.append_child(
+                root,
+                Text::new("Hello, hello")
+                    .font_size(16.0)
+                    .color(Color::rgb(99, 102, 241))
+                    .finish(),
+            );
+
+            let options = RasterizeOptions {
+                vectorize_options: VectorizeOptions {
+                    path_optimization,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            engine.rasterize(&mut scene, &options).unwrap().0
+        };
+
+        let expected = render(None);
+        let actual = render(Some(PathOptimization { precision: 4 }));
+
+        // rounding may move an edge across a single anti-aliasing sample
+        for (a, b) in actual.data().iter().zip(expected.data()) {
+            assert!(a.abs_diff(*b) <= 16);
+        }
+    }
+
+    /// Adds a 16 ppem color bitmap of the space glyph to a font without
+    /// bitmaps.
+    fn with_space_bitmap(font: &[u8]) -> Vec<u8> {
+        let face = swash::FontRef::from_index(font, 0).unwrap();
+        let glyph_id = face.charmap().map(' ') as usize;
+        let glyph_count = face.metrics(&[]).glyph_count as usize;
+
+        let mut pixmap = tiny_skia::Pixmap::new(16, 16).unwrap();
+        pixmap.fill(tiny_skia::Color::from_rgba8(255, 0, 0, 255));
+        let mut glyph = vec![0, 0, 0, 0];
+        glyph.extend(b"png ");
+        glyph.extend(pixmap.encode_png().unwrap());
+
+        // the offsets of every glyph data record from the start of the strike
+        let data_start = 4 + 4 * (glyph_count as u32 + 1);
+        let mut strike = [16u16.to_be_bytes(), 72u16.to_be_bytes()].concat();
+        for index in 0..=glyph_count {
+            let skipped = if index > glyph_id { glyph.len() } else { 0 };
+            strike.extend((data_start + skipped as u32).to_be_bytes());
+        }
+        strike.extend(glyph);
+
+        let mut sbix = [1u16.to_be_bytes(), 1u16.to_be_bytes()].concat();
+        sbix.extend(1u32.to_be_bytes());
+        sbix.extend(12u32.to_be_bytes());
+        sbix.extend(strike);
+
+        let (version, mut tables) = crate::utils::read_tables(font, 0).unwrap();
+        tables.insert(*b"sbix", sbix);
+        crate::utils::write_font(&version, tables)
+    }
+
+    #[test]
+    fn rasterizes_color_bitmaps_at_scale() {
+        const FONT: &[u8] =
+            include_bytes!("../../../examples/github-card/fonts/MonaSans-Regular.ttf");
+
+        let mut engine = Engine::new(EngineOptions::default());
+        engine.append_font("Bitmap", with_space_bitmap(FONT));
+
+        let mut render = |bitmap_scale| {
+            let mut scene = Scene::new(
+                Text::new("a b")
+                    .font_family("Bitmap")
+                    .font_size(16.0)
+                    .finish(),
+            );
+            engine.layout(
+                &mut scene,
+                &LayoutOptions::default(),
+                &ImageOptions::default(),
+            );
+
+            let mut svg = String::new();
+            scene
+                .write_vector(&mut svg, &VectorizeOptions::default(), bitmap_scale)
+                .unwrap();
+            svg
+        };
+
+        for (scale, pixels) in [(1.0, 16), (2.0, 32)] {
+            let svg = render(scale);
+            let start = svg.find("data:image/png;base64,").unwrap() + 22;
+            let end = start + svg[start..].find('"').unwrap();
+            let png = BASE64.decode(&svg[start..end]).unwrap();
+            let width = u32::from_be_bytes(png[16..20].try_into().unwrap());
+
+            // the bitmap resolution follows the scale, while its size does not
+            assert_eq!(width, pixels);
+            let attrs = &svg[end..end + svg[end..].find("/>").unwrap()];
+            assert!(attrs.ends_with(r#" width="16" height="16" "#));
+        }
+    }
+}
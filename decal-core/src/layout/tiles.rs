@@ -0,0 +1,196 @@
+use crate::layout::{
+    render_region,
+    RasterizeError,
+    RasterizeOptions,
+    RenderSource,
+};
+use tiny_skia::{
+    IntRect,
+    Pixmap,
+};
+use usvg::Tree;
+
+/// A rasterized tile of a scene.
+#[derive(Debug, Clone)]
+pub struct Tile {
+    /// The horizontal offset of the tile in the output image, in pixels.
+    pub x: u32,
+    /// The vertical offset of the tile in the output image, in pixels.
+    pub y: u32,
+    /// The pixmap containing the image data of the tile.
+    pub pixmap: Pixmap,
+}
+
+/// An iterator rasterizing a scene tile by tile, in row-major order.
+///
+/// The scene is parsed once, and each tile is rendered on demand, so that only
+/// a single tile is held in memory at a time. Tiles in the last column and row
+/// are cropped to the size of the output image.
+pub struct Tiles<'a> {
+    source: RenderSource<'static>,
+    options: &'a RasterizeOptions<'a>,
+    width: u32,
+    height: u32,
+    tile_width: u32,
+    tile_height: u32,
+    next: u32,
+}
+
+impl<'a> Tiles<'a> {
+    /// Creates a new [`Tiles`] instance.
+    ///
+    /// # Arguments
+    /// - `tree`: The render tree of the scene.
+    /// - `size`: The size of the output image, in pixels.
+    /// - `tile_size`: The size of each tile, in pixels.
+    /// - `options`: The [`RasterizeOptions`] value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new(
+        tree: Tree,
+        (width, height): (u32, u32),
+        (tile_width, tile_height): (u32, u32),
+        options: &'a RasterizeOptions<'a>,
+    ) -> Self {
+        Self {
+            source: RenderSource::Tree(Box::new(tree)),
+            options,
+            width,
+            height,
+            tile_width: tile_width.max(1),
+            tile_height: tile_height.max(1),
+            next: 0,
+        }
+    }
+
+    /// Returns the size of the output image, in pixels.
+    pub fn size(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Returns the number of tile columns.
+    pub fn columns(&self) -> u32 {
+        self.width.div_ceil(self.tile_width)
+    }
+
+    /// Returns the number of tile rows.
+    pub fn rows(&self) -> u32 {
+        self.height.div_ceil(self.tile_height)
+    }
+}
+
+impl Iterator for Tiles<'_> {
+    type Item = Result<Tile, RasterizeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let columns = self.columns();
+
+        if columns == 0 || self.next >= columns * self.rows() {
+            return None;
+        }
+
+        let x = (self.next % columns) * self.tile_width;
+        let y = (self.next / columns) * self.tile_height;
+        self.next += 1;
+
+        let region = IntRect::from_xywh(
+            x as i32,
+            y as i32,
+            self.tile_width.min(self.width - x),
+            self.tile_height.min(self.height - y),
+        )?;
+
+        Some(render_region(&self.source, region, self.options).map(|pixmap| Tile { x, y, pixmap }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = (self.columns() * self.rows()).saturating_sub(self.next) as usize;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Tiles<'_> {}
+
+impl std::fmt::Debug for Tiles<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tiles")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("tile_width", &self.tile_width)
+            .field("tile_height", &self.tile_height)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    fn scene() -> Scene {
+        let mut scene = Scene::new(Column::new().size((50, 30)).finish());
+        let root = scene.root_id();
+        scene.append_child(
+            root,
+            Block::new()
+                .size((20, 10))
+                .background(Color::rgb(255, 0, 0))
+                .finish(),
+        );
+        scene
+    }
+
+    #[test]
+    fn splits_into_cropped_tiles() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let options = RasterizeOptions::default();
+        let tiles = engine
+            .rasterize_tiles(&mut scene(), (16, 16), &options)
+            .unwrap();
+
+        assert_eq!((tiles.columns(), tiles.rows(), tiles.len()), (4, 2, 8));
+
+        let tiles = tiles.collect::<Result<Vec<_>, _>>().unwrap();
+        let layout = tiles
+            .iter()
+            .map(|tile| (tile.x, tile.y, tile.pixmap.width(), tile.pixmap.height()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(layout[0], (0, 0, 16, 16));
+        assert_eq!(layout[3], (48, 0, 2, 16));
+        assert_eq!(layout[7], (48, 16, 2, 14));
+    }
+
+    #[test]
+    fn tiles_match_full_rasterization() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let options = RasterizeOptions::default();
+        let (full, _) = engine.rasterize(&mut scene(), &options).unwrap();
+
+        for tile in engine
+            .rasterize_tiles(&mut scene(), (16, 8), &options)
+            .unwrap()
+        {
+            let tile = tile.unwrap();
+
+            for y in 0..tile.pixmap.height() {
+                for x in 0..tile.pixmap.width() {
+                    assert_eq!(tile.pixmap.pixel(x, y), full.pixel(tile.x + x, tile.y + y));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn renders_region() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let region = tiny_skia::IntRect::from_xywh(15, 5, 10, 10).unwrap();
+        let pixmap = engine
+            .rasterize_region(&mut scene(), region, &RasterizeOptions::default())
+            .unwrap();
+
+        assert_eq!((pixmap.width(), pixmap.height()), (10, 10));
+        assert_eq!(pixmap.pixel(0, 0).unwrap().red(), 255);
+        assert_eq!(pixmap.pixel(9, 9).unwrap().alpha(), 0);
+    }
+}
@@ -1,9 +1,14 @@
 use crate::primitives::{
     Ellipsize,
+    FontFeature,
     FontStyle,
+    FontVariation,
     FontWeight,
+    LayoutLength,
     Paint,
     TextAlign,
+    TextDecoration,
+    TextSpacing,
     TextWrap,
 };
 
@@ -14,17 +19,19 @@ use crate::primitives::{
 #[derive(Debug, Clone, Default)]
 pub(crate) struct Typography {
     pub(crate) family: Option<String>,
-    pub(crate) size: Option<f32>,
-    pub(crate) line_height: Option<f32>,
+    pub(crate) size: Option<LayoutLength<false, false>>,
+    pub(crate) line_height: Option<LayoutLength<false, false>>,
     pub(crate) weight: Option<FontWeight>,
     pub(crate) color: Option<Paint>,
     pub(crate) style: Option<FontStyle>,
-    pub(crate) letter_spacing: Option<f32>,
+    pub(crate) letter_spacing: Option<TextSpacing>,
     pub(crate) align: Option<TextAlign>,
     pub(crate) wrap: Option<TextWrap>,
     pub(crate) ellipsize: Option<Ellipsize>,
-    // TODO pub word_spacing: Option<f32>,
-    // TODO pub decoration: Option<TextDecoration>,
+    pub(crate) decoration: Option<TextDecoration>,
+    pub(crate) word_spacing: Option<TextSpacing>,
+    pub(crate) features: Option<Vec<FontFeature>>,
+    pub(crate) variations: Option<Vec<FontVariation>>,
     // computed during layout
     pub(crate) resolved_family: String,
 }
@@ -58,5 +65,9 @@ impl Typography {
         inherit!(align);
         inherit!(wrap);
         inherit!(ellipsize);
+        inherit!(decoration);
+        inherit!(word_spacing);
+        inherit!(features);
+        inherit!(variations);
     }
 }
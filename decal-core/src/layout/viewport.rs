@@ -0,0 +1,178 @@
+use crate::primitives::{
+    Calc,
+    Size,
+};
+
+/// A layout value holding expressions that may only depend on the viewport.
+trait ResolveViewport: Sized {
+    /// Replaces the expressions that do not depend on a percentage basis with
+    /// absolute lengths.
+    ///
+    /// # Arguments
+    /// - `viewport`: The size viewport units resolve against.
+    ///
+    /// # Returns
+    /// - The resolved value, or `None` if nothing was resolved.
+    fn resolve_viewport(self, viewport: Size<f32>) -> Option<Self>;
+}
+
+macro_rules! impl_resolve_viewport {
+    ($($dtype:ty),*) => {
+        $(impl ResolveViewport for $dtype {
+            fn resolve_viewport(self, viewport: Size<f32>) -> Option<Self> {
+                let raw = self.into_raw();
+
+                if !raw.is_calc() {
+                    return None;
+                }
+
+                Calc::resolve_definite_ptr(raw.calc_value(), viewport).map(Self::length)
+            }
+        })*
+    };
+}
+
+impl_resolve_viewport!(
+    taffy::Dimension,
+    taffy::LengthPercentage,
+    taffy::LengthPercentageAuto
+);
+
+impl<T> ResolveViewport for taffy::Size<T>
+where
+    T: ResolveViewport + Copy,
+{
+    fn resolve_viewport(self, viewport: Size<f32>) -> Option<Self> {
+        let width = self.width.resolve_viewport(viewport);
+        let height = self.height.resolve_viewport(viewport);
+
+        (width.is_some() || height.is_some()).then(|| taffy::Size {
+            width: width.unwrap_or(self.width),
+            height: height.unwrap_or(self.height),
+        })
+    }
+}
+
+impl<T> ResolveViewport for taffy::Rect<T>
+where
+    T: ResolveViewport + Copy,
+{
+    fn resolve_viewport(self, viewport: Size<f32>) -> Option<Self> {
+        let left = self.left.resolve_viewport(viewport);
+        let right = self.right.resolve_viewport(viewport);
+        let top = self.top.resolve_viewport(viewport);
+        let bottom = self.bottom.resolve_viewport(viewport);
+
+        (left.is_some() || right.is_some() || top.is_some() || bottom.is_some()).then(|| {
+            taffy::Rect {
+                left: left.unwrap_or(self.left),
+                right: right.unwrap_or(self.right),
+                top: top.unwrap_or(self.top),
+                bottom: bottom.unwrap_or(self.bottom),
+            }
+        })
+    }
+}
+
+/// Resolves the expressions of a layout style that only depend on the
+/// viewport, such as `vw(50.0)`, into absolute lengths.
+///
+/// Taffy treats every expression as `auto` when the value percentages resolve
+/// against is indefinite, such as the height of an automatically sized parent.
+/// Viewport units are known before layout, so they are resolved upfront.
+///
+/// # Arguments
+/// - `style`: The layout style.
+/// - `viewport`: The size viewport units resolve against.
+///
+/// # Returns
+/// - The resolved style, or `None` if the style does not contain any such
+///   expressions.
+pub(crate) fn resolve_viewport_style(
+    style: &taffy::Style,
+    viewport: Size<f32>,
+) -> Option<taffy::Style> {
+    let size = style.size.resolve_viewport(viewport);
+    let min_size = style.min_size.resolve_viewport(viewport);
+    let max_size = style.max_size.resolve_viewport(viewport);
+    let flex_basis = style.flex_basis.resolve_viewport(viewport);
+    let margin = style.margin.resolve_viewport(viewport);
+    let padding = style.padding.resolve_viewport(viewport);
+    let border = style.border.resolve_viewport(viewport);
+    let inset = style.inset.resolve_viewport(viewport);
+    let gap = style.gap.resolve_viewport(viewport);
+
+    if size.is_none()
+        && min_size.is_none()
+        && max_size.is_none()
+        && flex_basis.is_none()
+        && margin.is_none()
+        && padding.is_none()
+        && border.is_none()
+        && inset.is_none()
+        && gap.is_none()
+    {
+        return None;
+    }
+
+    Some(taffy::Style {
+        size: size.unwrap_or(style.size),
+        min_size: min_size.unwrap_or(style.min_size),
+        max_size: max_size.unwrap_or(style.max_size),
+        flex_basis: flex_basis.unwrap_or(style.flex_basis),
+        margin: margin.unwrap_or(style.margin),
+        padding: padding.unwrap_or(style.padding),
+        border: border.unwrap_or(style.border),
+        inset: inset.unwrap_or(style.inset),
+        gap: gap.unwrap_or(style.gap),
+        ..style.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    const VIEWPORT: Size<f32> = Size::from_values(1200.0, 630.0);
+
+    #[test]
+    fn resolves_viewport_units() {
+        // the style only stores pointers to the expressions
+        let width = LayoutLength::<true, true>::from(vw(50.0));
+        let height = LayoutLength::<true, true>::from(vh(10.0) + 7);
+        let left = LayoutLength::<false, true>::from(vmin(10.0));
+
+        let style = taffy::Style {
+            size: taffy::Size {
+                width: width.clone().into(),
+                height: height.clone().into(),
+            },
+            padding: taffy::Rect {
+                left: left.clone().into(),
+                ..taffy::Rect::zero()
+            },
+            ..Default::default()
+        };
+
+        let resolved = resolve_viewport_style(&style, VIEWPORT).unwrap();
+        assert_eq!(resolved.size.width, taffy::Dimension::length(600.0));
+        assert_eq!(resolved.size.height, taffy::Dimension::length(70.0));
+        assert_eq!(resolved.padding.left, taffy::LengthPercentage::length(63.0));
+        assert_eq!(resolved.padding.right, taffy::LengthPercentage::length(0.0));
+    }
+
+    #[test]
+    fn keeps_expressions_with_percentages() {
+        let width = LayoutLength::<true, true>::from(vw(50.0) - pct(10.0));
+        let style = taffy::Style {
+            size: taffy::Size {
+                width: width.clone().into(),
+                height: taffy::Dimension::auto(),
+            },
+            ..Default::default()
+        };
+
+        assert!(resolve_viewport_style(&style, VIEWPORT).is_none());
+    }
+}
@@ -1,6 +1,6 @@
 /// Wires a node type into the builder system by providing sealed accessors for
-/// layout, visual state, typography, and resources, and by generating the final
-/// [`Drawable`] implementation.
+/// layout, visual state, typography, resources, and expressions, and by
+/// generating the final [`Drawable`] implementation.
 ///
 /// # Parameters
 /// - `$node`: The concrete node type.
@@ -54,6 +54,11 @@ macro_rules! impl_node_builder {
             fn resources_mut(&mut self) -> &mut Vec<crate::paint::Resource> {
                 &mut self.resources
             }
+
+            #[inline]
+            fn expressions_mut(&mut self) -> &mut Vec<crate::primitives::Calc> {
+                &mut self.expressions
+            }
         }
 
         impl crate::capabilities::Drawable for $node {
@@ -1,8 +1,10 @@
 use crate::{
     attributes::CornerRadius,
     filters::Filter,
+    paint::Stroke,
     primitives::{
         BlendMode,
+        FillRule,
         PaintStack,
         Transform,
     },
@@ -22,4 +24,7 @@ pub struct Appearance {
     #[default(1.0)]
     pub(crate) opacity: f32,
     pub(crate) filter: Filter,
+    pub(crate) fill: PaintStack,
+    pub(crate) fill_rule: FillRule,
+    pub(crate) stroke: Stroke,
 }
@@ -1,4 +1,6 @@
 use std::{
+    cell::RefCell,
+    collections::HashMap,
     fmt::{
         Display,
         Formatter,
@@ -13,6 +15,9 @@ use twox_hash::XxHash3_64;
 const PREFIX: &'static str = "decal";
 
 /// Opaque identifier representing a stable, hashed resource IRI.
+///
+/// An IRI is rendered through the [`IriNamespace`] of the document it is
+/// written into, see [`IriNamespace::iri`].
 #[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 pub(crate) struct Iri(u64);
 
@@ -32,12 +37,187 @@ pub(crate) trait ResourceIri: Hash {
     }
 }
 
+#[cfg(test)]
 impl Display for Iri {
+    /// Renders the IRI in the default namespace.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{PREFIX}-{:x}", self.0)
+        IriNamespace::default().iri(*self).fmt(f)
+    }
+}
+
+/// The prefix and salt distinguishing the resources of a document.
+#[derive(Debug)]
+pub(crate) struct IriNamespace {
+    prefix: String,
+    salt: Option<u64>,
+    /// Sequential ids assigned to IRIs in order of appearance, when shortened.
+    short_ids: Option<RefCell<HashMap<u64, usize>>>,
+}
+
+impl Default for IriNamespace {
+    fn default() -> Self {
+        Self {
+            prefix: PREFIX.to_string(),
+            salt: None,
+            short_ids: None,
+        }
+    }
+}
+
+impl IriNamespace {
+    /// Creates a new namespace. The prefix is sanitized into a valid XML name,
+    /// see [`sanitize_prefix`].
+    ///
+    /// # Arguments
+    /// - `prefix`: The prefix of the rendered IRIs.
+    /// - `salt`: The optional salt mixed into the rendered IRIs.
+    /// - `shorten`: Whether IRIs are replaced with sequential ids.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new(prefix: &str, salt: Option<u64>, shorten: bool) -> Self {
+        Self {
+            prefix: sanitize_prefix(prefix),
+            salt,
+            short_ids: shorten.then(RefCell::default),
+        }
+    }
+
+    /// Returns a displayable form of an IRI within this namespace.
+    ///
+    /// # Arguments
+    /// - `iri`: The [`Iri`] to render.
+    ///
+    /// # Returns
+    /// - [`NamespacedIri`]
+    pub(crate) fn iri(&self, iri: Iri) -> NamespacedIri<'_> {
+        NamespacedIri {
+            namespace: self,
+            iri,
+        }
+    }
+}
+
+/// An [`Iri`] rendered within an [`IriNamespace`].
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct NamespacedIri<'a> {
+    namespace: &'a IriNamespace,
+    iri: Iri,
+}
+
+impl Display for NamespacedIri<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let IriNamespace {
+            prefix,
+            salt,
+            short_ids,
+        } = self.namespace;
+
+        match (short_ids, salt) {
+            (Some(ids), salt) => {
+                let mut ids = ids.borrow_mut();
+                let next = ids.len();
+                let id = *ids.entry(self.iri.0).or_insert(next);
+
+                match salt {
+                    Some(salt) => write!(f, "{prefix}-{salt:x}-{id:x}"),
+                    None => write!(f, "{prefix}-{id:x}"),
+                }
+            }
+            (None, Some(salt)) => {
+                let mut hasher = XxHash3_64::with_seed(*salt);
+                hasher.write_u64(self.iri.0);
+                write!(f, "{prefix}-{:x}", hasher.finish())
+            }
+            (None, None) => write!(f, "{prefix}-{:x}", self.iri.0),
+        }
+    }
+}
+
+/// Trait for values whose rendered form references resource IRIs, and so
+/// depends on the namespace of the document they are written into.
+pub(crate) trait NamespacedDisplay {
+    /// Formats the value, rendering IRIs within the given namespace.
+    ///
+    /// # Arguments
+    /// - `f`: The output formatter.
+    /// - `namespace`: The [`IriNamespace`] of the document.
+    ///
+    /// # Returns
+    /// - Empty tuple on success.
+    /// - [`std::fmt::Error`] if writing fails.
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result;
+
+    /// Returns a [`Display`] adapter rendering the value within the given
+    /// namespace.
+    ///
+    /// # Arguments
+    /// - `namespace`: The [`IriNamespace`] of the document.
+    ///
+    /// # Returns
+    /// - [`DisplayIn`]
+    fn display_in<'a>(&'a self, namespace: &'a IriNamespace) -> DisplayIn<'a, Self> {
+        DisplayIn {
+            value: self,
+            namespace,
+        }
     }
 }
 
+impl<T> NamespacedDisplay for &T
+where
+    T: NamespacedDisplay + ?Sized,
+{
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
+        (**self).fmt_in(f, namespace)
+    }
+}
+
+/// [`Display`] adapter for a [`NamespacedDisplay`] value.
+#[derive(Debug)]
+pub(crate) struct DisplayIn<'a, T: ?Sized> {
+    value: &'a T,
+    namespace: &'a IriNamespace,
+}
+
+impl<T> Display for DisplayIn<'_, T>
+where
+    T: NamespacedDisplay + ?Sized,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.value.fmt_in(f, self.namespace)
+    }
+}
+
+/// Turns an id prefix into a valid XML name. Characters outside of ASCII
+/// letters, digits, `-`, `_` and `.` are replaced with `_`, and an underscore
+/// is prepended when the prefix does not start with a letter or an underscore.
+/// An empty prefix falls back to the default one.
+///
+/// # Arguments
+/// - `prefix`: The prefix to sanitize.
+///
+/// # Returns
+/// - The sanitized prefix.
+fn sanitize_prefix(prefix: &str) -> String {
+    if prefix.is_empty() {
+        return PREFIX.to_string();
+    }
+
+    let mut sanitized = String::with_capacity(prefix.len() + 1);
+
+    if !prefix.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_') {
+        sanitized.push('_');
+    }
+
+    sanitized.extend(prefix.chars().map(|ch| match ch {
+        'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' | '.' => ch,
+        _ => '_',
+    }));
+
+    sanitized
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -49,7 +229,56 @@ mod tests {
 
     #[test]
     fn renders_iri() {
-        assert_eq!(Iri(0xdeadbeef).to_string(), "decal-deadbeef");
+        let namespace = IriNamespace::default();
+        assert_eq!(namespace.iri(Iri(0xdeadbeef)).to_string(), "decal-deadbeef");
+    }
+
+    #[test]
+    fn renders_iri_in_namespace() {
+        let namespace = IriNamespace::new("card", None, false);
+        assert_eq!(namespace.iri(Iri(0xdeadbeef)).to_string(), "card-deadbeef");
+
+        let namespace = IriNamespace::new("card", Some(1), false);
+        let salted = namespace.iri(Iri(0xdeadbeef)).to_string();
+        assert!(salted.starts_with("card-"));
+        assert_ne!(salted, "card-deadbeef");
+    }
+
+    #[test]
+    fn salts_iri_deterministically() {
+        let render = |salt| {
+            IriNamespace::new(PREFIX, Some(salt), false)
+                .iri(Iri(0xdeadbeef))
+                .to_string()
+        };
+
+        assert_eq!(render(1), render(1));
+        assert_ne!(render(1), render(2));
+    }
+
+    #[test]
+    fn shortens_iri_in_order_of_appearance() {
+        let namespace = IriNamespace::new("d", None, true);
+
+        assert_eq!(namespace.iri(Iri(0xbeef)).to_string(), "d-0");
+        assert_eq!(namespace.iri(Iri(0xdead)).to_string(), "d-1");
+        assert_eq!(namespace.iri(Iri(0xbeef)).to_string(), "d-0");
+
+        let namespace = IriNamespace::new("d", Some(0xa), true);
+        assert_eq!(namespace.iri(Iri(0xdead)).to_string(), "d-a-0");
+    }
+
+    #[test]
+    fn sanitizes_prefix() {
+        assert_eq!(sanitize_prefix("card"), "card");
+        assert_eq!(sanitize_prefix("my card#1"), "my_card_1");
+        assert_eq!(sanitize_prefix("1st"), "_1st");
+        assert_eq!(sanitize_prefix("-a"), "_-a");
+        assert_eq!(sanitize_prefix("é"), "__");
+        assert_eq!(sanitize_prefix(""), PREFIX);
+
+        let namespace = IriNamespace::new("a b", None, false);
+        assert_eq!(namespace.iri(Iri(0xbeef)).to_string(), "a_b-beef");
     }
 
     #[test]
@@ -4,6 +4,7 @@ mod resource;
 mod resources;
 mod round_rect;
 mod scaled_radii;
+mod stroke;
 
 pub(crate) use appearance::*;
 pub(crate) use iri::*;
@@ -11,3 +12,4 @@ pub(crate) use resource::*;
 pub(crate) use resources::*;
 pub(crate) use round_rect::*;
 pub(crate) use scaled_radii::*;
+pub(crate) use stroke::*;
@@ -1,3 +1,7 @@
+use super::{
+    IriNamespace,
+    NamespacedDisplay,
+};
 use crate::{
     filters::Filter,
     primitives::{
@@ -9,31 +13,41 @@ use crate::{
         Path,
         Pattern,
         RadialGradient,
+        TextDecoration,
     },
     utils::IsDefault,
 };
-use enum_display::EnumDisplay;
-use std::hash::Hash;
+use std::{
+    fmt::Formatter,
+    hash::Hash,
+};
 
 /// The resource that will be emitted inside `<defs>` and needs to be deduped.
-#[derive(Debug, Hash, Eq, PartialEq, Clone, EnumDisplay)]
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
 pub(crate) enum Resource {
-    #[display("{0}")]
     LinearGradient(LinearGradient),
-    #[display("{0}")]
     RadialGradient(RadialGradient),
-    #[display("{0}")]
     Pattern(Pattern),
-    #[display("{0}")]
     Filter(Filter),
-    #[display("{0}")]
     ClipPath(ClipPath),
-    #[display("{0}")]
     Mask(Mask),
-    #[display("{0}")]
     Path(Path),
 }
 
+impl NamespacedDisplay for Resource {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
+        match self {
+            Self::LinearGradient(x) => x.fmt_in(f, namespace),
+            Self::RadialGradient(x) => x.fmt_in(f, namespace),
+            Self::Pattern(x) => x.fmt_in(f, namespace),
+            Self::Filter(x) => x.fmt_in(f, namespace),
+            Self::ClipPath(x) => x.fmt_in(f, namespace),
+            Self::Mask(x) => x.fmt_in(f, namespace),
+            Self::Path(x) => x.fmt_in(f, namespace),
+        }
+    }
+}
+
 /// Conversion trait for extracting render resources from higher-level values.
 pub(crate) trait IntoResources {
     /// Converts the value into a collection of render resources.
@@ -63,6 +77,13 @@ impl IntoResources for Paint {
     }
 }
 
+impl IntoResources for TextDecoration {
+    #[inline]
+    fn into_resources(self) -> Vec<Resource> {
+        self.paint.map(|x| x.into_resources()).unwrap_or_default()
+    }
+}
+
 impl IntoResources for Pattern {
     #[inline]
     fn into_resources(self) -> Vec<Resource> {
@@ -1,9 +1,10 @@
-use super::Resource;
-use hashbrown::HashMap;
-use std::fmt::{
-    Display,
-    Formatter,
+use super::{
+    IriNamespace,
+    NamespacedDisplay,
+    Resource,
 };
+use hashbrown::HashMap;
+use std::fmt::Formatter;
 
 /// Collection managing a deduplicated set of render resources with stable
 /// indexing.
@@ -48,10 +49,10 @@ impl Resources {
     }
 }
 
-impl Display for Resources {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Resources {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         for resource in &self.resources {
-            resource.fmt(f)?;
+            resource.fmt_in(f, namespace)?;
         }
 
         Ok(())
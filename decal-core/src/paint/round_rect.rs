@@ -4,7 +4,7 @@ use crate::{
         Rect,
         Size,
     },
-    utils::PathWriter,
+    utils::PathSink,
 };
 
 /// Writes a path describing the filled area of a rectangle with optional
@@ -17,7 +17,7 @@ use crate::{
 /// - `r`: The [`ScaledRadii`] applied to the rectangle.
 pub(crate) fn write_fill_path<T>(out: &mut T, w: f32, h: f32, r: ScaledRadii) -> std::fmt::Result
 where
-    T: std::fmt::Write,
+    T: PathSink,
 {
     write_round_rect(out, 0.0, 0.0, w, h, r)
 }
@@ -41,7 +41,7 @@ pub(crate) fn write_border_path<T>(
     border: Rect<f32>,
 ) -> std::fmt::Result
 where
-    T: std::fmt::Write,
+    T: PathSink,
 {
     let (bt, br, bb, bl) = border.tuple();
     if bt + br + bb + bl == 0.0 {
@@ -80,7 +80,7 @@ pub(crate) fn write_clip_path<T>(
     scene_size: Size<f32>,
 ) -> std::fmt::Result
 where
-    T: std::fmt::Write,
+    T: PathSink,
 {
     let (bt, br, bb, bl) = border.tuple();
     let (scene_w, scene_h) = (scene_size.width(), scene_size.height());
@@ -144,18 +144,16 @@ fn write_round_rect<T>(
     r: ScaledRadii,
 ) -> std::fmt::Result
 where
-    T: std::fmt::Write,
+    T: PathSink,
 {
     if r.h_tl + r.h_tr + r.h_br + r.h_bl == 0.0 {
-        PathWriter::new(out)
-            .move_to(x1, y1)?
+        out.move_to(x1, y1)?
             .horizontal_to(x2)?
             .vertical_to(y2)?
             .horizontal_to(x1)?
             .close()
     } else {
-        PathWriter::new(out)
-            .move_to(x1 + r.h_tl, y1)?
+        out.move_to(x1 + r.h_tl, y1)?
             .horizontal_to(x2 - r.h_tr)?
             .arc_to(r.h_tr, r.v_tr, x2, y1 + r.v_tr)?
             .vertical_to(y2 - r.v_br)?
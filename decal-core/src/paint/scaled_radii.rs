@@ -1,4 +1,7 @@
-use crate::attributes::CornerRadius;
+use crate::{
+    attributes::CornerRadius,
+    primitives::Size,
+};
 
 /// The corner radii scaled to fit within the bounds of an element.
 #[derive(Debug, Clone, Copy, Default)]
@@ -19,25 +22,31 @@ pub(crate) struct ScaledRadii {
 /// - `r`: The [`CornerRadius`] value.
 /// - `w`: The width of the element.
 /// - `h`: The height of the element.
+/// - `viewport`: The size viewport units resolve against.
 ///
 /// # Returns
 /// - The clamped [`ScaledRadii`].
-pub(crate) fn compute_scaled_radii(r: CornerRadius, w: f32, h: f32) -> ScaledRadii {
+pub(crate) fn compute_scaled_radii(
+    r: &CornerRadius,
+    w: f32,
+    h: f32,
+    viewport: Size<f32>,
+) -> ScaledRadii {
     let (mut h_tl, mut v_tl) = (
-        r.top_left.resolve_abs(w).unwrap_or_default(),
-        r.top_left.resolve_abs(h).unwrap_or_default(),
+        r.top_left.resolve_abs(w, viewport).unwrap_or_default(),
+        r.top_left.resolve_abs(h, viewport).unwrap_or_default(),
     );
     let (mut h_tr, mut v_tr) = (
-        r.top_right.resolve_abs(w).unwrap_or_default(),
-        r.top_right.resolve_abs(h).unwrap_or_default(),
+        r.top_right.resolve_abs(w, viewport).unwrap_or_default(),
+        r.top_right.resolve_abs(h, viewport).unwrap_or_default(),
     );
     let (mut h_br, mut v_br) = (
-        r.bottom_right.resolve_abs(w).unwrap_or_default(),
-        r.bottom_right.resolve_abs(h).unwrap_or_default(),
+        r.bottom_right.resolve_abs(w, viewport).unwrap_or_default(),
+        r.bottom_right.resolve_abs(h, viewport).unwrap_or_default(),
     );
     let (mut h_bl, mut v_bl) = (
-        r.bottom_left.resolve_abs(w).unwrap_or_default(),
-        r.bottom_left.resolve_abs(h).unwrap_or_default(),
+        r.bottom_left.resolve_abs(w, viewport).unwrap_or_default(),
+        r.bottom_left.resolve_abs(h, viewport).unwrap_or_default(),
     );
 
     // scale all the radii by min ratio if adjacent radii exceed the available edge
@@ -104,7 +113,7 @@ mod tests {
 
     #[test]
     fn defaults_to_zero() {
-        let r = compute_scaled_radii(CornerRadius::default(), 100.0, 50.0);
+        let r = compute_scaled_radii(&CornerRadius::default(), 100.0, 50.0, Size::default());
         assert_eq!(r.h_tl, 0.0);
         assert_eq!(r.v_tl, 0.0);
         assert_eq!(r.h_tr, 0.0);
@@ -118,7 +127,12 @@ mod tests {
     #[test]
     fn radii_within_bounds_are_not_scaled() {
         // top-left, top-right, bottom-right, bottom-left
-        let r = compute_scaled_radii(corner_radius([10, 11, 12, 13]), 100.0, 100.0);
+        let r = compute_scaled_radii(
+            &corner_radius([10, 11, 12, 13]),
+            100.0,
+            100.0,
+            Size::default(),
+        );
         assert_eq!(r.h_tl, 10.0);
         assert_eq!(r.v_tl, 10.0);
         assert_eq!(r.h_tr, 11.0);
@@ -132,7 +146,7 @@ mod tests {
     #[test]
     fn horizontal_radii_scales_when_exceeding_width() {
         // top-left + top-right > width
-        let r = compute_scaled_radii(corner_radius([40, 40, 0, 0]), 40.0, 100.0);
+        let r = compute_scaled_radii(&corner_radius([40, 40, 0, 0]), 40.0, 100.0, Size::default());
         assert_eq!(r.h_tl, 20.0);
         assert_eq!(r.v_tl, 20.0);
         assert_eq!(r.h_tr, 20.0);
@@ -146,7 +160,7 @@ mod tests {
     #[test]
     fn vertical_radii_scales_when_exceeding_height() {
         // top-left + bottom-left > height
-        let r = compute_scaled_radii(corner_radius([40, 0, 0, 40]), 100.0, 40.0);
+        let r = compute_scaled_radii(&corner_radius([40, 0, 0, 40]), 100.0, 40.0, Size::default());
         assert_eq!(r.h_tl, 20.0);
         assert_eq!(r.v_tl, 20.0);
         assert_eq!(r.h_tr, 0.0);
@@ -160,7 +174,12 @@ mod tests {
     #[test]
     fn minimum_scale_factor() {
         // min scale from width, height
-        let r = compute_scaled_radii(corner_radius([40, 40, 40, 40]), 80.0, 20.0);
+        let r = compute_scaled_radii(
+            &corner_radius([40, 40, 40, 40]),
+            80.0,
+            20.0,
+            Size::default(),
+        );
         assert_eq!(r.h_tl, 10.0);
         assert_eq!(r.v_tl, 10.0);
         assert_eq!(r.h_tr, 10.0);
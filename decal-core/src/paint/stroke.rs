@@ -0,0 +1,124 @@
+use crate::{
+    primitives::{
+        PaintStack,
+        StrokeLineCap,
+        StrokeLineJoin,
+    },
+    utils::{
+        write_spaced,
+        ElementWriter,
+        FloatWriter,
+        Initialized,
+        IsDefault,
+    },
+};
+use smart_default::SmartDefault;
+use std::fmt::Write;
+
+/// The stroke applied to the outline of a shape.
+#[derive(Debug, Clone, SmartDefault)]
+pub(crate) struct Stroke {
+    pub(crate) paint: PaintStack,
+    #[default(1.0)]
+    pub(crate) width: f32,
+    pub(crate) dash_array: Vec<f32>,
+    pub(crate) dash_offset: f32,
+    pub(crate) line_cap: StrokeLineCap,
+    pub(crate) line_join: StrokeLineJoin,
+    #[default(4.0)]
+    pub(crate) miter_limit: f32,
+}
+
+impl Stroke {
+    /// Returns `true` if the stroke produces no visible output.
+    pub(crate) fn is_none(&self) -> bool {
+        self.paint.is_none() || self.width == 0.0
+    }
+
+    /// Writes the stroke attributes onto a paint layer element.
+    ///
+    /// # Arguments
+    /// - `element`: The element receiving the attributes.
+    ///
+    /// # Returns
+    /// - The element on success.
+    /// - [`std::fmt::Error`] if writing fails.
+    pub(crate) fn write_attrs<'a, W>(
+        &self,
+        element: ElementWriter<'a, W, Initialized>,
+    ) -> Result<ElementWriter<'a, W, Initialized>, std::fmt::Error>
+    where
+        W: Write,
+    {
+        let element = element
+            .attr("fill", "none")?
+            .attr_if("stroke-width", self.width, self.width != 1.0)?
+            .attr_if(
+                "stroke-linecap",
+                (self.line_cap,),
+                !self.line_cap.is_default(),
+            )?
+            .attr_if(
+                "stroke-linejoin",
+                (self.line_join,),
+                !self.line_join.is_default(),
+            )?
+            .attr_if(
+                "stroke-miterlimit",
+                self.miter_limit,
+                self.miter_limit != 4.0,
+            )?;
+
+        if self.dash_array.is_empty() {
+            return Ok(element);
+        }
+
+        element
+            .write_attr("stroke-dasharray", |out| {
+                write_spaced(out, self.dash_array.iter(), |out, value| {
+                    out.write_float(*value)
+                })
+            })?
+            .attr_if(
+                "stroke-dashoffset",
+                self.dash_offset,
+                self.dash_offset != 0.0,
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::str_sink;
+
+    #[test]
+    fn writes_default_attrs() {
+        assert_eq!(
+            str_sink(|out| Stroke::default()
+                .write_attrs(ElementWriter::new(out, "path")?)?
+                .close()),
+            r#"<path fill="none" />"#
+        );
+    }
+
+    #[test]
+    fn writes_attrs() {
+        let stroke = Stroke {
+            width: 2.0,
+            dash_array: vec![4.0, 2.5],
+            dash_offset: 1.0,
+            line_cap: StrokeLineCap::Round,
+            line_join: StrokeLineJoin::Bevel,
+            miter_limit: 8.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            str_sink(|out| stroke
+                .write_attrs(ElementWriter::new(out, "path")?)?
+                .close()),
+            r#"<path fill="none" stroke-width="2" stroke-linecap="round" stroke-linejoin="bevel" stroke-miterlimit="8" stroke-dasharray="4 2.5" stroke-dashoffset="1" />"#
+        );
+    }
+}
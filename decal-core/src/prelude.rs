@@ -7,5 +7,8 @@ pub use crate::{
     primitives::*,
 };
 
+// Explicit re-exports resolving glob name conflicts
+pub use crate::builders::Path;
+
 // Public macros re-export
 pub use decal_macros::*;
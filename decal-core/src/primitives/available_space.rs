@@ -0,0 +1,24 @@
+/// The space available to the root node along an axis during layout.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum AvailableSpace {
+    /// A definite amount of space in layout units. Text wraps to fit within it,
+    /// and percentages of the root node resolve against it.
+    Definite(f32),
+    /// The smallest space the content fits in, such as wrapping text at every
+    /// opportunity.
+    MinContent,
+    /// Unlimited space, so that the content takes its preferred size and text
+    /// only wraps at explicit line breaks.
+    #[default]
+    MaxContent,
+}
+
+impl From<AvailableSpace> for taffy::AvailableSpace {
+    fn from(value: AvailableSpace) -> Self {
+        match value {
+            AvailableSpace::Definite(value) => taffy::AvailableSpace::Definite(value),
+            AvailableSpace::MinContent => taffy::AvailableSpace::MinContent,
+            AvailableSpace::MaxContent => taffy::AvailableSpace::MaxContent,
+        }
+    }
+}
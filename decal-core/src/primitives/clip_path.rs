@@ -1,11 +1,12 @@
 use crate::{
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     utils::ElementWriter,
 };
-use std::fmt::{
-    Display,
-    Formatter,
-};
+use std::fmt::Formatter;
 
 /// The SVG clip path element.
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Default)]
@@ -32,9 +33,10 @@ impl ClipPath {
 
 impl ResourceIri for ClipPath {}
 
-impl Display for ClipPath {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let clip_path = ElementWriter::new(f, "clipPath")?.attr("id", (self.iri(),))?;
+impl NamespacedDisplay for ClipPath {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
+        let clip_path =
+            ElementWriter::new(f, "clipPath")?.attr("id", (namespace.iri(self.iri()),))?;
 
         if self.0.is_empty() {
             clip_path.close()
@@ -49,14 +51,17 @@ impl Display for ClipPath {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
     use std::fmt::Write;
 
     #[test]
     fn renders() {
         let clip_path = ClipPath::build(|out| out.write_str("content")).unwrap();
         assert_xml(
-            clip_path.to_string(),
+            render(&clip_path),
             format!(r#"<clipPath id="{}">content</clipPath>"#, clip_path.iri()),
         );
     }
@@ -65,7 +70,7 @@ mod tests {
     fn renders_without_content() {
         let clip_path = ClipPath::build(|_| Ok(())).unwrap();
         assert_xml(
-            clip_path.to_string(),
+            render(&clip_path),
             format!(r#"<clipPath id="{}" />"#, clip_path.iri()),
         );
     }
@@ -101,6 +101,20 @@ impl Display for Color {
     }
 }
 
+impl From<Color> for tiny_skia::ColorU8 {
+    #[inline]
+    fn from(value: Color) -> Self {
+        tiny_skia::ColorU8::from_rgba(value.r, value.g, value.b, value.a)
+    }
+}
+
+impl From<Color> for tiny_skia::Color {
+    #[inline]
+    fn from(value: Color) -> Self {
+        tiny_skia::Color::from_rgba8(value.r, value.g, value.b, value.a)
+    }
+}
+
 impl From<color::Rgba8> for Color {
     #[inline]
     fn from(value: color::Rgba8) -> Self {
@@ -1,11 +1,11 @@
-use crate::primitives::Length;
+use crate::primitives::{
+    LayoutLength,
+    Length,
+};
 
 /// The four-corner value typically used to describe per-corner properties.
 #[derive(Debug, Copy, Clone)]
-pub struct Corner<T>
-where
-    T: Copy,
-{
+pub struct Corner<T> {
     /// The top-left corner value.
     pub top_left: T,
     /// The top-right corner value.
@@ -16,10 +16,7 @@ where
     pub bottom_left: T,
 }
 
-impl<T> Corner<T>
-where
-    T: Copy,
-{
+impl<T> Corner<T> {
     /// Creates a new [`Corner`] instance.
     ///
     /// # Arguments
@@ -41,13 +38,13 @@ where
     }
 }
 
-impl<const AUTO: bool, const PERCENT: bool> Default for Corner<Length<AUTO, PERCENT>> {
+impl<const AUTO: bool, const PERCENT: bool> Default for Corner<LayoutLength<AUTO, PERCENT>> {
     fn default() -> Self {
         Self::from_values(
-            Length::zero(),
-            Length::zero(),
-            Length::zero(),
-            Length::zero(),
+            Length::zero().into(),
+            Length::zero().into(),
+            Length::zero().into(),
+            Length::zero().into(),
         )
     }
 }
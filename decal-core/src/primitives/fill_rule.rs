@@ -0,0 +1,23 @@
+use crate::utils::IsDefault;
+use enum_display::EnumDisplay;
+
+/// Determines the algorithm used to decide which parts of a shape are inside
+/// of it.
+///
+/// # Reference
+///
+/// https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/fill-rule
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone, Default, EnumDisplay)]
+pub enum FillRule {
+    /// Determines the insideness of a point by counting the path crossings
+    /// along with their direction.
+    #[default]
+    #[display("nonzero")]
+    NonZero,
+    /// Determines the insideness of a point by counting the number of path
+    /// crossings, regardless of their direction.
+    #[display("evenodd")]
+    EvenOdd,
+}
+
+impl IsDefault for FillRule {}
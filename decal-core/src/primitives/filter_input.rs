@@ -1,20 +1,20 @@
 use crate::{
     filters::PrimitiveNode,
-    paint::Iri,
+    paint::{
+        Iri,
+        IriNamespace,
+        NamespacedDisplay,
+    },
 };
-use enum_display::EnumDisplay;
 use std::fmt::{
     Display,
     Formatter,
 };
 
-#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone, EnumDisplay)]
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone)]
 enum FilterInputInner {
-    #[display("SourceGraphic")]
     SourceGraphic,
-    #[display("SourceAlpha")]
     SourceAlpha,
-    #[display("{0}")]
     Reference(Iri),
 }
 
@@ -39,9 +39,13 @@ impl FilterInput {
     }
 }
 
-impl Display for FilterInput {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0)
+impl NamespacedDisplay for FilterInput {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
+        match self.0 {
+            FilterInputInner::SourceGraphic => f.write_str("SourceGraphic"),
+            FilterInputInner::SourceAlpha => f.write_str("SourceAlpha"),
+            FilterInputInner::Reference(iri) => namespace.iri(iri).fmt(f),
+        }
     }
 }
 
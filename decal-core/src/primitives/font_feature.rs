@@ -0,0 +1,90 @@
+/// The OpenType feature setting applied during text shaping.
+///
+/// # Reference
+///
+/// https://learn.microsoft.com/en-us/typography/opentype/spec/featuretags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FontFeature {
+    pub(crate) tag: [u8; 4],
+    pub(crate) value: u32,
+}
+
+impl FontFeature {
+    /// Creates a new [`FontFeature`] setting.
+    ///
+    /// # Arguments
+    /// - `tag`: The four-byte feature tag (e.g. `b"salt"`).
+    /// - `value`: The feature value. Most features are toggled with `0` and
+    ///   `1`, while alternates select a specific glyph variant.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn new(tag: &[u8; 4], value: u32) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    /// Enables the feature with the given tag.
+    ///
+    /// # Arguments
+    /// - `tag`: The four-byte feature tag (e.g. `b"tnum"`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn on(tag: &[u8; 4]) -> Self {
+        Self::new(tag, 1)
+    }
+
+    /// Disables the feature with the given tag.
+    ///
+    /// # Arguments
+    /// - `tag`: The four-byte feature tag (e.g. `b"liga"`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn off(tag: &[u8; 4]) -> Self {
+        Self::new(tag, 0)
+    }
+
+    /// Enables tabular (fixed-width) numerals (`tnum`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn tabular_nums() -> Self {
+        Self::on(b"tnum")
+    }
+
+    /// Disables standard ligatures (`liga`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn no_ligatures() -> Self {
+        Self::off(b"liga")
+    }
+
+    /// Enables small capitals (`smcp`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn small_caps() -> Self {
+        Self::on(b"smcp")
+    }
+
+    /// Enables the stylistic set with the given number (`ss01` to `ss20`).
+    ///
+    /// # Arguments
+    /// - `set`: The stylistic set number, clamped to `[1, 20]`.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn stylistic_set(set: u8) -> Self {
+        let set = if set < 1 {
+            1
+        } else if set > 20 {
+            20
+        } else {
+            set
+        };
+
+        Self::on(&[b's', b's', b'0' + set / 10, b'0' + set % 10])
+    }
+}
@@ -0,0 +1,82 @@
+/// The variable font axis setting.
+///
+/// # Reference
+///
+/// https://learn.microsoft.com/en-us/typography/opentype/spec/dvaraxisreg
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FontVariation {
+    pub(crate) tag: [u8; 4],
+    pub(crate) value: f32,
+}
+
+impl FontVariation {
+    /// Creates a new [`FontVariation`] setting.
+    ///
+    /// # Arguments
+    /// - `tag`: The four-byte axis tag (e.g. `b"GRAD"`).
+    /// - `value`: The value of the axis in its user-space coordinates.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn new(tag: &[u8; 4], value: f32) -> Self {
+        Self { tag: *tag, value }
+    }
+
+    /// Sets the weight axis (`wght`).
+    ///
+    /// # Arguments
+    /// - `value`: The weight, usually in the range `[1, 1000]`.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn weight(value: f32) -> Self {
+        Self::new(b"wght", value)
+    }
+
+    /// Sets the width axis (`wdth`).
+    ///
+    /// # Arguments
+    /// - `value`: The width as a percentage of the normal width.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn width(value: f32) -> Self {
+        Self::new(b"wdth", value)
+    }
+
+    /// Sets the optical size axis (`opsz`).
+    ///
+    /// # Arguments
+    /// - `value`: The optical size in points.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn optical_size(value: f32) -> Self {
+        Self::new(b"opsz", value)
+    }
+
+    /// Sets the slant axis (`slnt`).
+    ///
+    /// # Arguments
+    /// - `value`: The slant angle in counter-clockwise degrees.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub const fn slant(value: f32) -> Self {
+        Self::new(b"slnt", value)
+    }
+
+    /// Returns `true` if this setting targets the weight axis.
+    pub(crate) fn is_weight(&self) -> bool {
+        &self.tag == b"wght"
+    }
+}
+
+impl From<FontVariation> for swash::Setting<f32> {
+    fn from(value: FontVariation) -> Self {
+        swash::Setting {
+            tag: swash::tag_from_bytes(&value.tag),
+            value: value.value,
+        }
+    }
+}
@@ -1,17 +1,26 @@
 use crate::{
     macros::ff32,
+    primitives::{
+        Rect,
+        Size,
+    },
     utils::FloatWriter,
 };
 use std::{
     fmt::{
+        Debug,
         Display,
         Formatter,
         Write,
     },
     ops::{
         Add,
+        Div,
+        Mul,
+        Neg,
         Sub,
     },
+    sync::Arc,
 };
 use strict_num::FiniteF32;
 use taffy::prelude::{
@@ -39,6 +48,469 @@ impl LengthInner {
     }
 }
 
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+enum ViewportUnit {
+    Vw,
+    Vh,
+    Vmin,
+    Vmax,
+}
+
+/// A length relative to the size of the viewport, such as `50vw`.
+///
+/// Viewport lengths are created with [`vw`](crate::prelude::vw),
+/// [`vh`](crate::prelude::vh), [`vmin`](crate::prelude::vmin) and
+/// [`vmax`](crate::prelude::vmax), and combine with other values into a
+/// [`Calc`] expression, such as `vw(100.0) - 32`.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy)]
+pub struct ViewportLength {
+    unit: ViewportUnit,
+    value: FiniteF32,
+}
+
+impl ViewportLength {
+    /// Creates a new [`ViewportLength`].
+    ///
+    /// # Arguments
+    /// - `unit`: The [`ViewportUnit`] value.
+    /// - `value`: The percentage of the viewport (e.g. `50.0` as `50vw`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn new(unit: ViewportUnit, value: f32) -> Self {
+        Self {
+            unit,
+            value: ff32!(value / 100.0),
+        }
+    }
+
+    /// Resolves the length into an absolute value.
+    ///
+    /// # Arguments
+    /// - `viewport`: The size viewport units resolve against.
+    ///
+    /// # Returns
+    /// - The resolved value.
+    pub(crate) fn resolve(self, viewport: Size<f32>) -> f32 {
+        self.value.get()
+            * match self.unit {
+                ViewportUnit::Vw => viewport.width,
+                ViewportUnit::Vh => viewport.height,
+                ViewportUnit::Vmin => viewport.width.min(viewport.height),
+                ViewportUnit::Vmax => viewport.width.max(viewport.height),
+            }
+    }
+}
+
+impl Display for ViewportLength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_float(self.value.get() * 100.0)?;
+        f.write_str(match self.unit {
+            ViewportUnit::Vw => "vw",
+            ViewportUnit::Vh => "vh",
+            ViewportUnit::Vmin => "vmin",
+            ViewportUnit::Vmax => "vmax",
+        })
+    }
+}
+
+impl<T> Add<T> for ViewportLength
+where
+    T: Into<Calc>,
+{
+    type Output = Calc;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Calc::from(self) + rhs
+    }
+}
+
+impl<T> Sub<T> for ViewportLength
+where
+    T: Into<Calc>,
+{
+    type Output = Calc;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Calc::from(self) - rhs
+    }
+}
+
+impl Mul<f32> for ViewportLength {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            value: ff32!(self.value.get() * rhs),
+            ..self
+        }
+    }
+}
+
+impl Div<f32> for ViewportLength {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        self * (1.0 / rhs)
+    }
+}
+
+impl Neg for ViewportLength {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
+/// A node of a [`Calc`] expression.
+///
+/// Nodes are aligned to 8 bytes, as taffy stores the pointer to an expression
+/// alongside a tag in its low bits.
+#[derive(Debug, Hash, Eq, PartialEq)]
+#[repr(align(8))]
+struct CalcNode(CalcOp);
+
+#[derive(Debug, Hash, Eq, PartialEq)]
+enum CalcOp {
+    Units(FiniteF32),
+    Percent(FiniteF32),
+    Viewport(ViewportLength),
+    Sum(Calc, Calc),
+    Difference(Calc, Calc),
+    Product(Calc, FiniteF32),
+    Min(Calc, Calc),
+    Max(Calc, Calc),
+    Clamp(Calc, Calc, Calc),
+}
+
+/// A `calc()` expression combining absolute, percentage and viewport lengths,
+/// such as `100% - 32` or `min(50%, 400)`.
+///
+/// Percentages resolve against the same basis as a plain percentage in the
+/// position the expression is used in, such as the width of the parent for
+/// widths, margins and paddings. Expressions are built with the arithmetic
+/// operators and [`Calc::min`], [`Calc::max`] and [`Calc::clamp`], and
+/// convert into a [`LayoutLength`] that accepts percentages:
+///
+/// ```rust
+/// # use decal::prelude::*;
+/// let width: LayoutLength = (calc(pct(100.0)) - 32).into();
+/// let column: LayoutLength = Calc::clamp(200, pct(50.0), 600).into();
+/// ```
+///
+/// A [`Calc`] is a reference counted handle, so cloning it is cheap. The
+/// expression is dropped along with the last node referencing it.
+#[derive(Clone, Hash, Eq, PartialEq)]
+pub struct Calc(Arc<CalcNode>);
+
+impl Calc {
+    /// Creates an expression with the given operation.
+    fn new(op: CalcOp) -> Self {
+        Self(Arc::new(CalcNode(op)))
+    }
+
+    /// Creates an expression resolving to the smaller of two values.
+    ///
+    /// # Arguments
+    /// - `a`: The first value.
+    /// - `b`: The second value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    #[must_use]
+    pub fn min<A, B>(a: A, b: B) -> Self
+    where
+        A: Into<Calc>,
+        B: Into<Calc>,
+    {
+        Self::new(CalcOp::Min(a.into(), b.into()))
+    }
+
+    /// Creates an expression resolving to the larger of two values.
+    ///
+    /// # Arguments
+    /// - `a`: The first value.
+    /// - `b`: The second value.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    #[must_use]
+    pub fn max<A, B>(a: A, b: B) -> Self
+    where
+        A: Into<Calc>,
+        B: Into<Calc>,
+    {
+        Self::new(CalcOp::Max(a.into(), b.into()))
+    }
+
+    /// Creates an expression restricting a value to a range. The lower bound
+    /// wins when the bounds overlap.
+    ///
+    /// # Arguments
+    /// - `min`: The lower bound.
+    /// - `value`: The preferred value.
+    /// - `max`: The upper bound.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    #[must_use]
+    pub fn clamp<A, B, C>(min: A, value: B, max: C) -> Self
+    where
+        A: Into<Calc>,
+        B: Into<Calc>,
+        C: Into<Calc>,
+    {
+        Self::new(CalcOp::Clamp(min.into(), value.into(), max.into()))
+    }
+
+    /// Resolves the expression into an absolute value.
+    ///
+    /// # Arguments
+    /// - `basis`: The value percentages resolve against.
+    /// - `viewport`: The size viewport units resolve against.
+    ///
+    /// # Returns
+    /// - The resolved value.
+    pub(crate) fn resolve(&self, basis: f32, viewport: Size<f32>) -> f32 {
+        self.0.resolve(basis, viewport)
+    }
+
+    /// Returns the expression behind a pointer passed to taffy.
+    ///
+    /// # Arguments
+    /// - `ptr`: The pointer returned by [`Calc::as_ptr`].
+    ///
+    /// # Returns
+    /// - The expression node.
+    fn from_ptr<'a>(ptr: *const ()) -> &'a CalcNode {
+        // SAFETY: taffy only hands back pointers created by `as_ptr` for the
+        // style of a node, which keeps the expressions alive until it is
+        // dropped along with its scene.
+        unsafe { &*(ptr as *const CalcNode) }
+    }
+
+    /// Resolves an expression passed to taffy as a pointer.
+    ///
+    /// # Arguments
+    /// - `ptr`: The pointer returned by [`Calc::as_ptr`].
+    /// - `basis`: The value percentages resolve against.
+    /// - `viewport`: The size viewport units resolve against.
+    ///
+    /// # Returns
+    /// - The resolved value.
+    pub(crate) fn resolve_ptr(ptr: *const (), basis: f32, viewport: Size<f32>) -> f32 {
+        Self::from_ptr(ptr).resolve(basis, viewport)
+    }
+
+    /// Resolves an expression passed to taffy as a pointer, if it does not
+    /// depend on a percentage basis.
+    ///
+    /// # Arguments
+    /// - `ptr`: The pointer returned by [`Calc::as_ptr`].
+    /// - `viewport`: The size viewport units resolve against.
+    ///
+    /// # Returns
+    /// - The resolved value, or `None` if the expression contains percentages.
+    pub(crate) fn resolve_definite_ptr(ptr: *const (), viewport: Size<f32>) -> Option<f32> {
+        let node = Self::from_ptr(ptr);
+        (!node.has_percent()).then(|| node.resolve(0.0, viewport))
+    }
+
+    /// Returns the pointer to the expression stored by taffy. The expression
+    /// must outlive the style the pointer is stored in.
+    fn as_ptr(&self) -> *const () {
+        Arc::as_ptr(&self.0) as *const ()
+    }
+}
+
+impl CalcNode {
+    /// Resolves the expression into an absolute value, see [`Calc::resolve`].
+    fn resolve(&self, basis: f32, viewport: Size<f32>) -> f32 {
+        let resolve = |x: &Calc| x.resolve(basis, viewport);
+
+        match &self.0 {
+            CalcOp::Units(x) => x.get(),
+            CalcOp::Percent(x) => x.get() * basis,
+            CalcOp::Viewport(x) => x.resolve(viewport),
+            CalcOp::Sum(a, b) => resolve(a) + resolve(b),
+            CalcOp::Difference(a, b) => resolve(a) - resolve(b),
+            CalcOp::Product(a, factor) => resolve(a) * factor.get(),
+            CalcOp::Min(a, b) => resolve(a).min(resolve(b)),
+            CalcOp::Max(a, b) => resolve(a).max(resolve(b)),
+            CalcOp::Clamp(min, value, max) => resolve(value).min(resolve(max)).max(resolve(min)),
+        }
+    }
+
+    /// Returns `true` if the expression contains percentages.
+    fn has_percent(&self) -> bool {
+        match &self.0 {
+            CalcOp::Percent(_) => true,
+            CalcOp::Units(_) | CalcOp::Viewport(_) => false,
+            CalcOp::Product(a, _) => a.0.has_percent(),
+            CalcOp::Sum(a, b)
+            | CalcOp::Difference(a, b)
+            | CalcOp::Min(a, b)
+            | CalcOp::Max(a, b) => a.0.has_percent() || b.0.has_percent(),
+            CalcOp::Clamp(min, value, max) => {
+                min.0.has_percent() || value.0.has_percent() || max.0.has_percent()
+            }
+        }
+    }
+
+    /// Writes the expression without the enclosing `calc()`.
+    ///
+    /// # Arguments
+    /// - `f`: The output formatter.
+    /// - `nested`: Whether the expression is an operand of an arithmetic
+    ///   operation, in which case sums and differences are parenthesized.
+    fn write_expr(&self, f: &mut Formatter<'_>, nested: bool) -> std::fmt::Result {
+        match &self.0 {
+            CalcOp::Units(x) => f.write_float(x.get()),
+            CalcOp::Percent(x) => {
+                f.write_float(x.get() * 100.0)?;
+                f.write_char('%')
+            }
+            CalcOp::Viewport(x) => Display::fmt(x, f),
+            CalcOp::Sum(a, b) | CalcOp::Difference(a, b) => {
+                let op = if matches!(self.0, CalcOp::Sum(..)) {
+                    " + "
+                } else {
+                    " - "
+                };
+
+                if nested {
+                    f.write_char('(')?;
+                }
+
+                a.0.write_expr(f, false)?;
+                f.write_str(op)?;
+                b.0.write_expr(f, true)?;
+
+                if nested {
+                    f.write_char(')')?;
+                }
+
+                Ok(())
+            }
+            CalcOp::Product(a, factor) => {
+                a.0.write_expr(f, true)?;
+                f.write_str(" * ")?;
+                f.write_float(factor.get())
+            }
+            CalcOp::Min(a, b) | CalcOp::Max(a, b) => {
+                f.write_str(if matches!(self.0, CalcOp::Min(..)) {
+                    "min("
+                } else {
+                    "max("
+                })?;
+                a.0.write_expr(f, false)?;
+                f.write_str(", ")?;
+                b.0.write_expr(f, false)?;
+                f.write_char(')')
+            }
+            CalcOp::Clamp(min, value, max) => {
+                f.write_str("clamp(")?;
+                min.0.write_expr(f, false)?;
+                f.write_str(", ")?;
+                value.0.write_expr(f, false)?;
+                f.write_str(", ")?;
+                max.0.write_expr(f, false)?;
+                f.write_char(')')
+            }
+        }
+    }
+}
+
+impl Display for Calc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("calc(")?;
+        self.0.write_expr(f, false)?;
+        f.write_char(')')
+    }
+}
+
+impl Debug for Calc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const PERCENT: bool> From<Length<false, PERCENT>> for Calc {
+    fn from(value: Length<false, PERCENT>) -> Self {
+        match value.0 {
+            LengthInner::Zero => Self::new(CalcOp::Units(FiniteF32::default())),
+            LengthInner::Absolute(x) => Self::new(CalcOp::Units(x)),
+            LengthInner::Percent(x) => Self::new(CalcOp::Percent(x)),
+            LengthInner::Auto => unreachable!(),
+        }
+    }
+}
+
+impl From<ViewportLength> for Calc {
+    fn from(value: ViewportLength) -> Self {
+        Self::new(CalcOp::Viewport(value))
+    }
+}
+
+macro_rules! impl_into_unit_calc {
+    ($($dtype:ty),*) => {
+        $(impl From<$dtype> for Calc {
+            fn from(value: $dtype) -> Self {
+                Self::new(CalcOp::Units(ff32!(value as f32)))
+            }
+        })*
+    };
+}
+
+impl_into_unit_calc!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl<T> Add<T> for Calc
+where
+    T: Into<Calc>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self::Output {
+        Self::new(CalcOp::Sum(self, rhs.into()))
+    }
+}
+
+impl<T> Sub<T> for Calc
+where
+    T: Into<Calc>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self::Output {
+        Self::new(CalcOp::Difference(self, rhs.into()))
+    }
+}
+
+impl Mul<f32> for Calc {
+    type Output = Self;
+
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self::new(CalcOp::Product(self, ff32!(rhs)))
+    }
+}
+
+impl Div<f32> for Calc {
+    type Output = Self;
+
+    fn div(self, rhs: f32) -> Self::Output {
+        Self::new(CalcOp::Product(self, ff32!(1.0 / rhs)))
+    }
+}
+
+impl Neg for Calc {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self * -1.0
+    }
+}
+
 /// The length value used for sizing, positioning, and spacing.
 ///
 /// A [`Length`] can represent:
@@ -121,14 +593,15 @@ impl<const AUTO: bool, const PERCENT: bool> Length<AUTO, PERCENT> {
 
     /// Tries to resolve the length into an absolute value.
     ///
-    /// - Absolute values are returned as it is.
+    /// - Zero and absolute values are returned as it is.
     /// - Percentage values are resolved relative to `full`.
     /// - Returns `None` otherwise.
     pub(crate) fn resolve_abs(&self, full: f32) -> Option<f32> {
         match self.0 {
+            LengthInner::Zero => Some(0.0),
             LengthInner::Absolute(value) => Some(value.get()),
             LengthInner::Percent(value) => Some(value.get() * full),
-            _ => None,
+            LengthInner::Auto => None,
         }
     }
 }
@@ -242,10 +715,217 @@ macro_rules! impl_into_unit_length {
 
 impl_into_unit_length!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
 
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+enum LayoutLengthInner {
+    Length(LengthInner),
+    Calc(Calc),
+}
+
+impl Default for LayoutLengthInner {
+    fn default() -> Self {
+        Self::Length(LengthInner::Zero)
+    }
+}
+
+/// The length value of properties resolved once the scene is laid out, such
+/// as sizes, spacing, corner radii and font sizes.
+///
+/// A [`LayoutLength`] can represent:
+/// - any [`Length`]
+/// - a [`ViewportLength`], such as `vw(50.0)`
+/// - a [`Calc`] expression, for properties accepting percentages
+///
+/// Lengths written into the markup as they are, such as the coordinates of
+/// gradients and filter regions, only accept a [`Length`]. Expressions are
+/// also rejected for properties without a percentage basis, such as font
+/// sizes:
+///
+/// ```rust,compile_fail
+/// # use decal::prelude::*;
+/// Text::new("Title").font_size(calc(vw(4.0)) + 8);
+/// ```
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Default)]
+pub struct LayoutLength<const AUTO: bool = true, const PERCENT: bool = true>(LayoutLengthInner);
+
+impl<const AUTO: bool, const PERCENT: bool> LayoutLength<AUTO, PERCENT> {
+    /// Returns `true` if the length resolves to zero.
+    pub(crate) fn is_zero(&self) -> bool {
+        match &self.0 {
+            LayoutLengthInner::Length(x) => Length::<AUTO, PERCENT>(*x).is_zero(),
+            LayoutLengthInner::Calc(_) => false,
+        }
+    }
+
+    /// Tries to resolve the length into an absolute value.
+    ///
+    /// - Zero and absolute values are returned as it is.
+    /// - Percentage values and expressions are resolved relative to `full`.
+    /// - Viewport units are resolved relative to `viewport`.
+    /// - Returns `None` otherwise.
+    pub(crate) fn resolve_abs(&self, full: f32, viewport: Size<f32>) -> Option<f32> {
+        match &self.0 {
+            LayoutLengthInner::Length(x) => Length::<AUTO, PERCENT>(*x).resolve_abs(full),
+            LayoutLengthInner::Calc(x) => Some(x.resolve(full, viewport)),
+        }
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool> Display for LayoutLength<AUTO, PERCENT> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            LayoutLengthInner::Length(x) => Display::fmt(&Length::<AUTO, PERCENT>(*x), f),
+            LayoutLengthInner::Calc(x) => Display::fmt(x, f),
+        }
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool> From<Length<AUTO, PERCENT>>
+    for LayoutLength<AUTO, PERCENT>
+{
+    fn from(value: Length<AUTO, PERCENT>) -> Self {
+        Self(LayoutLengthInner::Length(value.0))
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool> From<ViewportLength> for LayoutLength<AUTO, PERCENT> {
+    fn from(value: ViewportLength) -> Self {
+        Self(LayoutLengthInner::Calc(value.into()))
+    }
+}
+
+// expressions may contain percentages, so they are only accepted where a
+// percentage basis exists
+impl<const AUTO: bool> From<Calc> for LayoutLength<AUTO, true> {
+    fn from(value: Calc) -> Self {
+        Self(LayoutLengthInner::Calc(value))
+    }
+}
+
+macro_rules! impl_into_unit_layout_length {
+    ($($dtype:ty),*) => {
+        $(impl<const AUTO: bool, const PERCENT: bool> From<$dtype> for LayoutLength<AUTO, PERCENT> {
+            fn from(value: $dtype) -> Self {
+                Length::units(value as f32).into()
+            }
+        })*
+    };
+}
+
+impl_into_unit_layout_length!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+impl<const PERCENT: bool> From<LayoutLength<false, PERCENT>> for taffy::LengthPercentage {
+    fn from(value: LayoutLength<false, PERCENT>) -> Self {
+        match value.0 {
+            LayoutLengthInner::Length(x) => Length::<false, PERCENT>(x).into(),
+            LayoutLengthInner::Calc(x) => taffy::LengthPercentage::calc(x.as_ptr()),
+        }
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool> From<LayoutLength<AUTO, PERCENT>>
+    for taffy::LengthPercentageAuto
+{
+    fn from(value: LayoutLength<AUTO, PERCENT>) -> Self {
+        match value.0 {
+            LayoutLengthInner::Length(x) => Length::<AUTO, PERCENT>(x).into(),
+            LayoutLengthInner::Calc(x) => taffy::LengthPercentageAuto::calc(x.as_ptr()),
+        }
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool> From<LayoutLength<AUTO, PERCENT>> for taffy::Dimension {
+    fn from(value: LayoutLength<AUTO, PERCENT>) -> Self {
+        let length: taffy::LengthPercentageAuto = value.into();
+        taffy::Dimension::from(length)
+    }
+}
+
+/// Conversion into an optional [`LayoutLength`] value.
+///
+/// This is primarily used by builder APIs to allow both direct values and
+/// `Option` values to be passed ergonomically.
+pub trait IntoOptionalLayoutLength<const AUTO: bool = true, const PERCENT: bool = true> {
+    /// Converts the value into an optional layout length.
+    fn into_optional_layout_length(self) -> Option<LayoutLength<AUTO, PERCENT>>;
+}
+
+impl<const AUTO: bool, const PERCENT: bool> IntoOptionalLayoutLength<AUTO, PERCENT>
+    for Option<LayoutLength<AUTO, PERCENT>>
+{
+    fn into_optional_layout_length(self) -> Option<LayoutLength<AUTO, PERCENT>> {
+        self
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool, T> IntoOptionalLayoutLength<AUTO, PERCENT> for T
+where
+    T: Into<LayoutLength<AUTO, PERCENT>>,
+{
+    fn into_optional_layout_length(self) -> Option<LayoutLength<AUTO, PERCENT>> {
+        Some(self.into())
+    }
+}
+
+/// Values holding [`Calc`] expressions that are passed to taffy as pointers.
+pub(crate) trait Expressions {
+    /// Appends the expressions held by the value to `out`, so that they can
+    /// be kept alive with the node referencing them.
+    ///
+    /// # Arguments
+    /// - `out`: The expressions of the node.
+    fn collect_expressions(&self, out: &mut Vec<Calc>);
+}
+
+impl<const AUTO: bool, const PERCENT: bool> Expressions for LayoutLength<AUTO, PERCENT> {
+    fn collect_expressions(&self, out: &mut Vec<Calc>) {
+        if let LayoutLengthInner::Calc(x) = &self.0 {
+            out.push(x.clone());
+        }
+    }
+}
+
+impl<T> Expressions for Option<T>
+where
+    T: Expressions,
+{
+    fn collect_expressions(&self, out: &mut Vec<Calc>) {
+        if let Some(value) = self {
+            value.collect_expressions(out);
+        }
+    }
+}
+
+impl<T> Expressions for Size<T>
+where
+    T: Expressions,
+{
+    fn collect_expressions(&self, out: &mut Vec<Calc>) {
+        self.width.collect_expressions(out);
+        self.height.collect_expressions(out);
+    }
+}
+
+impl<T> Expressions for Rect<T>
+where
+    T: Expressions,
+{
+    fn collect_expressions(&self, out: &mut Vec<Calc>) {
+        self.top.collect_expressions(out);
+        self.right.collect_expressions(out);
+        self.bottom.collect_expressions(out);
+        self.left.collect_expressions(out);
+    }
+}
+
 //
 
 pub(super) mod helpers {
-    use super::Length;
+    use super::{
+        Calc,
+        Length,
+        ViewportLength,
+        ViewportUnit,
+    };
 
     /// Returns a zero [`Length`] value.
     ///
@@ -296,6 +976,89 @@ pub(super) mod helpers {
         Length::percent(value.into() as f32)
     }
 
+    /// Starts a [`Calc`] expression from a value, such as `calc(pct(100.0)) -
+    /// 32`.
+    ///
+    /// # Arguments
+    /// - `value`: The value convertible into [`Calc`].
+    ///
+    /// # Returns
+    /// - [`Calc`]
+    #[must_use]
+    pub fn calc<T>(value: T) -> Calc
+    where
+        T: Into<Calc>,
+    {
+        value.into()
+    }
+
+    /// Creates a length relative to the width of the viewport. The value is
+    /// interpreted as a percentage (e.g. `50.0` as `50vw`).
+    ///
+    /// The length can be combined with other values into a [`Calc`]
+    /// expression, such as `vw(100.0) - 32`.
+    ///
+    /// # Arguments
+    /// - `value`: The percentage of the viewport width.
+    ///
+    /// # Returns
+    /// - [`ViewportLength`]
+    #[must_use]
+    pub fn vw<T>(value: T) -> ViewportLength
+    where
+        T: Into<f64>,
+    {
+        ViewportLength::new(ViewportUnit::Vw, value.into() as f32)
+    }
+
+    /// Creates a length relative to the height of the viewport. The value is
+    /// interpreted as a percentage (e.g. `50.0` as `50vh`).
+    ///
+    /// # Arguments
+    /// - `value`: The percentage of the viewport height.
+    ///
+    /// # Returns
+    /// - [`ViewportLength`]
+    #[must_use]
+    pub fn vh<T>(value: T) -> ViewportLength
+    where
+        T: Into<f64>,
+    {
+        ViewportLength::new(ViewportUnit::Vh, value.into() as f32)
+    }
+
+    /// Creates a length relative to the smaller side of the viewport. The
+    /// value is interpreted as a percentage (e.g. `50.0` as `50vmin`).
+    ///
+    /// # Arguments
+    /// - `value`: The percentage of the smaller viewport side.
+    ///
+    /// # Returns
+    /// - [`ViewportLength`]
+    #[must_use]
+    pub fn vmin<T>(value: T) -> ViewportLength
+    where
+        T: Into<f64>,
+    {
+        ViewportLength::new(ViewportUnit::Vmin, value.into() as f32)
+    }
+
+    /// Creates a length relative to the larger side of the viewport. The value
+    /// is interpreted as a percentage (e.g. `50.0` as `50vmax`).
+    ///
+    /// # Arguments
+    /// - `value`: The percentage of the larger viewport side.
+    ///
+    /// # Returns
+    /// - [`ViewportLength`]
+    #[must_use]
+    pub fn vmax<T>(value: T) -> ViewportLength
+    where
+        T: Into<f64>,
+    {
+        ViewportLength::new(ViewportUnit::Vmax, value.into() as f32)
+    }
+
     pub trait LengthExtension<const AUTO: bool, const PERCENT: bool>: Sized + Copy {
         /// Converts the value into an absolute [`Length`] value.
         ///
@@ -336,6 +1099,7 @@ mod tests {
     };
 
     type Len = Length<true, true>;
+    type LayoutLen = LayoutLength<true, true>;
     type LenNoAutoNoPct = Length<false, false>;
 
     #[test]
@@ -462,4 +1226,131 @@ mod tests {
     fn from_percent_helper() {
         assert_eq!(pct::<_, true>(50), Len::percent(50.0));
     }
+
+    // calc
+
+    #[test]
+    fn resolves_calc() {
+        let x = LayoutLen::from(calc(pct(100.0)) - 32);
+        assert_eq!(x.resolve_abs(200.0, Size::default()), Some(168.0));
+        assert!(!x.is_zero());
+    }
+
+    #[test]
+    fn resolves_calc_functions() {
+        let min = Calc::min(pct(50.0), 400);
+        assert_eq!(min.resolve(600.0, Size::default()), 300.0);
+        assert_eq!(min.resolve(1000.0, Size::default()), 400.0);
+
+        let max = Calc::max(pct(50.0), 400);
+        assert_eq!(max.resolve(600.0, Size::default()), 400.0);
+
+        let clamp = Calc::clamp(200, pct(50.0), 600);
+        assert_eq!(clamp.resolve(100.0, Size::default()), 200.0);
+        assert_eq!(clamp.resolve(800.0, Size::default()), 400.0);
+        assert_eq!(clamp.resolve(2000.0, Size::default()), 600.0);
+        assert_eq!(
+            Calc::clamp(300, 0, 100).resolve(0.0, Size::default()),
+            300.0
+        );
+    }
+
+    #[test]
+    fn resolves_calc_arithmetic() {
+        let x = (calc(pct(100.0)) - 20) / 2.0 + 5;
+        assert_eq!(x.resolve(100.0, Size::default()), 45.0);
+        assert_eq!((-x * 2.0).resolve(100.0, Size::default()), -90.0);
+    }
+
+    #[test]
+    fn aligns_calc_pointers() {
+        let x = calc(pct(100.0)) - 32;
+        assert_eq!(x.as_ptr() as usize & 0b111, 0);
+    }
+
+    #[test]
+    fn resolves_calc_pointers() {
+        let x = calc(pct(50.0)) + 10;
+        let length: taffy::LengthPercentageAuto = LayoutLen::from(x.clone()).into();
+        assert_eq!(
+            Calc::resolve_ptr(length.into_raw().calc_value(), 100.0, Size::default()),
+            60.0
+        );
+        assert_eq!(
+            Calc::resolve_definite_ptr(length.into_raw().calc_value(), Size::default()),
+            None
+        );
+    }
+
+    #[test]
+    fn collects_expressions() {
+        let x = calc(pct(50.0)) + 10;
+        let mut expressions = Vec::new();
+
+        Some(LayoutLen::from(x.clone())).collect_expressions(&mut expressions);
+        LayoutLen::from(Len::units(4.0)).collect_expressions(&mut expressions);
+        None::<LayoutLen>.collect_expressions(&mut expressions);
+
+        assert_eq!(expressions, vec![x]);
+    }
+
+    #[test]
+    fn drops_calc_with_last_handle() {
+        let x = calc(pct(50.0)) + 10;
+        let length = LayoutLen::from(x.clone());
+        assert_eq!(Arc::strong_count(&x.0), 2);
+
+        drop(length);
+        assert_eq!(Arc::strong_count(&x.0), 1);
+    }
+
+    #[test]
+    fn renders_calc() {
+        assert_eq!(
+            LayoutLen::from(calc(pct(100.0)) - 32).to_string(),
+            "calc(100% - 32)"
+        );
+        assert_eq!(
+            LayoutLen::from(calc(10) - (calc(pct(50.0)) + 4)).to_string(),
+            "calc(10 - (50% + 4))"
+        );
+        assert_eq!(
+            LayoutLen::from(Calc::clamp(1, Calc::min(pct(50.0), 400) * 2.0, 3)).to_string(),
+            "calc(clamp(1, min(50%, 400) * 2, 3))"
+        );
+    }
+
+    // viewport units
+
+    #[test]
+    fn resolves_viewport_units() {
+        let viewport = Size::from_values(1200.0, 630.0);
+        assert_eq!(calc(vw(10)).resolve(0.0, viewport), 120.0);
+        assert_eq!(calc(vh(10)).resolve(0.0, viewport), 63.0);
+        assert_eq!(calc(vmin(10)).resolve(0.0, viewport), 63.0);
+        assert_eq!(calc(vmax(10)).resolve(0.0, viewport), 120.0);
+        assert_eq!(vw(10).resolve(viewport), 120.0);
+        assert_eq!(
+            LayoutLength::<false, false>::from(vw(50.0)).resolve_abs(100.0, viewport),
+            Some(600.0)
+        );
+    }
+
+    #[test]
+    fn combines_viewport_units() {
+        let viewport = Size::from_values(1080.0, 1920.0);
+        let x = Calc::max(vw(100.0) - 80, calc(pct(50.0)) + vmin(5.0));
+        assert_eq!(x.resolve(100.0, viewport), 1000.0);
+        assert_eq!(x.resolve(2000.0, viewport), 1054.0);
+    }
+
+    #[test]
+    fn renders_viewport_units() {
+        assert_eq!(vw(4.0).to_string(), "4vw");
+        assert_eq!(LayoutLen::from(vw(4.0)).to_string(), "calc(4vw)");
+        assert_eq!(
+            LayoutLen::from(vmin(5) + vh(2.5) - vmax(1)).to_string(),
+            "calc(5vmin + 2.5vh - 1vmax)"
+        );
+    }
 }
@@ -1,6 +1,8 @@
 use crate::{
     paint::{
         IntoResources,
+        IriNamespace,
+        NamespacedDisplay,
         Resource,
         ResourceIri,
     },
@@ -293,11 +295,11 @@ impl IntoResources for LinearGradient {
     }
 }
 
-impl Display for LinearGradient {
+impl NamespacedDisplay for LinearGradient {
     // noinspection DuplicatedCode (used by radial gradient too)
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         let gradient = ElementWriter::new(f, "linearGradient")?
-            .attr("id", (self.iri(),))?
+            .attr("id", (namespace.iri(self.iri()),))?
             .attr_if("x1", self.x1, !self.x1.is_zero())?
             .attr_if("y1", self.y1, !self.y1.is_zero())?
             .attr_if("x2", self.x2, self.x2 != GradientUnit::percent(100.0))?
@@ -328,13 +330,16 @@ impl Display for LinearGradient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
 
     #[test]
     fn renders_default_gradient() {
         let lg = LinearGradient::new();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" />"#, lg.iri()),
         );
     }
@@ -343,7 +348,7 @@ mod tests {
     fn renders_to_top() {
         let lg = LinearGradient::top();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" y1="100%" x2="0" />"#, lg.iri()),
         );
     }
@@ -352,7 +357,7 @@ mod tests {
     fn renders_to_right() {
         let lg = LinearGradient::right();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" />"#, lg.iri()),
         );
     }
@@ -361,7 +366,7 @@ mod tests {
     fn renders_to_bottom() {
         let lg = LinearGradient::bottom();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" x2="0" y2="100%" />"#, lg.iri()),
         );
     }
@@ -370,7 +375,7 @@ mod tests {
     fn renders_to_left() {
         let lg = LinearGradient::left();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" x1="100%" x2="0" />"#, lg.iri()),
         );
     }
@@ -379,7 +384,7 @@ mod tests {
     fn renders_to_top_left() {
         let lg = LinearGradient::top_left();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(
                 r#"<linearGradient id="{}" x1="100%" y1="100%" x2="0" />"#,
                 lg.iri()
@@ -391,7 +396,7 @@ mod tests {
     fn renders_to_top_right() {
         let lg = LinearGradient::top_right();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" y1="100%" />"#, lg.iri()),
         );
     }
@@ -400,7 +405,7 @@ mod tests {
     fn renders_to_bottom_left() {
         let lg = LinearGradient::bottom_left();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(
                 r#"<linearGradient id="{}" x1="100%" x2="0" y2="100%" />"#,
                 lg.iri()
@@ -412,7 +417,7 @@ mod tests {
     fn renders_to_bottom_right() {
         let lg = LinearGradient::bottom_right();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" y2="100%" />"#, lg.iri()),
         );
     }
@@ -421,7 +426,7 @@ mod tests {
     fn renders_with_angle() {
         let lg = LinearGradient::angle(90.0);
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" y1="50%" y2="50%" />"#, lg.iri()),
         );
     }
@@ -430,7 +435,7 @@ mod tests {
     fn self_closes_when_no_stops() {
         let lg = LinearGradient::new();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<linearGradient id="{}" />"#, lg.iri()),
         );
     }
@@ -442,7 +447,7 @@ mod tests {
             .stop(Stop::new().offset(1.0).color("#fff"));
 
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(
                 r#"
 <linearGradient id="{}">
@@ -466,7 +471,7 @@ mod tests {
             .color_interpolation(color_interpolation);
 
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(
                 r#"
 <linearGradient
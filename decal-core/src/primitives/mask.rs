@@ -1,15 +1,16 @@
 use crate::{
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::MaskType,
     utils::{
         ElementWriter,
         IsDefault,
     },
 };
-use std::fmt::{
-    Display,
-    Formatter,
-};
+use std::fmt::Formatter;
 
 /// The SVG mask element.
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Default)]
@@ -55,10 +56,10 @@ impl Mask {
 
 impl ResourceIri for Mask {}
 
-impl Display for Mask {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Mask {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         let mask = ElementWriter::new(f, "mask")?
-            .attr("id", (self.iri(),))?
+            .attr("id", (namespace.iri(self.iri()),))?
             .attr_if("mask-type", (self.r#type,), !self.r#type.is_default())?;
 
         if self.content.is_empty() {
@@ -73,14 +74,17 @@ impl Display for Mask {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
     use std::fmt::Write;
 
     #[test]
     fn renders() {
         let mask = Mask::build(|out| out.write_str("content")).unwrap();
         assert_xml(
-            mask.to_string(),
+            render(&mask),
             format!(r#"<mask id="{}">content</mask>"#, mask.iri()),
         );
     }
@@ -88,14 +92,14 @@ mod tests {
     #[test]
     fn renders_without_content() {
         let mask = Mask::build(|_| Ok(())).unwrap();
-        assert_xml(mask.to_string(), format!(r#"<mask id="{}" />"#, mask.iri()));
+        assert_xml(render(&mask), format!(r#"<mask id="{}" />"#, mask.iri()));
     }
 
     #[test]
     fn renders_with_mask_type() {
         let mask = Mask::build(|_| Ok(())).unwrap().r#type(MaskType::Alpha);
         assert_xml(
-            mask.to_string(),
+            render(&mask),
             format!(
                 r#"<mask id="{}" mask-type="{}" />"#,
                 mask.iri(),
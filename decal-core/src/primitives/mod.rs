@@ -1,4 +1,5 @@
 mod alignment;
+mod available_space;
 mod blend_mode;
 mod clip_path;
 mod color;
@@ -8,11 +9,14 @@ mod cross_origin;
 mod display;
 mod edge_mode;
 mod ellipsize;
+mod fill_rule;
 mod filter_input;
 mod filter_units;
 mod flex_direction;
 mod flex_wrap;
+mod font_feature;
 mod font_style;
+mod font_variation;
 mod font_weight;
 mod gradient_units;
 mod length;
@@ -20,10 +24,13 @@ mod light_source;
 mod linear_gradient;
 mod mask;
 mod mask_type;
+mod object_fit;
+mod object_position;
 mod overflow;
 mod paint;
 mod paint_transform;
 mod path;
+mod path_data;
 mod pattern;
 mod pattern_content_units;
 mod pattern_units;
@@ -37,12 +44,17 @@ mod rect;
 mod size;
 mod spread_method;
 mod stop;
+mod stroke_line_cap;
+mod stroke_line_join;
 mod text_align;
+mod text_decoration;
+mod text_spacing;
 mod text_wrap;
 mod transform;
 mod view_box;
 
 pub use alignment::*;
+pub use available_space::*;
 pub use blend_mode::*;
 pub use color::*;
 pub use color_interpolation::*;
@@ -51,19 +63,25 @@ pub use cross_origin::*;
 pub use display::*;
 pub use edge_mode::*;
 pub use ellipsize::*;
+pub use fill_rule::*;
 pub use filter_input::*;
 pub use filter_units::*;
 pub use flex_direction::*;
 pub use flex_wrap::*;
+pub use font_feature::*;
 pub use font_style::*;
+pub use font_variation::*;
 pub use font_weight::*;
 pub use gradient_units::*;
 pub use length::*;
 pub use light_source::*;
 pub use linear_gradient::*;
+pub use object_fit::*;
+pub use object_position::*;
 pub use overflow::*;
 pub use paint::*;
 pub use paint_transform::*;
+pub use path_data::*;
 pub use pattern::*;
 pub use pattern_content_units::*;
 pub use pattern_units::*;
@@ -77,7 +95,11 @@ pub use rect::*;
 pub use size::*;
 pub use spread_method::*;
 pub use stop::*;
+pub use stroke_line_cap::*;
+pub use stroke_line_join::*;
 pub use text_align::*;
+pub use text_decoration::*;
+pub use text_spacing::*;
 pub use text_wrap::*;
 pub use transform::*;
 pub use view_box::*;
@@ -91,3 +113,5 @@ pub(crate) use path::*;
 pub use color::helpers::*;
 #[cfg(feature = "helpers")]
 pub use length::helpers::*;
+#[cfg(feature = "helpers")]
+pub use text_spacing::helpers::*;
@@ -0,0 +1,26 @@
+/// Determines how the content of an image is resized to fit its layout box.
+///
+/// # Reference
+///
+/// https://developer.mozilla.org/en-US/docs/Web/CSS/object-fit
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone, Default)]
+pub enum ObjectFit {
+    /// Stretches the image to fill the layout box, ignoring its aspect ratio.
+    #[default]
+    Fill,
+    /// Scales the image to fit entirely within the layout box while preserving
+    /// its aspect ratio.
+    Contain,
+    /// Scales the image to cover the layout box while preserving its aspect
+    /// ratio. Overflowing parts of the image are clipped.
+    Cover,
+    /// Renders the image at its intrinsic size. Overflowing parts of the image
+    /// are clipped.
+    None,
+    /// Behaves like [`None`] or [`Contain`], whichever results in a smaller
+    /// image.
+    ///
+    /// [`None`]: Self::None
+    /// [`Contain`]: Self::Contain
+    ScaleDown,
+}
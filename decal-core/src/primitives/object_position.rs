@@ -0,0 +1,51 @@
+use crate::primitives::PreserveAspectRatio;
+
+/// Specifies the alignment of the content of an image within its layout box.
+///
+/// # Reference
+///
+/// https://developer.mozilla.org/en-US/docs/Web/CSS/object-position
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone, Default)]
+pub enum ObjectPosition {
+    /// Aligns the image to the top-left corner.
+    TopLeft,
+    /// Aligns the image to the top edge, centered horizontally.
+    Top,
+    /// Aligns the image to the top-right corner.
+    TopRight,
+    /// Aligns the image to the left edge, centered vertically.
+    Left,
+    /// Centers the image on both axes.
+    #[default]
+    Center,
+    /// Aligns the image to the right edge, centered vertically.
+    Right,
+    /// Aligns the image to the bottom-left corner.
+    BottomLeft,
+    /// Aligns the image to the bottom edge, centered horizontally.
+    Bottom,
+    /// Aligns the image to the bottom-right corner.
+    BottomRight,
+}
+
+impl ObjectPosition {
+    /// Returns the [`PreserveAspectRatio`] alignment matching this position.
+    ///
+    /// # Returns
+    /// - [`PreserveAspectRatio`]
+    pub(crate) fn preserve_aspect_ratio(self) -> PreserveAspectRatio {
+        let value = PreserveAspectRatio::new();
+
+        match self {
+            ObjectPosition::TopLeft => value.x_min_y_min(),
+            ObjectPosition::Top => value.x_mid_y_min(),
+            ObjectPosition::TopRight => value.x_max_y_min(),
+            ObjectPosition::Left => value.x_min_y_mid(),
+            ObjectPosition::Center => value.x_mid_y_mid(),
+            ObjectPosition::Right => value.x_max_y_mid(),
+            ObjectPosition::BottomLeft => value.x_min_y_max(),
+            ObjectPosition::Bottom => value.x_mid_y_max(),
+            ObjectPosition::BottomRight => value.x_max_y_max(),
+        }
+    }
+}
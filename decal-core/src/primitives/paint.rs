@@ -6,6 +6,8 @@ use crate::{
     macros::nf32,
     paint::{
         IntoResources,
+        IriNamespace,
+        NamespacedDisplay,
         Resource,
         ResourceIri,
     },
@@ -30,6 +32,7 @@ use crate::{
 use smart_default::SmartDefault;
 use std::fmt::{
     Display,
+    Formatter,
     Write,
 };
 use strict_num::NormalizedF32;
@@ -122,18 +125,18 @@ impl Paint {
     }
 }
 
-impl Display for Paint {
+impl NamespacedDisplay for Paint {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match &self.0 {
-            PaintInner::None => f.write_str("none"),
-            PaintInner::Color(color) => color.fmt(f),
-            PaintInner::LinearGradient(gradient) => write!(f, "url(#{})", gradient.iri()),
-            PaintInner::RadialGradient(gradient) => write!(f, "url(#{})", gradient.iri()),
-            PaintInner::Image(pattern) | PaintInner::Pattern(pattern) => {
-                write!(f, "url(#{})", pattern.iri())
-            }
-        }
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
+        let iri = match &self.0 {
+            PaintInner::None => return f.write_str("none"),
+            PaintInner::Color(color) => return color.fmt(f),
+            PaintInner::LinearGradient(gradient) => gradient.iri(),
+            PaintInner::RadialGradient(gradient) => gradient.iri(),
+            PaintInner::Image(pattern) | PaintInner::Pattern(pattern) => pattern.iri(),
+        };
+
+        write!(f, "url(#{})", namespace.iri(iri))
     }
 }
 
@@ -465,6 +468,21 @@ impl PaintStack {
         self.0.is_empty() || (self.0.len() == 1 && self.0[0].is_none())
     }
 
+    /// Returns the color of a paint stack made of a single, fully opaque solid
+    /// color layer with the default blending.
+    pub(crate) fn solid_color(&self) -> Option<Color> {
+        match self.0.as_slice() {
+            [
+                PaintLayer {
+                    paint: Paint(PaintInner::Color(color)),
+                    blend_mode,
+                    opacity,
+                },
+            ] if blend_mode.is_default() && *opacity == NormalizedF32::ONE => Some(*color),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if the paint stack requires isolated blending.
     ///
     /// Isolation is needed when any layer uses a non-default [`BlendMode`],
@@ -485,9 +503,94 @@ impl PaintStack {
     /// - `visit_layer`: The visitor invoked for each rendered paint layer.
     /// - `visit_group`: The visitor invoked for the enclosing group when
     ///   multiple layers are present.
-    pub(crate) fn render<'a, W, D, S, L, G>(
+    pub(crate) fn render<W, D, S, L, G>(
+        &self,
+        ctx: &mut RenderContext<W>,
+        draw_single_layer: D,
+        draw_cached_layer: S,
+        visit_layer: L,
+        visit_group: G,
+    ) -> std::fmt::Result
+    where
+        W: Write,
+        D: FnOnce(&mut W) -> std::fmt::Result,
+        S: FnOnce(&mut String) -> std::fmt::Result,
+        L: Fn(
+            ElementWriter<W, Initialized>,
+            bool,
+        ) -> Result<ElementWriter<W, Initialized>, std::fmt::Error>,
+        G: Fn(
+            ElementWriter<W, Initialized>,
+        ) -> Result<ElementWriter<W, Initialized>, std::fmt::Error>,
+    {
+        self.render_as(
+            ctx,
+            ("fill", "fill-opacity"),
+            draw_single_layer,
+            draw_cached_layer,
+            visit_layer,
+            visit_group,
+        )
+    }
+
+    /// Renders the paint stack as the stroke of the drawn geometry.
+    ///
+    /// # Arguments
+    /// - `ctx`: The current [`RenderContext`].
+    /// - `draw_single_layer`: The closure used to write path data for a single
+    ///   layer.
+    /// - `draw_cached_layer`: The closure used to generate reusable path
+    ///   geometry.
+    /// - `visit_layer`: The visitor invoked for each rendered paint layer.
+    /// - `visit_group`: The visitor invoked for the enclosing group when
+    ///   multiple layers are present.
+    pub(crate) fn render_stroke<W, D, S, L, G>(
+        &self,
+        ctx: &mut RenderContext<W>,
+        draw_single_layer: D,
+        draw_cached_layer: S,
+        visit_layer: L,
+        visit_group: G,
+    ) -> std::fmt::Result
+    where
+        W: Write,
+        D: FnOnce(&mut W) -> std::fmt::Result,
+        S: FnOnce(&mut String) -> std::fmt::Result,
+        L: Fn(
+            ElementWriter<W, Initialized>,
+            bool,
+        ) -> Result<ElementWriter<W, Initialized>, std::fmt::Error>,
+        G: Fn(
+            ElementWriter<W, Initialized>,
+        ) -> Result<ElementWriter<W, Initialized>, std::fmt::Error>,
+    {
+        self.render_as(
+            ctx,
+            ("stroke", "stroke-opacity"),
+            draw_single_layer,
+            draw_cached_layer,
+            visit_layer,
+            visit_group,
+        )
+    }
+
+    /// Renders the paint stack using the provided paint attributes.
+    ///
+    /// # Arguments
+    /// - `ctx`: The current [`RenderContext`].
+    /// - `(paint_attr, opacity_attr)`: The names of the paint and opacity
+    ///   attributes written for each layer.
+    /// - `draw_single_layer`: The closure used to write path data for a single
+    ///   layer.
+    /// - `draw_cached_layer`: The closure used to generate reusable path
+    ///   geometry.
+    /// - `visit_layer`: The visitor invoked for each rendered paint layer.
+    /// - `visit_group`: The visitor invoked for the enclosing group when
+    ///   multiple layers are present.
+    fn render_as<W, D, S, L, G>(
         &self,
         ctx: &mut RenderContext<W>,
+        (paint_attr, opacity_attr): (&str, &str),
         draw_single_layer: D,
         draw_cached_layer: S,
         visit_layer: L,
@@ -514,9 +617,9 @@ impl PaintStack {
             |element: ElementWriter<W, Initialized>, layer: &PaintLayer, cached: bool| {
                 visit_layer(
                     element
-                        .attr("fill", (&layer.paint,))?
+                        .attr(paint_attr, (layer.paint.display_in(&ctx.namespace),))?
                         .attr_if(
-                            "fill-opacity",
+                            opacity_attr,
                             layer.opacity,
                             layer.opacity != NormalizedF32::ONE,
                         )?
@@ -541,7 +644,7 @@ impl PaintStack {
         }
 
         let path = Path::build(draw_cached_layer)?;
-        let href = format_args!("#{}", path.iri());
+        let href = format_args!("#{}", ctx.namespace.iri(path.iri()));
         ctx.scene.resources.lock().get_or_add_resource(path.into());
 
         visit_group(ElementWriter::new(ctx.out, "g")?.attr_if(
@@ -578,15 +681,17 @@ impl IntoResources for PaintStack {
 mod tests {
     use super::*;
     use crate::{
-        layout::Scene
-        ,
-        test_utils::assert_xml,
+        layout::Scene,
+        test_utils::{
+            assert_xml,
+            render,
+        },
     };
     use std::fmt::Write;
 
     #[test]
     fn renders_none() {
-        assert_eq!(Paint::none().to_string(), "none");
+        assert_eq!(render(&Paint::none()), "none");
     }
 
     #[test]
@@ -597,14 +702,14 @@ mod tests {
 
     #[test]
     fn renders_color_paint() {
-        assert_eq!(Paint::color(Color::rgb(1, 2, 3)).to_string(), "rgb(1,2,3)");
+        assert_eq!(render(&Paint::color(Color::rgb(1, 2, 3))), "rgb(1,2,3)");
     }
 
     #[test]
     fn renders_linear_gradient_paint() {
         let lg = LinearGradient::new();
         assert_eq!(
-            Paint::linear_gradient(lg.clone()).to_string(),
+            render(&Paint::linear_gradient(lg.clone())),
             format!(r#"url(#{})"#, lg.iri())
         );
     }
@@ -613,7 +718,7 @@ mod tests {
     fn renders_radial_gradient_paint() {
         let rg = RadialGradient::new();
         assert_eq!(
-            Paint::radial_gradient(rg.clone()).to_string(),
+            render(&Paint::radial_gradient(rg.clone())),
             format!(r#"url(#{})"#, rg.iri())
         );
     }
@@ -624,12 +729,12 @@ mod tests {
         let pattern = img.clone().into_pattern().unwrap();
 
         assert_eq!(
-            Paint::image(img).to_string(),
+            render(&Paint::image(img)),
             format!(r#"url(#{})"#, pattern.iri())
         );
 
         assert_xml(
-            pattern.to_string(),
+            render(&pattern),
             format!(
                 r#"
 <pattern id="{}" width="1" height="1" patternContentUnits="objectBoundingBox">
@@ -1,11 +1,12 @@
 use crate::{
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     utils::ElementWriter,
 };
-use std::fmt::{
-    Display,
-    Formatter,
-};
+use std::fmt::Formatter;
 
 /// The SVG path element.
 #[derive(Debug, Hash, Eq, PartialEq, Clone, Default)]
@@ -32,10 +33,10 @@ impl Path {
 
 impl ResourceIri for Path {}
 
-impl Display for Path {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Path {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "path")?
-            .attr("id", (self.iri(),))?
+            .attr("id", (namespace.iri(self.iri()),))?
             .attr("d", self.0.as_str())?
             .close()
     }
@@ -44,14 +45,17 @@ impl Display for Path {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
     use std::fmt::Write;
 
     #[test]
     fn renders() {
         let path = Path::build(|out| out.write_str("data")).unwrap();
         assert_xml(
-            path.to_string(),
+            render(&path),
             format!(r#"<path id="{}" d="data" />"#, path.iri()),
         );
     }
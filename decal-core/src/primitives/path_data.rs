@@ -0,0 +1,837 @@
+use crate::{
+    primitives::Point,
+    utils::PathSink,
+};
+use std::{
+    f32::consts::{
+        FRAC_PI_2,
+        PI,
+    },
+    fmt::{
+        Display,
+        Formatter,
+    },
+    str::FromStr,
+};
+use svgtypes::{
+    PathParser,
+    PathSegment,
+};
+use thiserror::Error;
+
+/// The error that may occur while parsing SVG path data.
+#[derive(Debug, Error, PartialEq)]
+pub enum PathDataError {
+    #[error("path data must start with a move-to command")]
+    MissingMoveTo,
+    #[error("failed to parse path data: {0}")]
+    Parse(#[from] svgtypes::Error),
+}
+
+/// A single path command with absolute coordinates.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum PathCommand {
+    MoveTo(Point<f32>),
+    LineTo(Point<f32>),
+    QuadTo(Point<f32>, Point<f32>),
+    CubicTo(Point<f32>, Point<f32>, Point<f32>),
+    ArcTo {
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Point<f32>,
+    },
+    Close,
+}
+
+/// The geometry of a path, built from drawing commands or parsed from an SVG
+/// `d` attribute.
+///
+/// Relative commands are resolved against the current point when they are
+/// added, so the path always stores absolute coordinates.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PathData {
+    commands: Vec<PathCommand>,
+    current: Point<f32>,
+    start: Point<f32>,
+}
+
+impl PathData {
+    /// Creates an empty [`PathData`] instance.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses path data from the value of an SVG `d` attribute.
+    ///
+    /// # Arguments
+    /// - `d`: The SVG path data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use decal::prelude::*;
+    ///
+    /// let chevron = PathData::parse("M0 0 l5 5 -5 5").unwrap();
+    /// assert_eq!(chevron.to_string(), "M0 0 L5 5 L0 10 ");
+    /// ```
+    ///
+    /// # Returns
+    /// - [`Self`] on success.
+    /// - [`PathDataError`] if the path data is malformed.
+    pub fn parse(d: &str) -> Result<Self, PathDataError> {
+        PathParser::from(d).try_fold(Self::new(), |data, segment| match segment {
+            Ok(segment) => Ok(data.push_segment(segment)),
+            // the first segment is not a move-to command
+            Err(svgtypes::Error::UnexpectedData(_)) if data.is_empty() => {
+                Err(PathDataError::MissingMoveTo)
+            }
+            Err(err) => Err(err.into()),
+        })
+    }
+
+    /// Starts a new subpath at the given point.
+    ///
+    /// # Arguments
+    /// - `to`: The start point convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn move_to<P>(mut self, to: P) -> Self
+    where
+        P: Into<Point<f32>>,
+    {
+        let to = to.into();
+        self.commands.push(PathCommand::MoveTo(to));
+        self.current = to;
+        self.start = to;
+        self
+    }
+
+    /// Draws a straight line to the given point.
+    ///
+    /// # Arguments
+    /// - `to`: The end point convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn line_to<P>(mut self, to: P) -> Self
+    where
+        P: Into<Point<f32>>,
+    {
+        let to = to.into();
+        self.commands.push(PathCommand::LineTo(to));
+        self.current = to;
+        self
+    }
+
+    /// Draws a quadratic Bézier curve to the given point.
+    ///
+    /// # Arguments
+    /// - `ctrl`: The control point convertible into [`Point`].
+    /// - `to`: The end point convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn quad_to<C, P>(mut self, ctrl: C, to: P) -> Self
+    where
+        C: Into<Point<f32>>,
+        P: Into<Point<f32>>,
+    {
+        let to = to.into();
+        self.commands.push(PathCommand::QuadTo(ctrl.into(), to));
+        self.current = to;
+        self
+    }
+
+    /// Draws a cubic Bézier curve to the given point.
+    ///
+    /// # Arguments
+    /// - `ctrl1`: The first control point convertible into [`Point`].
+    /// - `ctrl2`: The second control point convertible into [`Point`].
+    /// - `to`: The end point convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn cubic_to<C1, C2, P>(mut self, ctrl1: C1, ctrl2: C2, to: P) -> Self
+    where
+        C1: Into<Point<f32>>,
+        C2: Into<Point<f32>>,
+        P: Into<Point<f32>>,
+    {
+        let to = to.into();
+        self.commands
+            .push(PathCommand::CubicTo(ctrl1.into(), ctrl2.into(), to));
+        self.current = to;
+        self
+    }
+
+    /// Draws an elliptical arc to the given point.
+    ///
+    /// # Arguments
+    /// - `rx`: The horizontal radius of the ellipse.
+    /// - `ry`: The vertical radius of the ellipse.
+    /// - `x_axis_rotation`: The rotation of the ellipse in degrees.
+    /// - `large_arc`: Whether the larger of the two possible arcs is drawn.
+    /// - `sweep`: Whether the arc is drawn in the positive-angle direction.
+    /// - `to`: The end point convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn arc_to<P>(
+        mut self,
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: P,
+    ) -> Self
+    where
+        P: Into<Point<f32>>,
+    {
+        let to = to.into();
+        self.commands.push(PathCommand::ArcTo {
+            rx: rx.abs(),
+            ry: ry.abs(),
+            x_axis_rotation,
+            large_arc,
+            sweep,
+            to,
+        });
+        self.current = to;
+        self
+    }
+
+    /// Closes the current subpath with a straight line to its start point.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn close(mut self) -> Self {
+        self.commands.push(PathCommand::Close);
+        self.current = self.start;
+        self
+    }
+
+    /// Starts a new subpath at an offset from the current point.
+    ///
+    /// # Arguments
+    /// - `by`: The offset convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn rel_move_to<P>(self, by: P) -> Self
+    where
+        P: Into<Point<f32>>,
+    {
+        let to = self.offset(by);
+        self.move_to(to)
+    }
+
+    /// Draws a straight line to an offset from the current point.
+    ///
+    /// # Arguments
+    /// - `by`: The offset convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn rel_line_to<P>(self, by: P) -> Self
+    where
+        P: Into<Point<f32>>,
+    {
+        let to = self.offset(by);
+        self.line_to(to)
+    }
+
+    /// Draws a quadratic Bézier curve with points relative to the current
+    /// point.
+    ///
+    /// # Arguments
+    /// - `ctrl`: The control point offset convertible into [`Point`].
+    /// - `to`: The end point offset convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn rel_quad_to<C, P>(self, ctrl: C, to: P) -> Self
+    where
+        C: Into<Point<f32>>,
+        P: Into<Point<f32>>,
+    {
+        let (ctrl, to) = (self.offset(ctrl), self.offset(to));
+        self.quad_to(ctrl, to)
+    }
+
+    /// Draws a cubic Bézier curve with points relative to the current point.
+    ///
+    /// # Arguments
+    /// - `ctrl1`: The first control point offset convertible into [`Point`].
+    /// - `ctrl2`: The second control point offset convertible into [`Point`].
+    /// - `to`: The end point offset convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn rel_cubic_to<C1, C2, P>(self, ctrl1: C1, ctrl2: C2, to: P) -> Self
+    where
+        C1: Into<Point<f32>>,
+        C2: Into<Point<f32>>,
+        P: Into<Point<f32>>,
+    {
+        let (ctrl1, ctrl2, to) = (self.offset(ctrl1), self.offset(ctrl2), self.offset(to));
+        self.cubic_to(ctrl1, ctrl2, to)
+    }
+
+    /// Draws an elliptical arc to an offset from the current point.
+    ///
+    /// # Arguments
+    /// - `rx`: The horizontal radius of the ellipse.
+    /// - `ry`: The vertical radius of the ellipse.
+    /// - `x_axis_rotation`: The rotation of the ellipse in degrees.
+    /// - `large_arc`: Whether the larger of the two possible arcs is drawn.
+    /// - `sweep`: Whether the arc is drawn in the positive-angle direction.
+    /// - `by`: The end point offset convertible into [`Point`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn rel_arc_to<P>(
+        self,
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        by: P,
+    ) -> Self
+    where
+        P: Into<Point<f32>>,
+    {
+        let to = self.offset(by);
+        self.arc_to(rx, ry, x_axis_rotation, large_arc, sweep, to)
+    }
+
+    /// Appends a parsed path segment, resolving relative coordinates and the
+    /// control points of smooth curves.
+    ///
+    /// # Arguments
+    /// - `segment`: The [`PathSegment`] to append.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn push_segment(self, segment: PathSegment) -> Self {
+        let origin = if segment.is_abs() {
+            Point::default()
+        } else {
+            self.current
+        };
+        let rel = |x: f64, y: f64| Point::from_values(origin.x + x as f32, origin.y + y as f32);
+
+        match segment {
+            PathSegment::MoveTo { x, y, .. } => self.move_to(rel(x, y)),
+            PathSegment::LineTo { x, y, .. } => self.line_to(rel(x, y)),
+            PathSegment::HorizontalLineTo { x, .. } => {
+                let y = self.current.y;
+                self.line_to((origin.x + x as f32, y))
+            }
+            PathSegment::VerticalLineTo { y, .. } => {
+                let x = self.current.x;
+                self.line_to((x, origin.y + y as f32))
+            }
+            PathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+                ..
+            } => self.cubic_to(rel(x1, y1), rel(x2, y2), rel(x, y)),
+            PathSegment::SmoothCurveTo { x2, y2, x, y, .. } => {
+                let ctrl1 = match self.commands.last() {
+                    Some(PathCommand::CubicTo(_, ctrl2, _)) => reflect(*ctrl2, self.current),
+                    _ => self.current,
+                };
+                self.cubic_to(ctrl1, rel(x2, y2), rel(x, y))
+            }
+            PathSegment::Quadratic { x1, y1, x, y, .. } => self.quad_to(rel(x1, y1), rel(x, y)),
+            PathSegment::SmoothQuadratic { x, y, .. } => {
+                let ctrl = match self.commands.last() {
+                    Some(PathCommand::QuadTo(ctrl, _)) => reflect(*ctrl, self.current),
+                    _ => self.current,
+                };
+                self.quad_to(ctrl, rel(x, y))
+            }
+            PathSegment::EllipticalArc {
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x,
+                y,
+                ..
+            } => self.arc_to(
+                rx as f32,
+                ry as f32,
+                x_axis_rotation as f32,
+                large_arc,
+                sweep,
+                rel(x, y),
+            ),
+            PathSegment::ClosePath { .. } => self.close(),
+        }
+    }
+
+    /// Returns `true` if the path has no commands.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Resolves an offset against the current point.
+    fn offset<P>(&self, by: P) -> Point<f32>
+    where
+        P: Into<Point<f32>>,
+    {
+        let by = by.into();
+        Point::from_values(self.current.x + by.x, self.current.y + by.y)
+    }
+
+    /// Computes the tight bounding box of the path.
+    ///
+    /// # Returns
+    /// - The `(min_x, min_y, max_x, max_y)` bounds, or `None` if the path is
+    ///   empty.
+    pub(crate) fn bounds(&self) -> Option<(f32, f32, f32, f32)> {
+        let mut bounds: Option<(f32, f32, f32, f32)> = None;
+        let mut include = |p: Point<f32>| {
+            bounds = Some(match bounds {
+                Some((x0, y0, x1, y1)) => (x0.min(p.x), y0.min(p.y), x1.max(p.x), y1.max(p.y)),
+                None => (p.x, p.y, p.x, p.y),
+            });
+        };
+
+        self.for_each_segment(|from, segment| match segment {
+            Segment::Point(p) => include(p),
+            Segment::Cubic(c1, c2, to) => {
+                include(to);
+                cubic_extrema(from, c1, c2, to)
+                    .into_iter()
+                    .for_each(&mut include);
+            }
+        });
+
+        bounds
+    }
+
+    /// Writes the path data, mapping every point through a scale and
+    /// translation.
+    ///
+    /// Arcs are kept as arcs under uniform scaling and converted into cubic
+    /// Bézier curves otherwise.
+    ///
+    /// # Arguments
+    /// - `out`: The output sink for the generated path commands.
+    /// - `(sx, sy, tx, ty)`: The scale and translation applied to the points.
+    pub(crate) fn write_transformed<T>(
+        &self,
+        out: &mut T,
+        (sx, sy, tx, ty): (f32, f32, f32, f32),
+    ) -> std::fmt::Result
+    where
+        T: PathSink,
+    {
+        let map = |p: Point<f32>| (p.x * sx + tx, p.y * sy + ty);
+        let uniform = (sx - sy).abs() <= f32::EPSILON * sx.abs().max(sy.abs());
+        let mut current = Point::default();
+        let mut start = Point::default();
+
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(to) => {
+                    let (x, y) = map(to);
+                    out.move_to(x, y)?;
+                    (current, start) = (to, to);
+                }
+                PathCommand::LineTo(to) => {
+                    let (x, y) = map(to);
+                    out.line_to(x, y)?;
+                    current = to;
+                }
+                PathCommand::QuadTo(ctrl, to) => {
+                    let ((cx, cy), (x, y)) = (map(ctrl), map(to));
+                    out.quad_to(cx, cy, x, y)?;
+                    current = to;
+                }
+                PathCommand::CubicTo(ctrl1, ctrl2, to) => {
+                    let ((cx1, cy1), (cx2, cy2), (x, y)) = (map(ctrl1), map(ctrl2), map(to));
+                    out.curve_to(cx1, cy1, cx2, cy2, x, y)?;
+                    current = to;
+                }
+                PathCommand::ArcTo {
+                    rx,
+                    ry,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    if uniform {
+                        let (x, y) = map(to);
+                        out.elliptical_arc_to(
+                            rx * sx.abs(),
+                            ry * sx.abs(),
+                            x_axis_rotation,
+                            (large_arc, sweep),
+                            x,
+                            y,
+                        )?;
+                    } else {
+                        let arc = (rx, ry, x_axis_rotation, large_arc, sweep);
+                        for (ctrl1, ctrl2, end) in arc_to_cubics(current, arc, to) {
+                            let ((cx1, cy1), (cx2, cy2), (x, y)) =
+                                (map(ctrl1), map(ctrl2), map(end));
+                            out.curve_to(cx1, cy1, cx2, cy2, x, y)?;
+                        }
+                    }
+
+                    current = to;
+                }
+                PathCommand::Close => {
+                    out.close()?;
+                    current = start;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Visits every segment of the path, with quadratic curves and arcs
+    /// converted into cubic Bézier curves.
+    fn for_each_segment<F>(&self, mut visit: F)
+    where
+        F: FnMut(Point<f32>, Segment),
+    {
+        let mut current = Point::default();
+        let mut start = Point::default();
+
+        for command in &self.commands {
+            match *command {
+                PathCommand::MoveTo(to) => {
+                    visit(current, Segment::Point(to));
+                    (current, start) = (to, to);
+                }
+                PathCommand::LineTo(to) => {
+                    visit(current, Segment::Point(to));
+                    current = to;
+                }
+                PathCommand::QuadTo(ctrl, to) => {
+                    let (ctrl1, ctrl2) = quad_to_cubic(current, ctrl, to);
+                    visit(current, Segment::Cubic(ctrl1, ctrl2, to));
+                    current = to;
+                }
+                PathCommand::CubicTo(ctrl1, ctrl2, to) => {
+                    visit(current, Segment::Cubic(ctrl1, ctrl2, to));
+                    current = to;
+                }
+                PathCommand::ArcTo {
+                    rx,
+                    ry,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                    to,
+                } => {
+                    let arc = (rx, ry, x_axis_rotation, large_arc, sweep);
+                    let mut from = current;
+
+                    for (ctrl1, ctrl2, end) in arc_to_cubics(current, arc, to) {
+                        visit(from, Segment::Cubic(ctrl1, ctrl2, end));
+                        from = end;
+                    }
+
+                    visit(from, Segment::Point(to));
+                    current = to;
+                }
+                PathCommand::Close => current = start,
+            }
+        }
+    }
+}
+
+impl Display for PathData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.write_transformed(f, (1.0, 1.0, 0.0, 0.0))
+    }
+}
+
+impl FromStr for PathData {
+    type Err = PathDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// A path segment normalized for geometric computations.
+enum Segment {
+    Point(Point<f32>),
+    Cubic(Point<f32>, Point<f32>, Point<f32>),
+}
+
+/// Elevates a quadratic Bézier curve to a cubic one.
+///
+/// # Returns
+/// - The two control points of the equivalent cubic curve.
+fn quad_to_cubic(from: Point<f32>, ctrl: Point<f32>, to: Point<f32>) -> (Point<f32>, Point<f32>) {
+    (
+        Point::from_values(
+            from.x + 2.0 / 3.0 * (ctrl.x - from.x),
+            from.y + 2.0 / 3.0 * (ctrl.y - from.y),
+        ),
+        Point::from_values(
+            to.x + 2.0 / 3.0 * (ctrl.x - to.x),
+            to.y + 2.0 / 3.0 * (ctrl.y - to.y),
+        ),
+    )
+}
+
+/// Computes the points where a cubic Bézier curve reaches a local extremum
+/// along either axis.
+fn cubic_extrema(
+    p0: Point<f32>,
+    p1: Point<f32>,
+    p2: Point<f32>,
+    p3: Point<f32>,
+) -> Vec<Point<f32>> {
+    let eval = |t: f32, a: f32, b: f32, c: f32, d: f32| {
+        let mt = 1.0 - t;
+        mt * mt * mt * a + 3.0 * mt * mt * t * b + 3.0 * mt * t * t * c + t * t * t * d
+    };
+
+    let roots = |a: f32, b: f32, c: f32, d: f32| {
+        // derivative coefficients divided by 3
+        let qa = d - 3.0 * c + 3.0 * b - a;
+        let qb = 2.0 * (c - 2.0 * b + a);
+        let qc = b - a;
+        let mut out = Vec::with_capacity(2);
+
+        if qa.abs() <= f32::EPSILON {
+            if qb.abs() > f32::EPSILON {
+                out.push(-qc / qb);
+            }
+        } else {
+            let disc = qb * qb - 4.0 * qa * qc;
+
+            if disc >= 0.0 {
+                let sqrt = disc.sqrt();
+                out.push((-qb + sqrt) / (2.0 * qa));
+                out.push((-qb - sqrt) / (2.0 * qa));
+            }
+        }
+
+        out.into_iter().filter(|t| *t > 0.0 && *t < 1.0)
+    };
+
+    roots(p0.x, p1.x, p2.x, p3.x)
+        .chain(roots(p0.y, p1.y, p2.y, p3.y))
+        .map(|t| {
+            Point::from_values(
+                eval(t, p0.x, p1.x, p2.x, p3.x),
+                eval(t, p0.y, p1.y, p2.y, p3.y),
+            )
+        })
+        .collect()
+}
+
+/// Approximates an elliptical arc with cubic Bézier curves.
+///
+/// # Arguments
+/// - `from`: The start point of the arc.
+/// - `(rx, ry, x_axis_rotation, large_arc, sweep)`: The arc parameters.
+/// - `to`: The end point of the arc.
+///
+/// # Returns
+/// - The `(ctrl1, ctrl2, to)` points of each cubic curve, with at most a
+///   quarter turn per curve.
+fn arc_to_cubics(
+    from: Point<f32>,
+    (rx, ry, x_axis_rotation, large_arc, sweep): (f32, f32, f32, bool, bool),
+    to: Point<f32>,
+) -> Vec<(Point<f32>, Point<f32>, Point<f32>)> {
+    if from == to {
+        return Vec::new();
+    }
+
+    if rx == 0.0 || ry == 0.0 {
+        return vec![(from, to, to)];
+    }
+
+    let (sin, cos) = x_axis_rotation.to_radians().sin_cos();
+    let dx = (from.x - to.x) / 2.0;
+    let dy = (from.y - to.y) / 2.0;
+    let x1 = cos * dx + sin * dy;
+    let y1 = -sin * dx + cos * dy;
+
+    // scale up radii that are too small to reach the end point
+    let lambda = (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+    let (rx, ry) = if lambda > 1.0 {
+        (rx * lambda.sqrt(), ry * lambda.sqrt())
+    } else {
+        (rx, ry)
+    };
+
+    let num = rx * rx * ry * ry - rx * rx * y1 * y1 - ry * ry * x1 * x1;
+    let den = rx * rx * y1 * y1 + ry * ry * x1 * x1;
+    let coef = (num / den).max(0.0).sqrt() * if large_arc == sweep { -1.0 } else { 1.0 };
+    let cx1 = coef * rx * y1 / ry;
+    let cy1 = -coef * ry * x1 / rx;
+    let cx = cos * cx1 - sin * cy1 + (from.x + to.x) / 2.0;
+    let cy = sin * cx1 + cos * cy1 + (from.y + to.y) / 2.0;
+
+    let angle = |ux: f32, uy: f32, vx: f32, vy: f32| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+    let theta = angle(1.0, 0.0, (x1 - cx1) / rx, (y1 - cy1) / ry);
+    let mut delta = angle(
+        (x1 - cx1) / rx,
+        (y1 - cy1) / ry,
+        (-x1 - cx1) / rx,
+        (-y1 - cy1) / ry,
+    );
+
+    if !sweep && delta > 0.0 {
+        delta -= 2.0 * PI;
+    } else if sweep && delta < 0.0 {
+        delta += 2.0 * PI;
+    }
+
+    let segments = (delta.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+    let step = delta / segments as f32;
+    let k = 4.0 / 3.0 * (step / 4.0).tan();
+    let map = |ux: f32, uy: f32| {
+        Point::from_values(
+            cx + cos * rx * ux - sin * ry * uy,
+            cy + sin * rx * ux + cos * ry * uy,
+        )
+    };
+
+    (0..segments)
+        .map(|idx| {
+            let (sin1, cos1) = (theta + step * idx as f32).sin_cos();
+            let (sin2, cos2) = (theta + step * (idx + 1) as f32).sin_cos();
+            let end = if idx + 1 == segments {
+                to
+            } else {
+                map(cos2, sin2)
+            };
+
+            (
+                map(cos1 - k * sin1, sin1 + k * cos1),
+                map(cos2 + k * sin2, sin2 - k * cos2),
+                end,
+            )
+        })
+        .collect()
+}
+
+/// Reflects a control point about the current point.
+fn reflect(ctrl: Point<f32>, about: Point<f32>) -> Point<f32> {
+    Point::from_values(2.0 * about.x - ctrl.x, 2.0 * about.y - ctrl.y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::str_sink;
+
+    #[test]
+    fn builds_absolute_commands() {
+        let data = PathData::new()
+            .move_to((1.0, 2.0))
+            .rel_line_to((3.0, 4.0))
+            .rel_quad_to((1.0, 0.0), (2.0, 2.0))
+            .close()
+            .rel_move_to((1.0, 1.0));
+
+        assert_eq!(data.to_string(), "M1 2 L4 6 Q5 6 6 8 ZM2 3 ");
+    }
+
+    #[test]
+    fn parses_path_data() {
+        let data = PathData::parse("M10,10h5v-5H0V0z m1 1 2 2").unwrap();
+        assert_eq!(
+            data.to_string(),
+            "M10 10 L15 10 L15 5 L0 5 L0 0 ZM11 11 L13 13 "
+        );
+    }
+
+    #[test]
+    fn parses_compact_numbers_and_flags() {
+        let data = PathData::parse("M.5-.5a1 1 0 01.5.5l1e1-1E-1").unwrap();
+        assert_eq!(data.to_string(), "M0.5 -0.5 A1 1 0 0 1 1 0 L11 -0.1 ");
+    }
+
+    #[test]
+    fn parses_smooth_curves() {
+        let data = PathData::parse("M0 0 C0 1 1 1 1 0 S2 -1 2 0 Q3 1 4 0 T6 0").unwrap();
+        assert_eq!(
+            data.to_string(),
+            "M0 0 C0 1 1 1 1 0 C1 -1 2 -1 2 0 Q3 1 4 0 Q5 -1 6 0 "
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_path_data() {
+        assert_eq!(PathData::parse("L0 0"), Err(PathDataError::MissingMoveTo));
+        assert_eq!(
+            PathData::parse("M0 0 X"),
+            Err(PathDataError::Parse(svgtypes::Error::UnexpectedData(6)))
+        );
+        assert!(matches!(
+            PathData::parse("M0"),
+            Err(PathDataError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn computes_tight_bounds() {
+        let data =
+            PathData::new()
+                .move_to((0.0, 0.0))
+                .cubic_to((0.0, 10.0), (10.0, 10.0), (10.0, 0.0));
+
+        let (x0, y0, x1, y1) = data.bounds().unwrap();
+        assert_eq!((x0, y0, x1), (0.0, 0.0, 10.0));
+        assert!((y1 - 7.5).abs() < 1e-4);
+    }
+
+    #[test]
+    fn computes_arc_bounds() {
+        let data =
+            PathData::new()
+                .move_to((0.0, 0.0))
+                .arc_to(5.0, 5.0, 0.0, false, true, (10.0, 0.0));
+
+        let (_, y0, _, y1) = data.bounds().unwrap();
+        assert!((y0 + 5.0).abs() < 1e-3);
+        assert_eq!(y1, 0.0);
+    }
+
+    #[test]
+    fn converts_arcs_under_non_uniform_scale() {
+        let data =
+            PathData::new()
+                .move_to((0.0, 0.0))
+                .arc_to(5.0, 5.0, 0.0, false, true, (10.0, 0.0));
+
+        assert_eq!(
+            str_sink(|out| data.write_transformed(out, (2.0, 2.0, 0.0, 0.0))),
+            "M0 0 A10 10 0 0 1 20 0 "
+        );
+        assert!(
+            str_sink(|out| data.write_transformed(out, (2.0, 1.0, 0.0, 0.0))).starts_with("M0 0 C")
+        );
+    }
+}
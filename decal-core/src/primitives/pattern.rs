@@ -1,5 +1,9 @@
 use crate::{
-    paint::ResourceIri,
+    paint::{
+        IriNamespace,
+        NamespacedDisplay,
+        ResourceIri,
+    },
     primitives::{
         IntoOptionalLength,
         Length,
@@ -14,10 +18,7 @@ use crate::{
         IsDefault,
     },
 };
-use std::fmt::{
-    Display,
-    Formatter,
-};
+use std::fmt::Formatter;
 
 type PatternUnit = Length<false, true>;
 
@@ -206,10 +207,10 @@ impl Pattern {
 
 impl ResourceIri for Pattern {}
 
-impl Display for Pattern {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+impl NamespacedDisplay for Pattern {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         ElementWriter::new(f, "pattern")?
-            .attr("id", (self.iri(),))?
+            .attr("id", (namespace.iri(self.iri()),))?
             .attr("viewBox", self.view_box.map(|x| (x,)))?
             .attr_if("x", self.x, !self.x.is_zero())?
             .attr_if("y", self.y, !self.y.is_zero())?
@@ -239,7 +240,10 @@ impl Display for Pattern {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
     use std::fmt::Write;
 
     #[test]
@@ -249,7 +253,7 @@ mod tests {
             Pattern::build(|out| out.write_str("content")).unwrap(),
         ] {
             assert_xml(
-                pattern.to_string(),
+                render(&pattern),
                 format!(r#"<pattern id="{}">content</pattern>"#, pattern.iri()),
             );
         }
@@ -273,7 +277,7 @@ mod tests {
             .transform(PatternTransform::new().translate((1.0, 2.0)));
 
         assert_xml(
-            pattern.to_string(),
+            render(&pattern),
             format!(
                 r#"
 <pattern
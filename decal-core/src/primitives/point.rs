@@ -1,5 +1,5 @@
 /// The two-dimensional point defined by `x` and `y` coordinates.
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
 pub struct Point<T>
 where
     T: Copy,
@@ -27,3 +27,13 @@ where
         Self { x, y }
     }
 }
+
+impl<T> From<(T, T)> for Point<T>
+where
+    T: Copy,
+{
+    #[inline]
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
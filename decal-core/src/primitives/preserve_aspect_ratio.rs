@@ -170,6 +170,31 @@ impl PreserveAspectRatio {
         self.1 = MeetOrSlice::Slice;
         self
     }
+
+    /// Returns the horizontal and vertical alignment of the view box within
+    /// the viewport as fractions of the remaining space.
+    ///
+    /// # Returns
+    /// - `None` if the aspect ratio is not preserved.
+    pub(crate) fn alignment(&self) -> Option<(f32, f32)> {
+        match self.0 {
+            Align::None => None,
+            Align::XMinYMin => Some((0.0, 0.0)),
+            Align::XMidYMin => Some((0.5, 0.0)),
+            Align::XMaxYMin => Some((1.0, 0.0)),
+            Align::XMinYMid => Some((0.0, 0.5)),
+            Align::XMidYMid => Some((0.5, 0.5)),
+            Align::XMaxYMid => Some((1.0, 0.5)),
+            Align::XMinYMax => Some((0.0, 1.0)),
+            Align::XMidYMax => Some((0.5, 1.0)),
+            Align::XMaxYMax => Some((1.0, 1.0)),
+        }
+    }
+
+    /// Returns `true` if the view box is scaled to cover the viewport.
+    pub(crate) fn is_slice(&self) -> bool {
+        self.1 == MeetOrSlice::Slice
+    }
 }
 
 impl Display for PreserveAspectRatio {
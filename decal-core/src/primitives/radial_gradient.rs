@@ -1,6 +1,8 @@
 use crate::{
     paint::{
         IntoResources,
+        IriNamespace,
+        NamespacedDisplay,
         Resource,
         ResourceIri,
     },
@@ -238,11 +240,11 @@ impl IntoResources for RadialGradient {
     }
 }
 
-impl Display for RadialGradient {
+impl NamespacedDisplay for RadialGradient {
     // noinspection DuplicatedCode (used by linear gradient too)
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    fn fmt_in(&self, f: &mut Formatter<'_>, namespace: &IriNamespace) -> std::fmt::Result {
         let gradient = ElementWriter::new(f, "radialGradient")?
-            .attr("id", (self.iri(),))?
+            .attr("id", (namespace.iri(self.iri()),))?
             .attr_if("r", self.r, self.r != GradientUnit::percent(50.0))?
             .attr_if("cx", self.cx, self.cx != GradientUnit::percent(50.0))?
             .attr_if("cy", self.cy, self.cy != GradientUnit::percent(50.0))?
@@ -269,13 +271,16 @@ impl Display for RadialGradient {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::assert_xml;
+    use crate::test_utils::{
+        assert_xml,
+        render,
+    };
 
     #[test]
     fn renders_default_gradient() {
         let lg = RadialGradient::new();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<radialGradient id="{}" />"#, lg.iri()),
         );
     }
@@ -284,7 +289,7 @@ mod tests {
     fn self_closes_when_no_stops() {
         let lg = RadialGradient::new();
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(r#"<radialGradient id="{}" />"#, lg.iri()),
         );
     }
@@ -296,7 +301,7 @@ mod tests {
             .stop(Stop::new().offset(1.0).color("#fff"));
 
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(
                 r#"
 <radialGradient id="{}">
@@ -324,7 +329,7 @@ mod tests {
             .color_interpolation(color_interpolation);
 
         assert_xml(
-            lg.to_string(),
+            render(&lg),
             format!(
                 r#"
 <radialGradient
@@ -1,11 +1,11 @@
-use crate::primitives::Length;
+use crate::primitives::{
+    LayoutLength,
+    Length,
+};
 
 /// The rectangle defined by values for each edge.
 #[derive(Debug, Copy, Clone)]
-pub struct Rect<T>
-where
-    T: Copy,
-{
+pub struct Rect<T> {
     /// The top edge value.
     pub top: T,
     /// The right edge value.
@@ -16,10 +16,7 @@ where
     pub left: T,
 }
 
-impl<T> Rect<T>
-where
-    T: Copy,
-{
+impl<T> Rect<T> {
     /// Creates a new [`Rect`] instance.
     ///
     /// # Arguments
@@ -39,7 +36,12 @@ where
             left,
         }
     }
+}
 
+impl<T> Rect<T>
+where
+    T: Copy,
+{
     /// Returns the rectangle edges as a tuple in `top`, `right`, `bottom`,
     /// `left` order.
     #[must_use]
@@ -48,19 +50,19 @@ where
     }
 }
 
-impl<const AUTO: bool, const PERCENT: bool> Default for Rect<Length<AUTO, PERCENT>> {
+impl<const AUTO: bool, const PERCENT: bool> Default for Rect<LayoutLength<AUTO, PERCENT>> {
     fn default() -> Self {
         Self::from_values(
-            Length::zero(),
-            Length::zero(),
-            Length::zero(),
-            Length::zero(),
+            Length::zero().into(),
+            Length::zero().into(),
+            Length::zero().into(),
+            Length::zero().into(),
         )
     }
 }
 
 impl<const PERCENT: bool> Into<taffy::Rect<taffy::LengthPercentage>>
-    for Rect<Length<false, PERCENT>>
+    for Rect<LayoutLength<false, PERCENT>>
 {
     fn into(self) -> taffy::Rect<taffy::LengthPercentage> {
         taffy::Rect {
@@ -73,7 +75,7 @@ impl<const PERCENT: bool> Into<taffy::Rect<taffy::LengthPercentage>>
 }
 
 impl<const AUTO: bool, const PERCENT: bool> Into<taffy::Rect<taffy::LengthPercentageAuto>>
-    for Rect<Length<AUTO, PERCENT>>
+    for Rect<LayoutLength<AUTO, PERCENT>>
 {
     fn into(self) -> taffy::Rect<taffy::LengthPercentageAuto> {
         taffy::Rect {
@@ -1,21 +1,19 @@
-use crate::primitives::Length;
+use crate::primitives::{
+    AvailableSpace,
+    LayoutLength,
+    Length,
+};
 
 /// The two-dimensional size expressed as a `width` and `height` pair.
-#[derive(Debug, Copy, Clone)]
-pub struct Size<T>
-where
-    T: Copy,
-{
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Size<T> {
     /// The width value.
     pub width: T,
     /// The height value.
     pub height: T,
 }
 
-impl<T> Size<T>
-where
-    T: Copy,
-{
+impl<T> Size<T> {
     /// Creates a new [`Size`] instance.
     ///
     /// # Arguments
@@ -28,7 +26,12 @@ where
     pub const fn from_values(width: T, height: T) -> Self {
         Self { width, height }
     }
+}
 
+impl<T> Size<T>
+where
+    T: Copy,
+{
     /// Returns the width component of the size.
     pub fn width(&self) -> T {
         self.width
@@ -40,9 +43,21 @@ where
     }
 }
 
-impl<const AUTO: bool, const PERCENT: bool> Default for Size<Length<AUTO, PERCENT>> {
+impl Default for Size<f32> {
     fn default() -> Self {
-        Self::from_values(Length::zero(), Length::zero())
+        Self::from_values(0.0, 0.0)
+    }
+}
+
+impl Default for Size<AvailableSpace> {
+    fn default() -> Self {
+        Self::from_values(AvailableSpace::MaxContent, AvailableSpace::MaxContent)
+    }
+}
+
+impl<const AUTO: bool, const PERCENT: bool> Default for Size<LayoutLength<AUTO, PERCENT>> {
+    fn default() -> Self {
+        Self::from_values(Length::zero().into(), Length::zero().into())
     }
 }
 
@@ -58,10 +73,10 @@ where
     }
 }
 
-impl<const AUTO: bool, const PERCENT: bool> From<Size<Length<AUTO, PERCENT>>>
+impl<const AUTO: bool, const PERCENT: bool> From<Size<LayoutLength<AUTO, PERCENT>>>
     for taffy::Size<taffy::Dimension>
 {
-    fn from(value: Size<Length<AUTO, PERCENT>>) -> Self {
+    fn from(value: Size<LayoutLength<AUTO, PERCENT>>) -> Self {
         taffy::Size {
             width: value.width.into(),
             height: value.height.into(),
@@ -69,10 +84,10 @@ impl<const AUTO: bool, const PERCENT: bool> From<Size<Length<AUTO, PERCENT>>>
     }
 }
 
-impl<const PERCENT: bool> From<Size<Length<false, PERCENT>>>
+impl<const PERCENT: bool> From<Size<LayoutLength<false, PERCENT>>>
     for taffy::Size<taffy::LengthPercentage>
 {
-    fn from(value: Size<Length<false, PERCENT>>) -> Self {
+    fn from(value: Size<LayoutLength<false, PERCENT>>) -> Self {
         taffy::Size {
             width: value.width.into(),
             height: value.height.into(),
@@ -80,10 +95,19 @@ impl<const PERCENT: bool> From<Size<Length<false, PERCENT>>>
     }
 }
 
-impl<const AUTO: bool, const PERCENT: bool> From<Size<Length<AUTO, PERCENT>>>
+impl<const AUTO: bool, const PERCENT: bool> From<Size<LayoutLength<AUTO, PERCENT>>>
     for taffy::Size<taffy::LengthPercentageAuto>
 {
-    fn from(value: Size<Length<AUTO, PERCENT>>) -> Self {
+    fn from(value: Size<LayoutLength<AUTO, PERCENT>>) -> Self {
+        taffy::Size {
+            width: value.width.into(),
+            height: value.height.into(),
+        }
+    }
+}
+
+impl From<Size<AvailableSpace>> for taffy::Size<taffy::AvailableSpace> {
+    fn from(value: Size<AvailableSpace>) -> Self {
         taffy::Size {
             width: value.width.into(),
             height: value.height.into(),
@@ -0,0 +1,23 @@
+use crate::utils::IsDefault;
+use enum_display::EnumDisplay;
+
+/// Defines the shape drawn at the end of open subpaths when stroked.
+///
+/// # Reference
+///
+/// https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/stroke-linecap
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone, Default, EnumDisplay)]
+pub enum StrokeLineCap {
+    /// Ends the stroke sharply at the end of the subpath.
+    #[default]
+    #[display("butt")]
+    Butt,
+    /// Extends the stroke with a half circle at the end of the subpath.
+    #[display("round")]
+    Round,
+    /// Extends the stroke with a half square at the end of the subpath.
+    #[display("square")]
+    Square,
+}
+
+impl IsDefault for StrokeLineCap {}
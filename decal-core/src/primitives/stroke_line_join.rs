@@ -0,0 +1,27 @@
+use crate::utils::IsDefault;
+use enum_display::EnumDisplay;
+
+/// Defines the shape drawn at the corners of a stroked path.
+///
+/// # Reference
+///
+/// https://developer.mozilla.org/en-US/docs/Web/SVG/Reference/Attribute/stroke-linejoin
+#[derive(Debug, Hash, Eq, PartialEq, Copy, Clone, Default, EnumDisplay)]
+pub enum StrokeLineJoin {
+    /// Joins path segments with a sharp corner, falling back to
+    /// [`StrokeLineJoin::Bevel`] when the miter limit is exceeded.
+    #[default]
+    #[display("miter")]
+    Miter,
+    /// Joins path segments with a sharp corner, clipped at the miter limit.
+    #[display("miter-clip")]
+    MiterClip,
+    /// Joins path segments with a rounded corner.
+    #[display("round")]
+    Round,
+    /// Joins path segments with a beveled corner.
+    #[display("bevel")]
+    Bevel,
+}
+
+impl IsDefault for StrokeLineJoin {}
@@ -0,0 +1,341 @@
+use crate::{
+    primitives::Paint,
+    utils::PathWriter,
+};
+use std::fmt::Write;
+
+/// The kind of line drawn by a [`TextDecoration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDecorationLine {
+    /// No decoration line. Useful for removing an inherited decoration.
+    #[default]
+    None,
+    /// A line drawn below the baseline.
+    Underline,
+    /// A line drawn above the glyphs.
+    Overline,
+    /// A line drawn through the middle of the glyphs.
+    LineThrough,
+}
+
+/// The style of a [`TextDecoration`] line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextDecorationStyle {
+    /// A single solid line.
+    #[default]
+    Solid,
+    /// Two parallel solid lines.
+    Double,
+    /// A series of square dots.
+    Dotted,
+    /// A series of short dashes.
+    Dashed,
+    /// A wavy line.
+    Wavy,
+}
+
+/// The text decoration property.
+///
+/// Decoration lines are positioned using the underline and strikeout metrics
+/// of the font used to shape the decorated glyphs.
+#[derive(Debug, Clone, Default)]
+pub struct TextDecoration {
+    pub(crate) line: TextDecorationLine,
+    pub(crate) style: TextDecorationStyle,
+    pub(crate) thickness: Option<f32>,
+    pub(crate) offset: f32,
+    pub(crate) paint: Option<Paint>,
+}
+
+impl TextDecoration {
+    /// Creates a new [`TextDecoration`] drawing the provided line kind.
+    ///
+    /// # Arguments
+    /// - `line`: The [`TextDecorationLine`] to draw.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn new(line: TextDecorationLine) -> Self {
+        Self {
+            line,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a [`TextDecoration`] that draws no line.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn none() -> Self {
+        Self::new(TextDecorationLine::None)
+    }
+
+    /// Creates an underline [`TextDecoration`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn underline() -> Self {
+        Self::new(TextDecorationLine::Underline)
+    }
+
+    /// Creates an overline [`TextDecoration`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn overline() -> Self {
+        Self::new(TextDecorationLine::Overline)
+    }
+
+    /// Creates a line-through (strikethrough) [`TextDecoration`].
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn line_through() -> Self {
+        Self::new(TextDecorationLine::LineThrough)
+    }
+
+    /// Sets the style of the decoration line.
+    ///
+    /// # Arguments
+    /// - `style`: The [`TextDecorationStyle`] to apply.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn style(mut self, style: TextDecorationStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the thickness of the decoration line. When not set, the stroke
+    /// size from the font metrics is used.
+    ///
+    /// # Arguments
+    /// - `thickness`: The line thickness in layout units.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn thickness<T>(mut self, thickness: T) -> Self
+    where
+        T: Into<Option<f32>>,
+    {
+        self.thickness = thickness.into().map(|x| x.max(0.0));
+        self
+    }
+
+    /// Sets the vertical offset of the decoration line relative to its default
+    /// position. Positive values move the line downwards.
+    ///
+    /// # Arguments
+    /// - `offset`: The offset in layout units.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the paint of the decoration line. When not set, the text color is
+    /// used.
+    ///
+    /// # Arguments
+    /// - `paint`: The [`Paint`] value applied to the line.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn paint<T>(mut self, paint: T) -> Self
+    where
+        T: Into<Paint>,
+    {
+        self.paint = Some(paint.into());
+        self
+    }
+
+    /// Returns `true` if the decoration draws no line.
+    pub(crate) fn is_none(&self) -> bool {
+        self.line == TextDecorationLine::None
+    }
+
+    /// Writes the path data of the decoration line.
+    ///
+    /// Every style except [`TextDecorationStyle::Wavy`] is written as a set of
+    /// filled rectangles so that bounding box relative paints still apply. The
+    /// wavy line is written as an open curve and must be stroked.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `x`: The start of the line.
+    /// - `width`: The length of the line.
+    /// - `y`: The vertical center of the line.
+    /// - `thickness`: The resolved line thickness.
+    pub(crate) fn write_path<W>(
+        &self,
+        out: &mut W,
+        x: f32,
+        width: f32,
+        y: f32,
+        thickness: f32,
+    ) -> std::fmt::Result
+    where
+        W: Write,
+    {
+        if width <= 0.0 || thickness <= 0.0 {
+            return Ok(());
+        }
+
+        let mut d = PathWriter::new(out);
+        let half = thickness / 2.0;
+
+        match self.style {
+            TextDecorationStyle::Solid => {
+                write_rect(&mut d, x, y - half, width, thickness)?;
+            }
+            TextDecorationStyle::Double => {
+                write_rect(&mut d, x, y - half, width, thickness)?;
+                write_rect(&mut d, x, y + thickness + half, width, thickness)?;
+            }
+            TextDecorationStyle::Dotted | TextDecorationStyle::Dashed => {
+                let (dash, gap) = if self.style == TextDecorationStyle::Dotted {
+                    (thickness, thickness)
+                } else {
+                    (thickness * 3.0, thickness * 2.0)
+                };
+
+                let end = x + width;
+                let mut start = x;
+
+                while start < end {
+                    write_rect(&mut d, start, y - half, dash.min(end - start), thickness)?;
+                    start += dash + gap;
+                }
+            }
+            TextDecorationStyle::Wavy => {
+                let amplitude = thickness.max(1.0);
+                let half_period = amplitude * 2.0;
+                let end = x + width;
+                let mut start = x;
+                let mut direction = -1.0;
+
+                d.move_to(x, y)?;
+
+                while start < end {
+                    let next = (start + half_period).min(end);
+                    d.quad_to(
+                        (start + next) / 2.0,
+                        y + amplitude * 2.0 * direction,
+                        next,
+                        y,
+                    )?;
+                    start = next;
+                    direction = -direction;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<TextDecorationLine> for TextDecoration {
+    #[inline]
+    fn from(value: TextDecorationLine) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Writes a closed rectangle into the path.
+fn write_rect<W>(d: &mut PathWriter<W>, x: f32, y: f32, width: f32, height: f32) -> std::fmt::Result
+where
+    W: Write,
+{
+    d.move_to(x, y)?
+        .horizontal_to(x + width)?
+        .vertical_to(y + height)?
+        .horizontal_to(x)?
+        .close()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::str_sink;
+
+    #[test]
+    fn defaults_to_none() {
+        assert!(TextDecoration::default().is_none());
+        assert!(TextDecoration::none().is_none());
+        assert!(!TextDecoration::underline().is_none());
+    }
+
+    #[test]
+    fn clamps_negative_thickness() {
+        assert_eq!(
+            TextDecoration::underline().thickness(-2.0).thickness,
+            Some(0.0)
+        );
+    }
+
+    #[test]
+    fn writes_solid_line() {
+        assert_eq!(
+            str_sink(|out| TextDecoration::underline().write_path(out, 0.0, 10.0, 5.0, 2.0)),
+            "M0 4 H10 V6 H0 Z"
+        );
+    }
+
+    #[test]
+    fn writes_double_line() {
+        assert_eq!(
+            str_sink(|out| {
+                TextDecoration::underline()
+                    .style(TextDecorationStyle::Double)
+                    .write_path(out, 0.0, 10.0, 5.0, 2.0)
+            }),
+            "M0 4 H10 V6 H0 ZM0 8 H10 V10 H0 Z"
+        );
+    }
+
+    #[test]
+    fn writes_dotted_line() {
+        assert_eq!(
+            str_sink(|out| {
+                TextDecoration::underline()
+                    .style(TextDecorationStyle::Dotted)
+                    .write_path(out, 0.0, 5.0, 1.0, 2.0)
+            }),
+            "M0 0 H2 V2 H0 ZM4 0 H5 V2 H4 Z"
+        );
+    }
+
+    #[test]
+    fn writes_dashed_line() {
+        assert_eq!(
+            str_sink(|out| {
+                TextDecoration::line_through()
+                    .style(TextDecorationStyle::Dashed)
+                    .write_path(out, 0.0, 8.0, 1.0, 2.0)
+            }),
+            "M0 0 H6 V2 H0 Z"
+        );
+    }
+
+    #[test]
+    fn writes_wavy_line() {
+        assert_eq!(
+            str_sink(|out| {
+                TextDecoration::overline()
+                    .style(TextDecorationStyle::Wavy)
+                    .write_path(out, 0.0, 4.0, 0.0, 1.0)
+            }),
+            "M0 0 Q1 -2 2 0 Q3 2 4 0 "
+        );
+    }
+
+    #[test]
+    fn skips_empty_line() {
+        assert_eq!(
+            str_sink(|out| TextDecoration::underline().write_path(out, 0.0, 0.0, 5.0, 2.0)),
+            ""
+        );
+    }
+}
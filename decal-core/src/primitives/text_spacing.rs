@@ -0,0 +1,149 @@
+use crate::{
+    macros::ff32,
+    primitives::{
+        LayoutLength,
+        Length,
+        Size,
+        ViewportLength,
+    },
+};
+use strict_num::FiniteF32;
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+enum TextSpacingInner {
+    Em(FiniteF32),
+    Length(LayoutLength<false, false>),
+}
+
+/// The spacing added between text glyphs or to word separators.
+///
+/// Bare numbers are relative to the font size, in ems (e.g. `0.1` as
+/// `0.1em`), which can be spelled out with [`TextSpacing::em`]. Spacing in
+/// layout units is created from a [`Length`] or a [`ViewportLength`], such as
+/// `Length::units(2.0)` or `vw(1.0)`.
+#[derive(Debug, Hash, Eq, PartialEq, Clone)]
+pub struct TextSpacing(TextSpacingInner);
+
+impl TextSpacing {
+    /// Creates a spacing relative to the font size.
+    ///
+    /// # Arguments
+    /// - `value`: The spacing in ems (e.g. `0.1` as `0.1em`).
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub fn em(value: f32) -> Self {
+        Self(TextSpacingInner::Em(ff32!(value)))
+    }
+
+    /// Resolves the spacing into layout units.
+    ///
+    /// # Arguments
+    /// - `font_size`: The font size ems resolve against.
+    /// - `viewport`: The size viewport units resolve against.
+    ///
+    /// # Returns
+    /// - The spacing in layout units.
+    pub(crate) fn resolve(&self, font_size: f32, viewport: Size<f32>) -> f32 {
+        match &self.0 {
+            TextSpacingInner::Em(x) => x.get() * font_size,
+            TextSpacingInner::Length(x) => x.resolve_abs(0.0, viewport).unwrap_or(0.0),
+        }
+    }
+}
+
+impl From<Length<false, false>> for TextSpacing {
+    fn from(value: Length<false, false>) -> Self {
+        Self(TextSpacingInner::Length(value.into()))
+    }
+}
+
+impl From<ViewportLength> for TextSpacing {
+    fn from(value: ViewportLength) -> Self {
+        Self(TextSpacingInner::Length(value.into()))
+    }
+}
+
+macro_rules! impl_into_em_spacing {
+    ($($dtype:ty),*) => {
+        $(impl From<$dtype> for TextSpacing {
+            fn from(value: $dtype) -> Self {
+                Self::em(value as f32)
+            }
+        })*
+    };
+}
+
+impl_into_em_spacing!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+
+/// Conversion into an optional [`TextSpacing`] value.
+///
+/// This is primarily used by builder APIs to allow both direct values and
+/// `Option` values to be passed ergonomically.
+pub trait IntoOptionalTextSpacing {
+    /// Converts the value into an optional text spacing.
+    fn into_optional_text_spacing(self) -> Option<TextSpacing>;
+}
+
+impl IntoOptionalTextSpacing for Option<TextSpacing> {
+    fn into_optional_text_spacing(self) -> Option<TextSpacing> {
+        self
+    }
+}
+
+impl<T> IntoOptionalTextSpacing for T
+where
+    T: Into<TextSpacing>,
+{
+    fn into_optional_text_spacing(self) -> Option<TextSpacing> {
+        Some(self.into())
+    }
+}
+
+pub(super) mod helpers {
+    use super::TextSpacing;
+
+    /// Creates a [`TextSpacing`] relative to the font size, in ems.
+    ///
+    /// # Arguments
+    /// - `value`: The spacing in ems (e.g. `0.1` as `0.1em`).
+    ///
+    /// # Returns
+    /// - [`TextSpacing`]
+    #[must_use]
+    pub fn em<T>(value: T) -> TextSpacing
+    where
+        T: Into<f64>,
+    {
+        TextSpacing::em(value.into() as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        helpers::*,
+        *,
+    };
+    use crate::primitives::length::helpers::vw;
+
+    #[test]
+    fn resolves_bare_numbers_in_ems() {
+        let viewport = Size::from_values(200.0, 100.0);
+
+        assert_eq!(TextSpacing::from(0.1).resolve(20.0, viewport), 2.0);
+        assert_eq!(TextSpacing::from(2).resolve(10.0, viewport), 20.0);
+        assert_eq!(em(0.5).resolve(20.0, viewport), 10.0);
+    }
+
+    #[test]
+    fn resolves_lengths_in_layout_units() {
+        let viewport = Size::from_values(200.0, 100.0);
+
+        assert_eq!(
+            TextSpacing::from(Length::units(2.0)).resolve(20.0, viewport),
+            2.0
+        );
+        assert_eq!(TextSpacing::from(vw(1.0)).resolve(20.0, viewport), 2.0);
+    }
+}
@@ -217,23 +217,22 @@ impl Transform {
         self
     }
 
-    /// Writes the resolved transform as an SVG matrix attribute.
+    /// Resolves the transform into a matrix.
     ///
     /// # Arguments
-    /// - `out`: The output writer.
     /// - `pos`: The top-left position of the node.
     /// - `translate`: Translation applied before local transforms.
     /// - `size`: The size of the node used for center-based transforms.
-    pub(crate) fn write<T>(
+    ///
+    /// # Returns
+    /// - The resolved matrix, or `None` if it is the identity or not
+    ///   invertible.
+    pub(crate) fn resolve(
         &self,
-        out: &mut T,
         pos: (f32, f32),
         translate: (f32, f32),
         size: (f32, f32),
-    ) -> std::fmt::Result
-    where
-        T: Write,
-    {
+    ) -> Option<usvg::Transform> {
         let mut tf = self
             .initial_tf
             .unwrap_or_default()
@@ -287,9 +286,29 @@ impl Transform {
             }
         }
 
-        if tf.is_identity() || !tf.is_valid() {
+        (!tf.is_identity() && tf.is_valid()).then_some(tf)
+    }
+
+    /// Writes the resolved transform as an SVG matrix attribute.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `pos`: The top-left position of the node.
+    /// - `translate`: Translation applied before local transforms.
+    /// - `size`: The size of the node used for center-based transforms.
+    pub(crate) fn write<T>(
+        &self,
+        out: &mut T,
+        pos: (f32, f32),
+        translate: (f32, f32),
+        size: (f32, f32),
+    ) -> std::fmt::Result
+    where
+        T: Write,
+    {
+        let Some(tf) = self.resolve(pos, translate, size) else {
             return Ok(());
-        }
+        };
 
         out.write_str(r#" transform="matrix("#)?;
         out.write_float(tf.sx)?;
@@ -1,5 +1,6 @@
 use crate::{
     macros::ff32,
+    primitives::PreserveAspectRatio,
     utils::FloatWriter,
 };
 use std::fmt::{
@@ -38,6 +39,61 @@ impl ViewBox {
             height: ff32!(height),
         }
     }
+
+    /// Returns the width of the view box.
+    pub(crate) fn width(&self) -> f32 {
+        self.width.get()
+    }
+
+    /// Returns the height of the view box.
+    pub(crate) fn height(&self) -> f32 {
+        self.height.get()
+    }
+
+    /// Computes the transform mapping the view box onto a viewport.
+    ///
+    /// # Note
+    /// A view box with a zero dimension is scaled uniformly along its other
+    /// dimension.
+    ///
+    /// # Arguments
+    /// - `preserve_aspect_ratio`: The [`PreserveAspectRatio`] value.
+    /// - `width`: The width of the viewport.
+    /// - `height`: The height of the viewport.
+    ///
+    /// # Returns
+    /// - The `(scale_x, scale_y, translate_x, translate_y)` transform.
+    pub(crate) fn fit(
+        &self,
+        preserve_aspect_ratio: PreserveAspectRatio,
+        width: f32,
+        height: f32,
+    ) -> (f32, f32, f32, f32) {
+        let (min_x, min_y) = (self.min_x.get(), self.min_y.get());
+        let (vw, vh) = (self.width(), self.height());
+        let scale_x = (vw > 0.0).then(|| width / vw);
+        let scale_y = (vh > 0.0).then(|| height / vh);
+
+        let Some((align_x, align_y)) = preserve_aspect_ratio.alignment() else {
+            let scale_x = scale_x.or(scale_y).unwrap_or(1.0);
+            let scale_y = scale_y.unwrap_or(scale_x);
+            return (scale_x, scale_y, -min_x * scale_x, -min_y * scale_y);
+        };
+
+        let scale = match (scale_x, scale_y) {
+            (Some(x), Some(y)) if preserve_aspect_ratio.is_slice() => x.max(y),
+            (Some(x), Some(y)) => x.min(y),
+            (Some(x), None) | (None, Some(x)) => x,
+            (None, None) => 1.0,
+        };
+
+        (
+            scale,
+            scale,
+            -min_x * scale + (width - vw * scale) * align_x,
+            -min_y * scale + (height - vh * scale) * align_y,
+        )
+    }
 }
 
 impl Display for ViewBox {
@@ -63,4 +119,30 @@ mod tests {
             "0.2 0.3 150 250"
         );
     }
+
+    #[test]
+    fn fits_into_viewport() {
+        let view_box = ViewBox::new(10.0, 0.0, 20.0, 10.0);
+
+        assert_eq!(
+            view_box.fit(PreserveAspectRatio::default(), 40.0, 40.0),
+            (2.0, 2.0, -20.0, 10.0)
+        );
+        assert_eq!(
+            view_box.fit(PreserveAspectRatio::new().x_min_y_min().slice(), 40.0, 40.0),
+            (4.0, 4.0, -40.0, 0.0)
+        );
+        assert_eq!(
+            view_box.fit(PreserveAspectRatio::new().align_none(), 40.0, 40.0),
+            (2.0, 4.0, -20.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn fits_flat_view_box() {
+        assert_eq!(
+            ViewBox::new(0.0, 0.0, 20.0, 0.0).fit(PreserveAspectRatio::default(), 40.0, 10.0),
+            (2.0, 2.0, 0.0, 5.0)
+        );
+    }
 }
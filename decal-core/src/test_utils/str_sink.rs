@@ -1,3 +1,8 @@
+use crate::paint::{
+    IriNamespace,
+    NamespacedDisplay,
+};
+
 /// Executes a write operation into a temporary string buffer and returns the
 /// result.
 ///
@@ -14,3 +19,17 @@ where
     write_fn(&mut out).unwrap();
     out
 }
+
+/// Renders a value within the default IRI namespace.
+///
+/// # Arguments
+/// - `value`: The value to render.
+///
+/// # Returns
+/// - Rendered string.
+pub(crate) fn render<T>(value: &T) -> String
+where
+    T: NamespacedDisplay + ?Sized,
+{
+    value.display_in(&IriNamespace::default()).to_string()
+}
@@ -57,6 +57,25 @@ where
     }
 }
 
+/// Rounds a float value to the value read back after it is written by
+/// [`FloatWriter::write_float`].
+///
+/// # Arguments
+/// - `value`: The float value to round.
+///
+/// # Returns
+/// - The rounded value.
+pub(crate) fn round_float(value: f32) -> f32 {
+    let scale = <String as FloatWriter<String>>::FLOAT_SCALE;
+    let value = (value * scale).round() / scale;
+
+    if value.fract() == 0.0 {
+        value as i32 as f32
+    } else {
+        value
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,332 @@
+use std::collections::BTreeMap;
+use subsetter::{
+    GlyphRemapper,
+    Tag,
+};
+
+const CMAP_TAG: [u8; 4] = *b"cmap";
+const HEAD_TAG: [u8; 4] = *b"head";
+const OS2_TAG: [u8; 4] = *b"OS/2";
+const CHECKSUM_MAGIC: u32 = 0xB1B0_AFBA;
+
+/// The tables of a font, keyed by their tag.
+type Tables = BTreeMap<[u8; 4], Vec<u8>>;
+
+const LAYOUT_TAGS: [[u8; 4]; 6] = [*b"GDEF", *b"GPOS", *b"GSUB", *b"kern", *b"kerx", *b"morx"];
+
+/// Subsets a font so that it only contains the glyphs mapped by `glyphs`.
+///
+/// The subsetter strips the `cmap` and `OS/2` tables, which are required by
+/// browsers to use the font. A new `cmap` table is generated from the provided
+/// character mapping, while the `OS/2` table is copied from the source font.
+/// When the font cannot be subset, the complete face is used along with the
+/// generated `cmap` table.
+///
+/// # Arguments
+/// - `data`: The raw font data.
+/// - `index`: The index of the face within a font collection.
+/// - `glyphs`: The mapping from characters to glyph IDs in the source font.
+/// - `weight`: The value of the `wght` axis variable fonts are instantiated at.
+///
+/// # Returns
+/// - [`Vec<u8>`]: The subset font data on success.
+/// - `None` if the font cannot be read.
+pub(crate) fn subset_font(
+    data: &[u8],
+    index: u32,
+    glyphs: &BTreeMap<char, u16>,
+    weight: Option<f32>,
+) -> Option<Vec<u8>> {
+    let mut remapper = GlyphRemapper::new();
+    remapper.remap(0);
+
+    for glyph in glyphs.values() {
+        remapper.remap(*glyph);
+    }
+
+    let subset = match weight {
+        Some(weight) => subsetter::subset_with_variations(
+            data,
+            index,
+            &[(Tag::new(b"wght"), weight)],
+            &remapper,
+        ),
+        None => subsetter::subset(data, index, &remapper),
+    };
+
+    let (version, mut tables, mapping) = match subset {
+        Ok(subset) => {
+            let (version, tables) = read_tables(&subset, 0)?;
+            let mapping = glyphs
+                .iter()
+                .filter_map(|(ch, glyph)| Some((*ch as u32, remapper.get(*glyph)?)))
+                .collect::<Vec<_>>();
+
+            (version, tables, mapping)
+        }
+        Err(_) => {
+            let (version, mut tables) = read_tables(data, index)?;
+            // glyphs are already shaped, so the layout tables must not
+            // substitute or move them again
+            tables.retain(|tag, _| !LAYOUT_TAGS.contains(tag));
+
+            let mapping = glyphs
+                .iter()
+                .map(|(ch, glyph)| (*ch as u32, *glyph))
+                .collect::<Vec<_>>();
+
+            (version, tables, mapping)
+        }
+    };
+
+    tables.insert(CMAP_TAG, write_cmap(&mapping));
+
+    if let Some(os2) = read_tables(data, index).and_then(|(_, mut x)| x.remove(&OS2_TAG)) {
+        tables.insert(OS2_TAG, os2);
+    }
+
+    Some(write_font(&version, tables))
+}
+
+/// Reads the tables of an OpenType font.
+///
+/// # Arguments
+/// - `data`: The raw font data.
+/// - `index`: The index of the face within a font collection.
+///
+/// # Returns
+/// - The four-byte sfnt version and the mapping from table tags to table data,
+///   if the font is valid.
+pub(crate) fn read_tables(data: &[u8], index: u32) -> Option<([u8; 4], Tables)> {
+    let u16_at = |at: usize| Some(u16::from_be_bytes(data.get(at..at + 2)?.try_into().ok()?));
+    let u32_at = |at: usize| Some(u32::from_be_bytes(data.get(at..at + 4)?.try_into().ok()?));
+
+    let offset = if data.get(..4)? == b"ttcf" {
+        u32_at(12 + 4 * index as usize)? as usize
+    } else {
+        0
+    };
+
+    let num_tables = u16_at(offset + 4)? as usize;
+    let mut tables = BTreeMap::new();
+
+    for record in (0..num_tables).map(|x| offset + 12 + x * 16) {
+        let tag: [u8; 4] = data.get(record..record + 4)?.try_into().ok()?;
+        let start = u32_at(record + 8)? as usize;
+        let length = u32_at(record + 12)? as usize;
+        tables.insert(tag, data.get(start..start + length)?.to_vec());
+    }
+
+    let version = data.get(offset..offset + 4)?.try_into().ok()?;
+
+    Some((version, tables))
+}
+
+/// Writes an OpenType font from its tables, computing the table checksums and
+/// the `head` checksum adjustment.
+///
+/// # Arguments
+/// - `version`: The four-byte sfnt version.
+/// - `tables`: The font tables, sorted by tag.
+///
+/// # Returns
+/// - The raw font data.
+pub(crate) fn write_font(version: &[u8], mut tables: Tables) -> Vec<u8> {
+    if let Some(head) = tables.get_mut(&HEAD_TAG).filter(|x| x.len() >= 12) {
+        head[8..12].fill(0);
+    }
+
+    let num_tables = tables.len() as u16;
+    let entry_selector = num_tables.max(1).ilog2() as u16;
+    let search_range = (1u16 << entry_selector) * 16;
+
+    let mut out = Vec::new();
+    out.extend_from_slice(version);
+    out.extend_from_slice(&num_tables.to_be_bytes());
+    out.extend_from_slice(&search_range.to_be_bytes());
+    out.extend_from_slice(&entry_selector.to_be_bytes());
+    out.extend_from_slice(&(num_tables * 16 - search_range).to_be_bytes());
+
+    let mut offset = 12 + tables.len() * 16;
+    let mut head_offset = None;
+
+    for (tag, table) in &tables {
+        if *tag == HEAD_TAG {
+            head_offset = Some(offset);
+        }
+
+        out.extend_from_slice(tag);
+        out.extend_from_slice(&checksum(table).to_be_bytes());
+        out.extend_from_slice(&(offset as u32).to_be_bytes());
+        out.extend_from_slice(&(table.len() as u32).to_be_bytes());
+        offset += table.len().next_multiple_of(4);
+    }
+
+    for table in tables.values() {
+        out.extend_from_slice(table);
+        out.resize(out.len().next_multiple_of(4), 0);
+    }
+
+    if let Some(at) = head_offset.filter(|x| out.len() >= x + 12) {
+        let adjustment = CHECKSUM_MAGIC.wrapping_sub(checksum(&out));
+        out[at + 8..at + 12].copy_from_slice(&adjustment.to_be_bytes());
+    }
+
+    out
+}
+
+/// Computes the checksum of a font table.
+///
+/// # Arguments
+/// - `data`: The table data.
+///
+/// # Returns
+/// - The sum of the data as big-endian `u32` values.
+fn checksum(data: &[u8]) -> u32 {
+    data.chunks(4).fold(0u32, |sum, chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        sum.wrapping_add(u32::from_be_bytes(word))
+    })
+}
+
+/// Writes a `cmap` table containing a format 4 subtable for the Basic
+/// Multilingual Plane and a format 12 subtable covering all characters.
+///
+/// # Arguments
+/// - `mapping`: The mapping from code points to glyph IDs, sorted by code
+///   point.
+///
+/// # Returns
+/// - The `cmap` table data.
+fn write_cmap(mapping: &[(u32, u16)]) -> Vec<u8> {
+    // groups of consecutive code points mapped to consecutive glyphs
+    let mut groups: Vec<(u32, u32, u16)> = Vec::new();
+
+    for &(code, glyph) in mapping {
+        match groups.last_mut() {
+            Some((start, end, start_glyph))
+                if *end + 1 == code
+                    && u32::from(*start_glyph) + (code - *start) == u32::from(glyph) =>
+            {
+                *end = code;
+            }
+            _ => groups.push((code, code, glyph)),
+        }
+    }
+
+    let bmp = groups
+        .iter()
+        .filter(|(start, ..)| *start <= 0xFFFE)
+        .map(|&(start, end, glyph)| (start, end.min(0xFFFE), glyph))
+        .collect::<Vec<_>>();
+
+    let mut out = Vec::new();
+
+    // header with two encoding records
+    let format4_len = 16 + 8 * (bmp.len() + 1);
+    out.extend_from_slice(&0u16.to_be_bytes());
+    out.extend_from_slice(&2u16.to_be_bytes());
+    out.extend_from_slice(&[0, 3, 0, 1]);
+    out.extend_from_slice(&20u32.to_be_bytes());
+    out.extend_from_slice(&[0, 3, 0, 10]);
+    out.extend_from_slice(&(20 + format4_len as u32).to_be_bytes());
+
+    // format 4
+    let seg_count = (bmp.len() + 1) as u16;
+    let entry_selector = seg_count.ilog2() as u16;
+    let search_range = (1u16 << entry_selector) * 2;
+
+    out.extend_from_slice(&4u16.to_be_bytes());
+    out.extend_from_slice(&(format4_len as u16).to_be_bytes());
+    out.extend_from_slice(&0u16.to_be_bytes());
+    out.extend_from_slice(&(seg_count * 2).to_be_bytes());
+    out.extend_from_slice(&search_range.to_be_bytes());
+    out.extend_from_slice(&entry_selector.to_be_bytes());
+    out.extend_from_slice(&(seg_count * 2 - search_range).to_be_bytes());
+
+    for &(_, end, _) in &bmp {
+        out.extend_from_slice(&(end as u16).to_be_bytes());
+    }
+
+    out.extend_from_slice(&0xFFFFu16.to_be_bytes());
+    out.extend_from_slice(&0u16.to_be_bytes());
+
+    for &(start, ..) in &bmp {
+        out.extend_from_slice(&(start as u16).to_be_bytes());
+    }
+
+    out.extend_from_slice(&0xFFFFu16.to_be_bytes());
+
+    for &(start, _, glyph) in &bmp {
+        out.extend_from_slice(&glyph.wrapping_sub(start as u16).to_be_bytes());
+    }
+
+    out.extend_from_slice(&1u16.to_be_bytes());
+    out.resize(out.len() + 2 * seg_count as usize, 0);
+
+    // format 12
+    out.extend_from_slice(&12u16.to_be_bytes());
+    out.extend_from_slice(&0u16.to_be_bytes());
+    out.extend_from_slice(&(16 + 12 * groups.len() as u32).to_be_bytes());
+    out.extend_from_slice(&0u32.to_be_bytes());
+    out.extend_from_slice(&(groups.len() as u32).to_be_bytes());
+
+    for &(start, end, glyph) in &groups {
+        out.extend_from_slice(&start.to_be_bytes());
+        out.extend_from_slice(&end.to_be_bytes());
+        out.extend_from_slice(&u32::from(glyph).to_be_bytes());
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swash::FontRef;
+
+    const FONT: &[u8] = include_bytes!("../../../examples/github-card/fonts/MonaSans-Regular.ttf");
+
+    #[test]
+    fn computes_checksum_of_unaligned_data() {
+        assert_eq!(checksum(&[0, 0, 0, 1, 0, 0, 0, 2]), 3);
+        assert_eq!(checksum(&[0, 0, 0, 1, 1]), 0x0100_0001);
+    }
+
+    #[test]
+    fn groups_consecutive_mappings() {
+        let cmap = write_cmap(&[(65, 10), (66, 11), (67, 20), (0x1F600, 30)]);
+        let font = write_font(b"\0\x01\0\0", BTreeMap::from([(CMAP_TAG, cmap)]));
+        let charmap = FontRef::from_index(&font, 0).unwrap().charmap();
+
+        assert_eq!(charmap.map('A'), 10);
+        assert_eq!(charmap.map('B'), 11);
+        assert_eq!(charmap.map('C'), 20);
+        assert_eq!(charmap.map('D'), 0);
+        assert_eq!(charmap.map('\u{1F600}'), 30);
+    }
+
+    #[test]
+    fn subsets_font() {
+        let source = FontRef::from_index(FONT, 0).unwrap().charmap();
+        let glyphs = "decal"
+            .chars()
+            .map(|ch| (ch, source.map(ch)))
+            .collect::<BTreeMap<_, _>>();
+
+        let subset = subset_font(FONT, 0, &glyphs, None).unwrap();
+        let font = FontRef::from_index(&subset, 0).unwrap();
+
+        assert!(subset.len() < FONT.len());
+        assert_eq!(checksum(&subset), CHECKSUM_MAGIC);
+        assert!(font.table(swash::tag_from_bytes(b"OS/2")).is_some());
+        assert_eq!(font.glyph_metrics(&[]).glyph_count(), 6);
+
+        for ch in "decal".chars() {
+            assert_ne!(font.charmap().map(ch), 0);
+        }
+
+        assert_eq!(font.charmap().map('x'), 0);
+    }
+}
@@ -0,0 +1,109 @@
+use std::fmt::Write;
+
+/// Adapter forwarding formatted output to an [`std::io::Write`] destination.
+///
+/// Every write is passed through to the destination as it is produced, so the
+/// output is never held in memory. As [`std::fmt::Error`] carries no details,
+/// the first I/O error is retained and can be recovered with
+/// [`IoWriter::into_result`].
+#[derive(Debug)]
+pub(crate) struct IoWriter<'a, T>
+where
+    T: std::io::Write,
+{
+    inner: &'a mut T,
+    error: Option<std::io::Error>,
+}
+
+impl<'a, T> IoWriter<'a, T>
+where
+    T: std::io::Write,
+{
+    /// Creates a new [`IoWriter`] instance.
+    ///
+    /// # Arguments
+    /// - `inner`: The destination receiving the output.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new(inner: &'a mut T) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Replaces the error of a failed write with the underlying I/O error.
+    ///
+    /// # Arguments
+    /// - `result`: The result of the writes performed through the adapter.
+    ///
+    /// # Returns
+    /// - The I/O error raised by the destination, if any, or `result`
+    ///   otherwise.
+    pub(crate) fn into_result<R, E>(self, result: Result<R, E>) -> Result<R, E>
+    where
+        E: From<std::io::Error>,
+    {
+        match self.error {
+            Some(error) => Err(error.into()),
+            None => result,
+        }
+    }
+}
+
+impl<T> Write for IoWriter<'_, T>
+where
+    T: std::io::Write,
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        if self.error.is_some() {
+            return Err(std::fmt::Error);
+        }
+
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            std::fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::ErrorKind;
+
+    struct FailingWriter;
+
+    impl std::io::Write for FailingWriter {
+        fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+            Err(ErrorKind::BrokenPipe.into())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn forwards_writes() {
+        let mut bytes = Vec::new();
+        let mut out = IoWriter::new(&mut bytes);
+        let result: Result<(), std::io::Error> =
+            write!(out, "<svg>{}</svg>", 1).map_err(|_| ErrorKind::Other.into());
+
+        assert!(out.into_result(result).is_ok());
+        assert_eq!(bytes, b"<svg>1</svg>");
+    }
+
+    #[test]
+    fn retains_io_errors() {
+        let mut destination = FailingWriter;
+        let mut out = IoWriter::new(&mut destination);
+
+        assert!(out.write_str("a").is_err());
+        assert!(out.write_str("b").is_err());
+
+        let error = out
+            .into_result::<(), std::io::Error>(Err(ErrorKind::Other.into()))
+            .unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::BrokenPipe);
+    }
+}
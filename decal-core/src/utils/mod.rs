@@ -2,15 +2,23 @@ mod angle_to_line;
 mod element_writer;
 mod encode_image;
 mod float_writer;
+mod font_subset;
+mod io_writer;
 mod is_default;
+mod path_sink;
 mod path_writer;
 mod write_spaced;
 mod writer;
+mod xml_format;
 
 pub(crate) use angle_to_line::*;
 pub(crate) use element_writer::*;
 pub(crate) use encode_image::*;
 pub(crate) use float_writer::*;
+pub(crate) use font_subset::*;
+pub(crate) use io_writer::*;
 pub(crate) use is_default::*;
+pub(crate) use path_sink::*;
 pub(crate) use path_writer::*;
 pub(crate) use write_spaced::*;
+pub(crate) use xml_format::*;
@@ -0,0 +1,183 @@
+use super::path_writer::PathWriter;
+use std::fmt::Write;
+
+/// Receiver of absolute path commands.
+///
+/// Geometry is traced into a [`PathSink`] so that the same code can write SVG
+/// path data into any [`Write`] sink, or build a path directly.
+pub(crate) trait PathSink {
+    /// Starts a new subpath at the given point.
+    ///
+    /// # Arguments
+    /// - `x`: X coordinate of the target point.
+    /// - `y`: Y coordinate of the target point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn move_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds a straight line to the given point.
+    ///
+    /// # Arguments
+    /// - `x`: X coordinate of the target point.
+    /// - `y`: Y coordinate of the target point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn line_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds a horizontal line to the given X coordinate.
+    ///
+    /// # Arguments
+    /// - `x`: Target X coordinate.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn horizontal_to(&mut self, x: f32) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds a vertical line to the given Y coordinate.
+    ///
+    /// # Arguments
+    /// - `y`: Target Y coordinate.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn vertical_to(&mut self, y: f32) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds a quadratic Bézier curve.
+    ///
+    /// # Arguments
+    /// - `cx`: X coordinate of the control point.
+    /// - `cy`: Y coordinate of the control point.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds a cubic Bézier curve.
+    ///
+    /// # Arguments
+    /// - `cx1`: X coordinate of the first control point.
+    /// - `cy1`: Y coordinate of the first control point.
+    /// - `cx2`: X coordinate of the second control point.
+    /// - `cy2`: Y coordinate of the second control point.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn curve_to(
+        &mut self,
+        cx1: f32,
+        cy1: f32,
+        cx2: f32,
+        cy2: f32,
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds a clockwise arc of an unrotated ellipse, with the small arc flag.
+    ///
+    /// # Arguments
+    /// - `rx`: X axis radius.
+    /// - `ry`: Y axis radius.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn arc_to(&mut self, rx: f32, ry: f32, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Adds an elliptical arc with explicit rotation and flags.
+    ///
+    /// # Arguments
+    /// - `rx`: X axis radius.
+    /// - `ry`: Y axis radius.
+    /// - `x_axis_rotation`: Rotation of the ellipse in degrees.
+    /// - `(large_arc, sweep)`: The large-arc and sweep flags.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    fn elliptical_arc_to(
+        &mut self,
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        flags: (bool, bool),
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error>;
+
+    /// Closes the current subpath.
+    fn close(&mut self) -> std::fmt::Result;
+}
+
+/// Writes the commands as SVG path data through a [`PathWriter`].
+impl<T> PathSink for T
+where
+    T: Write,
+{
+    fn move_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).move_to(x, y)?;
+        Ok(self)
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).line_to(x, y)?;
+        Ok(self)
+    }
+
+    fn horizontal_to(&mut self, x: f32) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).horizontal_to(x)?;
+        Ok(self)
+    }
+
+    fn vertical_to(&mut self, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).vertical_to(y)?;
+        Ok(self)
+    }
+
+    fn quad_to(&mut self, cx: f32, cy: f32, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).quad_to(cx, cy, x, y)?;
+        Ok(self)
+    }
+
+    fn curve_to(
+        &mut self,
+        cx1: f32,
+        cy1: f32,
+        cx2: f32,
+        cy2: f32,
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).curve_to(cx1, cy1, cx2, cy2, x, y)?;
+        Ok(self)
+    }
+
+    fn arc_to(&mut self, rx: f32, ry: f32, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).arc_to(rx, ry, x, y)?;
+        Ok(self)
+    }
+
+    fn elliptical_arc_to(
+        &mut self,
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        flags: (bool, bool),
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        PathWriter::new(self).elliptical_arc_to(rx, ry, x_axis_rotation, flags, x, y)?;
+        Ok(self)
+    }
+
+    fn close(&mut self) -> std::fmt::Result {
+        PathWriter::new(self).close()
+    }
+}
@@ -172,12 +172,269 @@ where
             .space()
     }
 
+    /// Writes an absolute elliptical arc command with explicit rotation and
+    /// flags.
+    ///
+    /// # Arguments
+    /// - `rx`: X axis radius.
+    /// - `ry`: Y axis radius.
+    /// - `x_axis_rotation`: Rotation of the ellipse in degrees.
+    /// - `(large_arc, sweep)`: The large-arc and sweep flags.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    pub(crate) fn elliptical_arc_to(
+        &mut self,
+        rx: f32,
+        ry: f32,
+        x_axis_rotation: f32,
+        (large_arc, sweep): (bool, bool),
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        self.char('A')?
+            .float(rx)?
+            .space()?
+            .float(ry)?
+            .space()?
+            .float(x_axis_rotation)?
+            .space()?
+            .char(if large_arc { '1' } else { '0' })?
+            .space()?
+            .char(if sweep { '1' } else { '0' })?
+            .space()?
+            .float(x)?
+            .space()?
+            .float(y)?
+            .space()
+    }
+
     /// Writes a close-path command.
     pub(crate) fn close(&mut self) -> std::fmt::Result {
         self.char('Z').map(|_| ())
     }
 }
 
+/// Utility writer for constructing compact SVG path data from absolute
+/// coordinates.
+///
+/// Every command is written relative to the current point, with coordinates
+/// rounded to a fixed number of decimal places. Rounding is applied to the
+/// absolute coordinates, so errors do not accumulate along the path. Repeated
+/// command letters and redundant separators are omitted.
+#[derive(Debug)]
+pub(crate) struct RelativePathWriter<'a, T>
+where
+    T: Write,
+{
+    out: &'a mut T,
+    precision: u32,
+    scale: f64,
+    current: (i64, i64),
+    start: (i64, i64),
+    command: Option<char>,
+    // whether the last number written contains a decimal point
+    last_number: Option<bool>,
+}
+
+impl<'a, T> RelativePathWriter<'a, T>
+where
+    T: Write,
+{
+    /// Creates a new [`RelativePathWriter`] instance.
+    ///
+    /// # Arguments
+    /// - `out`: The output sink receiving path commands.
+    /// - `precision`: The number of decimal places kept for coordinates.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn new(out: &'a mut T, precision: u8) -> Self {
+        let precision = precision.min(6) as u32;
+
+        Self {
+            out,
+            precision,
+            scale: 10f64.powi(precision as i32),
+            current: (0, 0),
+            start: (0, 0),
+            command: None,
+            last_number: None,
+        }
+    }
+
+    /// Writes a relative move-to command.
+    ///
+    /// # Arguments
+    /// - `x`: X coordinate of the target point.
+    /// - `y`: Y coordinate of the target point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    pub(crate) fn move_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        let point = self.units(x, y);
+
+        // implicit commands following a move-to are line-tos
+        self.command = None;
+        self.command('m')?.point(point)?;
+        self.current = point;
+        self.start = point;
+
+        Ok(self)
+    }
+
+    /// Writes a relative line-to command, using the horizontal and vertical
+    /// forms for axis-aligned lines. Zero-length lines are skipped.
+    ///
+    /// # Arguments
+    /// - `x`: X coordinate of the target point.
+    /// - `y`: Y coordinate of the target point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    pub(crate) fn line_to(&mut self, x: f32, y: f32) -> Result<&mut Self, std::fmt::Error> {
+        let point = self.units(x, y);
+        let (dx, dy) = (point.0 - self.current.0, point.1 - self.current.1);
+
+        match (dx, dy) {
+            (0, 0) => return Ok(self),
+            (_, 0) => self.command('h')?.number(dx)?,
+            (0, _) => self.command('v')?.number(dy)?,
+            _ => self.command('l')?.point(point)?,
+        };
+
+        self.current = point;
+        Ok(self)
+    }
+
+    /// Writes a relative quadratic Bézier curve command.
+    ///
+    /// # Arguments
+    /// - `cx`: X coordinate of the control point.
+    /// - `cy`: Y coordinate of the control point.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    pub(crate) fn quad_to(
+        &mut self,
+        cx: f32,
+        cy: f32,
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        let point = self.units(x, y);
+
+        let control = self.units(cx, cy);
+
+        self.command('q')?.point(control)?.point(point)?;
+        self.current = point;
+
+        Ok(self)
+    }
+
+    /// Writes a relative cubic Bézier curve command.
+    ///
+    /// # Arguments
+    /// - `cx1`: X coordinate of the first control point.
+    /// - `cy1`: Y coordinate of the first control point.
+    /// - `cx2`: X coordinate of the second control point.
+    /// - `cy2`: Y coordinate of the second control point.
+    /// - `x`: X coordinate of the end point.
+    /// - `y`: Y coordinate of the end point.
+    ///
+    /// # Returns
+    /// - `Ok(&mut Self)` for chaining.
+    pub(crate) fn curve_to(
+        &mut self,
+        cx1: f32,
+        cy1: f32,
+        cx2: f32,
+        cy2: f32,
+        x: f32,
+        y: f32,
+    ) -> Result<&mut Self, std::fmt::Error> {
+        let point = self.units(x, y);
+
+        let (control1, control2) = (self.units(cx1, cy1), self.units(cx2, cy2));
+
+        self.command('c')?
+            .point(control1)?
+            .point(control2)?
+            .point(point)?;
+        self.current = point;
+
+        Ok(self)
+    }
+
+    /// Writes a close-path command.
+    pub(crate) fn close(&mut self) -> std::fmt::Result {
+        self.command = None;
+        self.command('z')?;
+        self.current = self.start;
+
+        Ok(())
+    }
+
+    /// Converts absolute coordinates into rounded integer units.
+    fn units(&self, x: f32, y: f32) -> (i64, i64) {
+        (
+            (x as f64 * self.scale).round() as i64,
+            (y as f64 * self.scale).round() as i64,
+        )
+    }
+
+    /// Writes a command letter, unless it repeats the previous command.
+    fn command(&mut self, command: char) -> Result<&mut Self, std::fmt::Error> {
+        if self.command != Some(command) {
+            self.out.write_char(command)?;
+            self.command = Some(command);
+            self.last_number = None;
+        }
+
+        Ok(self)
+    }
+
+    /// Writes a point relative to the current point.
+    fn point(&mut self, (x, y): (i64, i64)) -> Result<&mut Self, std::fmt::Error> {
+        let (dx, dy) = (x - self.current.0, y - self.current.1);
+        self.number(dx)?.number(dy)
+    }
+
+    /// Writes a number of units, omitting the leading zero and the separator
+    /// where the number remains unambiguous.
+    fn number(&mut self, value: i64) -> Result<&mut Self, std::fmt::Error> {
+        let unit = 10u64.pow(self.precision);
+        let abs = value.unsigned_abs();
+        let (int, fract) = (abs / unit, abs % unit);
+        let has_point = fract != 0;
+
+        if value < 0 {
+            self.out.write_char('-')?;
+        } else if let Some(last_has_point) = self.last_number {
+            // `.5` may directly follow `0.5`, but not `1`
+            if int != 0 || !has_point || !last_has_point {
+                self.out.write_char(' ')?;
+            }
+        }
+
+        if int != 0 || !has_point {
+            write!(self.out, "{int}")?;
+        }
+
+        if has_point {
+            let digits = format!("{fract:0width$}", width = self.precision as usize);
+            write!(self.out, ".{}", digits.trim_end_matches('0'))?;
+        }
+
+        self.last_number = Some(has_point);
+        Ok(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,6 +496,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn writes_elliptical_arc_to() {
+        assert_eq!(
+            write_path(|d| d
+                .elliptical_arc_to(1.0, 2.0, 30.0, (true, false), 3.0, 4.0)
+                .map(|_| ())),
+            "A1 2 30 1 0 3 4 "
+        );
+    }
+
     #[test]
     fn close_without_commands() {
         assert_eq!(write_path(|d| d.close()), "Z");
@@ -259,4 +526,90 @@ mod tests {
             "M1.2346 2 "
         );
     }
+
+    fn write_relative_path<F>(precision: u8, write_fn: F) -> String
+    where
+        F: FnOnce(&mut RelativePathWriter<'_, String>) -> std::fmt::Result,
+    {
+        let mut out = String::new();
+        let mut d = RelativePathWriter::new(&mut out, precision);
+        write_fn(&mut d).unwrap();
+        out
+    }
+
+    #[test]
+    fn writes_relative_commands() {
+        assert_eq!(
+            write_relative_path(2, |d| d
+                .move_to(10.0, 10.0)?
+                .line_to(15.0, 12.0)?
+                .quad_to(20.0, 12.0, 20.0, 20.0)?
+                .curve_to(20.0, 25.0, 15.0, 30.0, 10.0, 30.0)?
+                .close()),
+            "m10 10l5 2q5 0 5 8c0 5-5 10-10 10z"
+        );
+    }
+
+    #[test]
+    fn writes_axis_aligned_lines() {
+        assert_eq!(
+            write_relative_path(2, |d| d
+                .move_to(0.0, 0.0)?
+                .line_to(10.0, 0.0)?
+                .line_to(10.0, 10.0)?
+                .line_to(10.0, 10.0)?
+                .close()),
+            "m0 0h10v10z"
+        );
+    }
+
+    #[test]
+    fn omits_repeated_commands() {
+        assert_eq!(
+            write_relative_path(2, |d| d
+                .move_to(0.0, 0.0)?
+                .line_to(1.0, 1.0)?
+                .line_to(2.0, 3.0)?
+                .move_to(5.0, 5.0)?
+                .line_to(6.0, 6.0)?
+                .close()),
+            "m0 0l1 1 1 2m3 2l1 1z"
+        );
+    }
+
+    #[test]
+    fn omits_leading_zeros_and_separators() {
+        assert_eq!(
+            write_relative_path(2, |d| d
+                .move_to(0.5, 1.0)?
+                .line_to(1.0, 1.5)?
+                .line_to(1.25, 1.0)?
+                .close()),
+            "m.5 1l.5.5.25-.5z"
+        );
+    }
+
+    #[test]
+    fn rounds_without_accumulating_errors() {
+        assert_eq!(
+            write_relative_path(1, |d| d
+                .move_to(0.04, 0.0)?
+                .line_to(0.08, 1.0)?
+                .line_to(0.12, 2.0)?
+                .line_to(0.16, 3.0)
+                .map(|_| ())),
+            "m0 0l.1 1v1l.1 1"
+        );
+    }
+
+    #[test]
+    fn closes_relative_to_subpath_start() {
+        assert_eq!(
+            write_relative_path(0, |d| {
+                d.move_to(10.0, 10.0)?.line_to(20.0, 10.0)?.close()?;
+                d.move_to(30.0, 10.0).map(|_| ())
+            }),
+            "m10 10h10zm20 0"
+        );
+    }
 }
@@ -0,0 +1,899 @@
+use crate::utils::FloatWriter;
+use std::fmt::Write;
+use svgtypes::{
+    PathParser,
+    PathSegment,
+};
+
+/// Elements whose content is written verbatim when pretty-printing, as
+/// whitespace between their children is significant.
+const INLINE_ELEMENTS: [&str; 1] = ["text"];
+
+/// Attribute values that match the initial value of non-inherited attributes,
+/// as `(element, attribute, value)`. An element of `*` matches every element.
+const DEFAULT_ATTRS: &[(&str, &str, &str)] = &[
+    ("*", "opacity", "1"),
+    ("rect", "x", "0"),
+    ("rect", "y", "0"),
+    ("image", "x", "0"),
+    ("image", "y", "0"),
+    ("use", "x", "0"),
+    ("use", "y", "0"),
+    ("svg", "x", "0"),
+    ("svg", "y", "0"),
+    ("circle", "cx", "0"),
+    ("circle", "cy", "0"),
+    ("ellipse", "cx", "0"),
+    ("ellipse", "cy", "0"),
+    ("stop", "offset", "0"),
+    ("stop", "stop-opacity", "1"),
+    ("linearGradient", "x1", "0"),
+    ("linearGradient", "x1", "0%"),
+    ("linearGradient", "y1", "0"),
+    ("linearGradient", "y1", "0%"),
+    ("linearGradient", "x2", "100%"),
+    ("linearGradient", "y2", "0"),
+    ("linearGradient", "y2", "0%"),
+    ("linearGradient", "gradientUnits", "objectBoundingBox"),
+    ("linearGradient", "spreadMethod", "pad"),
+    ("radialGradient", "cx", "50%"),
+    ("radialGradient", "cy", "50%"),
+    ("radialGradient", "r", "50%"),
+    ("radialGradient", "fr", "0"),
+    ("radialGradient", "fr", "0%"),
+    ("radialGradient", "gradientUnits", "objectBoundingBox"),
+    ("radialGradient", "spreadMethod", "pad"),
+    ("pattern", "x", "0"),
+    ("pattern", "y", "0"),
+    ("pattern", "patternUnits", "objectBoundingBox"),
+    ("pattern", "patternContentUnits", "userSpaceOnUse"),
+    ("clipPath", "clipPathUnits", "userSpaceOnUse"),
+    ("mask", "maskUnits", "objectBoundingBox"),
+    ("mask", "maskContentUnits", "userSpaceOnUse"),
+    ("filter", "filterUnits", "objectBoundingBox"),
+    ("filter", "primitiveUnits", "userSpaceOnUse"),
+    ("feBlend", "mode", "normal"),
+    ("feComposite", "operator", "over"),
+    ("feOffset", "dx", "0"),
+    ("feOffset", "dy", "0"),
+    ("feFlood", "flood-opacity", "1"),
+    ("feMorphology", "operator", "erode"),
+    ("feColorMatrix", "type", "matrix"),
+    ("feTurbulence", "numOctaves", "1"),
+    ("feTurbulence", "seed", "0"),
+    ("feTurbulence", "stitchTiles", "noStitch"),
+    ("feTurbulence", "type", "turbulence"),
+    ("feDisplacementMap", "scale", "0"),
+];
+
+/// Attributes holding path data or glyph positions, as `(element, attribute)`,
+/// whose numbers are rounded to the precision of the minified markup.
+const GEOMETRY_ATTRS: &[(&str, &str)] = &[
+    ("path", "d"),
+    ("text", "x"),
+    ("text", "y"),
+    ("tspan", "x"),
+    ("tspan", "y"),
+];
+
+/// A token of serialized XML markup.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    /// A start tag, or an empty element tag when `empty` is set.
+    Start {
+        raw: &'a str,
+        name: &'a str,
+        attrs: &'a str,
+        empty: bool,
+    },
+    /// An end tag.
+    End(&'a str),
+    /// Character data between tags.
+    Text(&'a str),
+    /// A comment, CDATA section or declaration.
+    Other(&'a str),
+}
+
+/// Iterator splitting serialized XML markup into tokens.
+struct Tokens<'a> {
+    rest: &'a str,
+    /// Whether the markup is complete. Otherwise, the iterator stops before a
+    /// token that may continue in markup written later.
+    complete: bool,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        if !self.rest.starts_with('<') {
+            let end = self.rest.find('<').unwrap_or(self.rest.len());
+            return Some(Token::Text(self.take(end)));
+        }
+
+        const DELIMITERS: [(&str, &str); 4] = [
+            ("<![CDATA[", "]]>"),
+            ("<!--", "-->"),
+            ("<?", "?>"),
+            ("<!", ">"),
+        ];
+
+        if !self.complete
+            && DELIMITERS
+                .iter()
+                .any(|(open, _)| open.len() > self.rest.len() && open.starts_with(self.rest))
+        {
+            return None;
+        }
+
+        for (open, close) in DELIMITERS {
+            if self.rest.starts_with(open) {
+                let end = match self.rest.find(close) {
+                    Some(idx) => idx + close.len(),
+                    None if self.complete => self.rest.len(),
+                    None => return None,
+                };
+                return Some(Token::Other(self.take(end)));
+            }
+        }
+
+        let mut quote = None;
+        let Some(end) = self.rest.char_indices().skip(1).find_map(|(idx, ch)| {
+            match (quote, ch) {
+                (None, '"' | '\'') => quote = Some(ch),
+                (Some(open), _) if open == ch => quote = None,
+                (None, '>') => return Some(idx + 1),
+                _ => {}
+            }
+
+            None
+        }) else {
+            return self
+                .complete
+                .then(|| Token::Other(self.take(self.rest.len())));
+        };
+
+        let raw = self.take(end);
+
+        if raw.starts_with("</") {
+            return Some(Token::End(raw));
+        }
+
+        let inner = &raw[1..raw.len() - 1];
+        let (inner, empty) = match inner.strip_suffix('/') {
+            Some(inner) => (inner, true),
+            None => (inner, false),
+        };
+        let inner = inner.trim_end();
+        let (name, attrs) = inner
+            .split_once(char::is_whitespace)
+            .map_or((inner, ""), |(name, attrs)| (name, attrs.trim_start()));
+
+        Some(Token::Start {
+            raw,
+            name,
+            attrs,
+            empty,
+        })
+    }
+}
+
+impl<'a> Tokens<'a> {
+    /// Creates a new [`Tokens`] iterator.
+    ///
+    /// # Arguments
+    /// - `markup`: The serialized XML markup.
+    /// - `complete`: Whether the markup is complete.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    fn new(markup: &'a str, complete: bool) -> Self {
+        Self {
+            rest: markup,
+            complete,
+        }
+    }
+
+    /// Splits off the next `len` bytes of the remaining markup.
+    fn take(&mut self, len: usize) -> &'a str {
+        let (head, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        head
+    }
+}
+
+/// Splits the attributes of a start tag into `(key, value, raw)` tuples.
+///
+/// # Arguments
+/// - `attrs`: The attributes of a start tag.
+///
+/// # Returns
+/// - The attributes, or `None` if they cannot be parsed.
+fn parse_attrs(mut attrs: &str) -> Option<Vec<(&str, &str, &str)>> {
+    let mut parsed = Vec::new();
+
+    while !attrs.is_empty() {
+        let (key, rest) = attrs.split_once('=')?;
+        let quote = rest.chars().next().filter(|ch| matches!(ch, '"' | '\''))?;
+        let len = rest[1..].find(quote)?;
+        let raw_len = key.len() + len + 3;
+
+        parsed.push((key.trim(), &rest[1..=len], &attrs[..raw_len]));
+        attrs = attrs[raw_len..].trim_start();
+    }
+
+    Some(parsed)
+}
+
+/// The formatting applied by an [`XmlFormatter`].
+#[derive(Debug)]
+enum Style {
+    /// Every element is written on its own line, indented by its depth.
+    /// Character data and the content of [`INLINE_ELEMENTS`] are written
+    /// verbatim, so that the output renders identically.
+    Pretty {
+        /// Whether each open element has element children.
+        stack: Vec<bool>,
+        /// The depth of the open inline element, if any.
+        inline_depth: Option<usize>,
+        first: bool,
+    },
+    /// Attributes matching their initial value are removed and empty element
+    /// tags are shortened. The numbers of [`GEOMETRY_ATTRS`] are rounded to
+    /// `scale`.
+    Minified { scale: f32 },
+}
+
+impl Style {
+    /// Writes a single token.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `token`: The token to write.
+    fn write<T>(&mut self, out: &mut T, token: Token) -> std::fmt::Result
+    where
+        T: Write,
+    {
+        match self {
+            Self::Pretty {
+                stack,
+                inline_depth,
+                first,
+            } => {
+                let indent = |out: &mut T, depth: usize| -> std::fmt::Result {
+                    out.write_char('\n')?;
+                    (0..depth).try_for_each(|_| out.write_str("  "))
+                };
+
+                match token {
+                    Token::Start {
+                        raw, name, empty, ..
+                    } => {
+                        if inline_depth.is_none() {
+                            if let Some(has_children) = stack.last_mut() {
+                                *has_children = true;
+                            }
+
+                            if !*first {
+                                indent(out, stack.len())?;
+                            }
+                        }
+
+                        out.write_str(raw)?;
+
+                        if !empty {
+                            stack.push(false);
+
+                            if inline_depth.is_none() && INLINE_ELEMENTS.contains(&name) {
+                                *inline_depth = Some(stack.len());
+                            }
+                        }
+                    }
+                    Token::End(raw) => {
+                        let has_children = stack.pop().unwrap_or_default();
+
+                        if inline_depth.is_none() && has_children {
+                            indent(out, stack.len())?;
+                        }
+
+                        out.write_str(raw)?;
+
+                        if inline_depth.is_some_and(|depth| depth > stack.len()) {
+                            *inline_depth = None;
+                        }
+                    }
+                    Token::Text(raw) | Token::Other(raw) => out.write_str(raw)?,
+                }
+
+                *first = false;
+            }
+            Self::Minified { scale } => match token {
+                Token::Start {
+                    name, attrs, empty, ..
+                } => {
+                    write!(out, "<{name}")?;
+
+                    match parse_attrs(attrs) {
+                        Some(parsed) => {
+                            for (key, value, raw) in parsed {
+                                let is_default =
+                                    DEFAULT_ATTRS.iter().any(|&(element, attr, default)| {
+                                        (element == "*" || element == name)
+                                            && attr == key
+                                            && default == value
+                                    });
+
+                                if is_default {
+                                    continue;
+                                }
+
+                                match GEOMETRY_ATTRS
+                                    .iter()
+                                    .find(|&&(element, attr)| element == name && attr == key)
+                                {
+                                    Some((_, "d")) => {
+                                        write!(out, r#" {key}=""#)?;
+                                        write_rounded_path(out, value, *scale)?;
+                                        out.write_char('"')?;
+                                    }
+                                    Some(_) => {
+                                        write!(out, r#" {key}=""#)?;
+                                        write_rounded_list(out, value, *scale)?;
+                                        out.write_char('"')?;
+                                    }
+                                    None => write!(out, " {raw}")?,
+                                }
+                            }
+                        }
+                        None if !attrs.is_empty() => write!(out, " {attrs}")?,
+                        None => {}
+                    }
+
+                    out.write_str(if empty { "/>" } else { ">" })?;
+                }
+                Token::End(raw) | Token::Text(raw) | Token::Other(raw) => out.write_str(raw)?,
+            },
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes path data with its coordinates rounded to the given scale. Relative
+/// coordinates are rounded against the rounded absolute position, so that
+/// errors do not accumulate along the path. Path data that cannot be parsed is
+/// written unchanged.
+///
+/// # Arguments
+/// - `out`: The output writer.
+/// - `data`: The path data.
+/// - `scale`: The rounding scale.
+fn write_rounded_path<T>(out: &mut T, data: &str, scale: f32) -> std::fmt::Result
+where
+    T: Write,
+{
+    let Ok(segments) = PathParser::from(data).collect::<Result<Vec<_>, _>>() else {
+        return out.write_str(data);
+    };
+
+    let round = |value: f64| (value * scale as f64).round() / scale as f64;
+    // the exact and the rounded position of the pen and the start of the
+    // current subpath
+    let mut current = (0.0, 0.0);
+    let mut rounded = (0.0, 0.0);
+    let mut start = ((0.0, 0.0), (0.0, 0.0));
+    let mut last_command = None;
+
+    for segment in segments {
+        let abs = segment.is_abs();
+        let origin = if abs { (0.0, 0.0) } else { current };
+        let rounded_origin = if abs { (0.0, 0.0) } else { rounded };
+        // maps a point of the segment to its exact and rounded absolute position
+        let point = |x: f64, y: f64| {
+            let exact = (origin.0 + x, origin.1 + y);
+            (exact, (round(exact.0), round(exact.1)))
+        };
+        // the coordinates written for a rounded absolute position
+        let coords = |(x, y): (f64, f64)| [x - rounded_origin.0, y - rounded_origin.1];
+
+        let (command, end, numbers) = match segment {
+            PathSegment::MoveTo { x, y, .. } => {
+                let end = point(x, y);
+                start = end;
+                ('M', Some(end), coords(end.1).to_vec())
+            }
+            PathSegment::LineTo { x, y, .. } => {
+                let end = point(x, y);
+                ('L', Some(end), coords(end.1).to_vec())
+            }
+            PathSegment::HorizontalLineTo { x, .. } => {
+                let (exact, (x, _)) = point(x, 0.0);
+                let end = ((exact.0, current.1), (x, rounded.1));
+                ('H', Some(end), vec![x - rounded_origin.0])
+            }
+            PathSegment::VerticalLineTo { y, .. } => {
+                let (exact, (_, y)) = point(0.0, y);
+                let end = ((current.0, exact.1), (rounded.0, y));
+                ('V', Some(end), vec![y - rounded_origin.1])
+            }
+            PathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+                ..
+            } => {
+                let end = point(x, y);
+                let numbers = [point(x1, y1).1, point(x2, y2).1, end.1]
+                    .into_iter()
+                    .flat_map(coords)
+                    .collect();
+                ('C', Some(end), numbers)
+            }
+            PathSegment::SmoothCurveTo { x2, y2, x, y, .. } => {
+                let end = point(x, y);
+                let numbers = [point(x2, y2).1, end.1]
+                    .into_iter()
+                    .flat_map(coords)
+                    .collect();
+                ('S', Some(end), numbers)
+            }
+            PathSegment::Quadratic { x1, y1, x, y, .. } => {
+                let end = point(x, y);
+                let numbers = [point(x1, y1).1, end.1]
+                    .into_iter()
+                    .flat_map(coords)
+                    .collect();
+                ('Q', Some(end), numbers)
+            }
+            PathSegment::SmoothQuadratic { x, y, .. } => {
+                let end = point(x, y);
+                ('T', Some(end), coords(end.1).to_vec())
+            }
+            PathSegment::EllipticalArc {
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x,
+                y,
+                ..
+            } => {
+                let end = point(x, y);
+                let [dx, dy] = coords(end.1);
+                let flags = (large_arc as u8 as f64, sweep as u8 as f64);
+                let numbers = vec![
+                    round(rx),
+                    round(ry),
+                    round(x_axis_rotation),
+                    flags.0,
+                    flags.1,
+                    dx,
+                    dy,
+                ];
+                ('A', Some(end), numbers)
+            }
+            PathSegment::ClosePath { .. } => ('Z', None, vec![]),
+        };
+
+        let command = if abs {
+            command
+        } else {
+            command.to_ascii_lowercase()
+        };
+
+        // a repeated command letter is implied, except after a move-to
+        if last_command == Some(command) && !matches!(command, 'M' | 'm' | 'Z' | 'z') {
+            out.write_char(' ')?;
+        } else {
+            out.write_char(command)?;
+        }
+
+        for (idx, value) in numbers.into_iter().enumerate() {
+            if idx > 0 {
+                out.write_char(' ')?;
+            }
+
+            out.write_float_precise(value as f32, scale)?;
+        }
+
+        (current, rounded) = match end {
+            Some(end) => end,
+            None => start,
+        };
+        last_command = Some(command);
+    }
+
+    Ok(())
+}
+
+/// Writes a list of numbers rounded to the given scale. Lists that cannot be
+/// parsed are written unchanged.
+///
+/// # Arguments
+/// - `out`: The output writer.
+/// - `data`: The whitespace or comma separated numbers.
+/// - `scale`: The rounding scale.
+fn write_rounded_list<T>(out: &mut T, data: &str, scale: f32) -> std::fmt::Result
+where
+    T: Write,
+{
+    let Ok(values) = data
+        .split(|ch: char| ch.is_whitespace() || ch == ',')
+        .filter(|value| !value.is_empty())
+        .map(str::parse::<f32>)
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return out.write_str(data);
+    };
+
+    for (idx, value) in values.into_iter().enumerate() {
+        if idx > 0 {
+            out.write_char(' ')?;
+        }
+
+        out.write_float_precise(value, scale)?;
+    }
+
+    Ok(())
+}
+
+/// Writer formatting serialized XML markup as it is written, before
+/// forwarding it to the output writer. Only the token being written is
+/// buffered, such as an unterminated start tag.
+///
+/// [`XmlFormatter::finish`] must be called once the markup is written.
+#[derive(Debug)]
+pub(crate) struct XmlFormatter<'a, T> {
+    out: &'a mut T,
+    style: Style,
+    pending: String,
+}
+
+impl<'a, T> XmlFormatter<'a, T>
+where
+    T: Write,
+{
+    /// Creates a formatter writing every element on its own line, indented by
+    /// its depth.
+    ///
+    /// Character data and the content of [`INLINE_ELEMENTS`] are written
+    /// verbatim, so that the output renders identically.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn pretty(out: &'a mut T) -> Self {
+        Self {
+            out,
+            style: Style::Pretty {
+                stack: Vec::new(),
+                inline_depth: None,
+                first: true,
+            },
+            pending: String::new(),
+        }
+    }
+
+    /// Creates a formatter removing attributes matching their initial value
+    /// and shortening empty element tags. Path data and glyph positions are
+    /// rounded to the given number of decimal places, while other values,
+    /// such as opacities, gradient stops and transforms, are kept as written.
+    ///
+    /// # Arguments
+    /// - `out`: The output writer.
+    /// - `precision`: The number of decimal places kept for path data and glyph
+    ///   positions.
+    ///
+    /// # Returns
+    /// - [`Self`]
+    pub(crate) fn minified(out: &'a mut T, precision: u8) -> Self {
+        Self {
+            out,
+            style: Style::Minified {
+                scale: 10f32.powi(precision.min(8) as i32),
+            },
+            pending: String::new(),
+        }
+    }
+
+    /// Writes the remaining markup.
+    pub(crate) fn finish(mut self) -> std::fmt::Result {
+        self.flush(true)?;
+
+        match self.style {
+            Style::Pretty { .. } => self.out.write_char('\n'),
+            Style::Minified { .. } => Ok(()),
+        }
+    }
+
+    /// Formats and forwards every complete token of the pending markup.
+    ///
+    /// # Arguments
+    /// - `complete`: Whether the pending markup is complete.
+    fn flush(&mut self, complete: bool) -> std::fmt::Result {
+        let mut tokens = Tokens::new(&self.pending, complete);
+
+        for token in tokens.by_ref() {
+            self.style.write(self.out, token)?;
+        }
+
+        let consumed = self.pending.len() - tokens.rest.len();
+        self.pending.drain(..consumed);
+
+        Ok(())
+    }
+}
+
+impl<T> Write for XmlFormatter<'_, T>
+where
+    T: Write,
+{
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        self.pending.push_str(s);
+
+        // every tag ends with `>`, so an unterminated tag stays pending until
+        // one is written
+        if self.pending.starts_with('<') && !s.contains('>') {
+            return Ok(());
+        }
+
+        self.flush(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    const MARKUP: &str = concat!(
+        r#"<svg viewBox="0 0 10 10"><g opacity="1"><rect x="0" y="2" width="4" height="4" />"#,
+        r#"<text xml:space="preserve"><tspan x="1">a</tspan><tspan x="2">b</tspan></text>"#,
+        r#"</g><defs><style>.a > b { fill: red }</style>"#,
+        r#"<linearGradient id="a" x1="0%" x2="100%" y2="100%" /></defs></svg>"#
+    );
+
+    /// Formats the markup, writing it in chunks of `len` bytes.
+    fn format(minify: bool, markup: &str, len: usize) -> String {
+        let mut out = String::new();
+        let mut formatter = match minify {
+            true => XmlFormatter::minified(&mut out, 4),
+            false => XmlFormatter::pretty(&mut out),
+        };
+
+        markup
+            .as_bytes()
+            .chunks(len)
+            .try_for_each(|chunk| formatter.write_str(std::str::from_utf8(chunk).unwrap()))
+            .and_then(|_| formatter.finish())
+            .unwrap();
+
+        out
+    }
+
+    #[test]
+    fn tokenizes_markup() {
+        let tokens =
+            Tokens::new(r#"<a b="1 > 0"><c/>text<![CDATA[<x>]]></a>"#, true).collect::<Vec<_>>();
+
+        assert_eq!(
+            tokens,
+            [
+                Token::Start {
+                    raw: r#"<a b="1 > 0">"#,
+                    name: "a",
+                    attrs: r#"b="1 > 0""#,
+                    empty: false,
+                },
+                Token::Start {
+                    raw: "<c/>",
+                    name: "c",
+                    attrs: "",
+                    empty: true,
+                },
+                Token::Text("text"),
+                Token::Other("<![CDATA[<x>]]>"),
+                Token::End("</a>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_attrs() {
+        assert_eq!(
+            parse_attrs(r#"x="1" font-family='a "b"'"#),
+            Some(vec![
+                ("x", "1", r#"x="1""#),
+                ("font-family", r#"a "b""#, r#"font-family='a "b"'"#),
+            ])
+        );
+        assert_eq!(parse_attrs("x"), None);
+    }
+
+    #[test]
+    fn stops_before_incomplete_tokens() {
+        let mut tokens = Tokens::new(r#"<a>text<!-- b -"#, false);
+
+        assert_eq!(tokens.by_ref().count(), 2);
+        assert_eq!(tokens.rest, "<!-- b -");
+
+        for partial in ["<", "<!", "<![CD", r#"<a b="1 > 0"#] {
+            assert_eq!(Tokens::new(partial, false).next(), None);
+        }
+    }
+
+    #[test]
+    fn pretty_prints_markup() {
+        assert_eq!(
+            format(false, MARKUP, MARKUP.len()),
+            concat!(
+                "<svg viewBox=\"0 0 10 10\">\n",
+                "  <g opacity=\"1\">\n",
+                "    <rect x=\"0\" y=\"2\" width=\"4\" height=\"4\" />\n",
+                "    <text xml:space=\"preserve\"><tspan x=\"1\">a</tspan><tspan x=\"2\">b</tspan></text>\n",
+                "  </g>\n",
+                "  <defs>\n",
+                "    <style>.a > b { fill: red }</style>\n",
+                "    <linearGradient id=\"a\" x1=\"0%\" x2=\"100%\" y2=\"100%\" />\n",
+                "  </defs>\n",
+                "</svg>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn minifies_markup() {
+        assert_eq!(
+            format(true, MARKUP, MARKUP.len()),
+            concat!(
+                r#"<svg viewBox="0 0 10 10"><g><rect y="2" width="4" height="4"/>"#,
+                r#"<text xml:space="preserve"><tspan x="1">a</tspan><tspan x="2">b</tspan></text>"#,
+                r#"</g><defs><style>.a > b { fill: red }</style>"#,
+                r#"<linearGradient id="a" y2="100%"/></defs></svg>"#
+            )
+        );
+    }
+
+    #[test]
+    fn rounds_path_data() {
+        let round = |data, precision| {
+            let mut out = String::new();
+            write_rounded_path(&mut out, data, 10f32.powi(precision)).unwrap();
+            out
+        };
+
+        assert_eq!(
+            round("M0.26 0.74L10.44 2.25l0.3 0.3h0.4z", 0),
+            "M0 1L10 2l1 1h0z"
+        );
+        assert_eq!(round("M0 0L1.25 1L2 2.75", 1), "M0 0L1.3 1 2 2.8");
+        assert_eq!(
+            round("M0 0A5.5 5.5 0 0 1 10.4 10.6", 0),
+            "M0 0A6 6 0 0 1 10 11"
+        );
+        // relative coordinates do not accumulate rounding errors
+        assert_eq!(round("m0 0l0.4 0 0.4 0 0.4 0", 0), "m0 0l0 0 1 0 0 0");
+        assert_eq!(round("M0 0L", 0), "M0 0L");
+    }
+
+    #[test]
+    fn rounds_geometry_to_precision() {
+        let markup = concat!(
+            r#"<g opacity="0.25" transform="matrix(0.866 0.5 -0.5 0.866 0 0)">"#,
+            r#"<path d="M0.25 0.75H10.5" fill-opacity="0.25"/><text x="1.25 2.75">ab</text>"#,
+            r#"<stop offset="0.05"/></g>"#
+        );
+        let mut out = String::new();
+        let mut formatter = XmlFormatter::minified(&mut out, 0);
+        formatter.write_str(markup).unwrap();
+        formatter.finish().unwrap();
+
+        assert_eq!(
+            out,
+            concat!(
+                r#"<g opacity="0.25" transform="matrix(0.866 0.5 -0.5 0.866 0 0)">"#,
+                r#"<path d="M0 1H11" fill-opacity="0.25"/><text x="1 3">ab</text>"#,
+                r#"<stop offset="0.05"/></g>"#
+            )
+        );
+    }
+
+    #[test]
+    fn formats_markup_written_in_chunks() {
+        for len in 1..16 {
+            assert_eq!(
+                format(false, MARKUP, len),
+                format(false, MARKUP, MARKUP.len())
+            );
+            assert_eq!(
+                format(true, MARKUP, len),
+                format(true, MARKUP, MARKUP.len())
+            );
+        }
+    }
+
+    #[test]
+    fn formatted_output_renders_identically() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut render = |format| {
+            let mut scene = Scene::new(
+                Column::new()
+                    .size((64, 48))
+                    .corner_radius(12)
+                    .background(LinearGradient::angle(45.0).stops([
+                        (0.0, Color::rgb(99, 102, 241)),
+                        (1.0, Color::rgb(236, 72, 153)),
+                    ]))
+                    .opacity(0.8)
+                    .finish(),
+            );
+            let root = scene.root_id();
+            scene.append_child(root, Text::new("Hi").font_size(24.0).finish());
+
+            let options = RasterizeOptions {
+                vectorize_options: VectorizeOptions {
+                    format,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            engine.rasterize(&mut scene, &options).unwrap().0
+        };
+
+        let compact = render(SvgFormat::Compact);
+        assert_eq!(render(SvgFormat::Pretty), compact);
+        assert_eq!(render(SvgFormat::Minify { precision: 4 }), compact);
+    }
+
+    #[test]
+    fn low_precision_output_renders_identically() {
+        let mut engine = Engine::new(EngineOptions::default());
+        let mut render = |format| {
+            let mut scene = Scene::new(
+                Column::new()
+                    .size((64, 48))
+                    .background(LinearGradient::angle(45.0).stops([
+                        (0.05, Color::rgb(99, 102, 241)),
+                        (0.35, Color::rgba(236, 72, 153, 0.35)),
+                    ]))
+                    .opacity(0.25)
+                    .finish(),
+            );
+            let root = scene.root_id();
+            scene.append_child(
+                root,
+                Block::new()
+                    .size((24, 16))
+                    .background(Color::rgb(20, 184, 166))
+                    .opacity(0.45)
+                    .transform(Transform::new().rotate(30.0))
+                    .finish(),
+            );
+
+            let options = RasterizeOptions {
+                vectorize_options: VectorizeOptions {
+                    format,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            engine.rasterize(&mut scene, &options).unwrap().0
+        };
+
+        let compact = render(SvgFormat::Compact);
+        assert_eq!(render(SvgFormat::Minify { precision: 0 }), compact);
+        assert_eq!(render(SvgFormat::Minify { precision: 1 }), compact);
+    }
+}
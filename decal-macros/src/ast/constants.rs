@@ -1,5 +1,30 @@
 pub(crate) static VALID_NODES: &[&str] = &[
-    "Scene", "Snippet", "Block", "Flex", "Column", "Row", "Grid", "Text", "Image",
+    "Scene",
+    "Snippet",
+    "Block",
+    "Flex",
+    "Column",
+    "Row",
+    "Grid",
+    "Text",
+    "Image",
+    "Rectangle",
+    "Circle",
+    "Ellipse",
+    "Line",
+    "Polygon",
+    "Polyline",
+    "Path",
 ];
 
-pub(crate) static ATOMIC_NODES: &[&str] = &["Text", "Image"];
+pub(crate) static ATOMIC_NODES: &[&str] = &[
+    "Text",
+    "Image",
+    "Rectangle",
+    "Circle",
+    "Ellipse",
+    "Line",
+    "Polygon",
+    "Polyline",
+    "Path",
+];
@@ -47,8 +47,7 @@ fn main() {
             // background
             Block {}
                 .size(pct(100.0))
-                .margin_top(-80.0)
-                .margin_left(-80.0)
+                .inset(0.0)
                 .position(Position::Absolute)
                 .bg(rgb(0x0))
                 .fx(backdrop)